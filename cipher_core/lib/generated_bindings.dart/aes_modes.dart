@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cbc_decrypt_internal`, `cbc_decrypt_prefixed_internal`, `cbc_decrypt_with`, `cbc_encrypt_internal`, `cbc_encrypt_random_iv_internal`, `cbc_encrypt_with`, `cbc_hmac_decrypt_internal`, `cbc_hmac_encrypt_internal`, `check_aes_key`, `check_iv`, `ctr_apply_internal`, `ctr_decrypt_prefixed_internal`, `ctr_encrypt_random_iv_internal`, `random_iv`, `split_iv`
+
+
+            Uint8List  aesCbcEncrypt({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCbcEncrypt(plaintext: plaintext, key: key);
+
+Future<Uint8List>  aesCbcEncryptAsync({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCbcEncryptAsync(plaintext: plaintext, key: key);
+
+Uint8List  aesCbcDecrypt({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCbcDecrypt(ciphertext: ciphertext, key: key);
+
+Future<Uint8List>  aesCbcDecryptAsync({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCbcDecryptAsync(ciphertext: ciphertext, key: key);
+
+Uint8List  aesCbcEncryptWithIv({required List<int> plaintext , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateAesModesAesCbcEncryptWithIv(plaintext: plaintext, key: key, iv: iv);
+
+Future<Uint8List>  aesCbcEncryptWithIvAsync({required List<int> plaintext , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateAesModesAesCbcEncryptWithIvAsync(plaintext: plaintext, key: key, iv: iv);
+
+Uint8List  aesCbcDecryptWithIv({required List<int> ciphertext , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateAesModesAesCbcDecryptWithIv(ciphertext: ciphertext, key: key, iv: iv);
+
+Future<Uint8List>  aesCbcDecryptWithIvAsync({required List<int> ciphertext , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateAesModesAesCbcDecryptWithIvAsync(ciphertext: ciphertext, key: key, iv: iv);
+
+Uint8List  aesCtrEncrypt({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCtrEncrypt(plaintext: plaintext, key: key);
+
+Future<Uint8List>  aesCtrEncryptAsync({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCtrEncryptAsync(plaintext: plaintext, key: key);
+
+Uint8List  aesCtrDecrypt({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCtrDecrypt(ciphertext: ciphertext, key: key);
+
+Future<Uint8List>  aesCtrDecryptAsync({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateAesModesAesCtrDecryptAsync(ciphertext: ciphertext, key: key);
+
+Uint8List  aesCtrApplyKeystream({required List<int> data , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateAesModesAesCtrApplyKeystream(data: data, key: key, iv: iv);
+
+Future<Uint8List>  aesCtrApplyKeystreamAsync({required List<int> data , required List<int> key , required List<int> iv }) => RustLib.instance.api.crateAesModesAesCtrApplyKeystreamAsync(data: data, key: key, iv: iv);
+
+Uint8List  aesCbcHmacSha256Encrypt({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateAesModesAesCbcHmacSha256Encrypt(plaintext: plaintext, encKey: encKey, macKey: macKey, aad: aad);
+
+Future<Uint8List>  aesCbcHmacSha256EncryptAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateAesModesAesCbcHmacSha256EncryptAsync(plaintext: plaintext, encKey: encKey, macKey: macKey, aad: aad);
+
+Uint8List  aesCbcHmacSha256Decrypt({required List<int> ciphertext , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateAesModesAesCbcHmacSha256Decrypt(ciphertext: ciphertext, encKey: encKey, macKey: macKey, aad: aad);
+
+Future<Uint8List>  aesCbcHmacSha256DecryptAsync({required List<int> ciphertext , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateAesModesAesCbcHmacSha256DecryptAsync(ciphertext: ciphertext, encKey: encKey, macKey: macKey, aad: aad);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'hash_algorithm.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `batch_pool`, `build_pool`, `hmac_batch_internal`, `hmac_map_internal`, `par_map_internal`
+
+
+            /// Cap the number of worker threads used by batch functions; 0 means one per
+/// core. Batches already running finish on the previous pool.
+void  setBatchParallelism({required BigInt maxThreads }) => RustLib.instance.api.crateBatchSetBatchParallelism(maxThreads: maxThreads);
+
+/// Number of worker threads batch functions currently fan out to
+BigInt  batchParallelism() => RustLib.instance.api.crateBatchBatchParallelism();
+
+/// Hash every input with `algorithm`; results keep input order and SHAKE
+/// yields `digest_size` bytes, as in `hash`.
+List<Uint8List>  hashBatch({required HashAlgorithm algorithm , required List<Uint8List> inputs }) => RustLib.instance.api.crateBatchHashBatch(algorithm: algorithm, inputs: inputs);
+
+Future<List<Uint8List>>  hashBatchAsync({required HashAlgorithm algorithm , required List<Uint8List> inputs }) => RustLib.instance.api.crateBatchHashBatchAsync(algorithm: algorithm, inputs: inputs);
+
+/// HMAC every message under one key with any algorithm whose
+/// `supports_hmac` is true; others fail with `UnsupportedAlgorithm`.
+List<Uint8List>  hmacBatch({required HashAlgorithm algorithm , required List<int> key , required List<Uint8List> messages }) => RustLib.instance.api.crateBatchHmacBatch(algorithm: algorithm, key: key, messages: messages);
+
+Future<List<Uint8List>>  hmacBatchAsync({required HashAlgorithm algorithm , required List<int> key , required List<Uint8List> messages }) => RustLib.instance.api.crateBatchHmacBatchAsync(algorithm: algorithm, key: key, messages: messages);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            EcdsaKeyPair  ecdsaP256GenerateKeypair() => RustLib.instance.api.crateEcdsaEcdsaP256GenerateKeypair();
+
+/// Rebuild a keypair from a previously exported 32-byte private scalar.
+EcdsaKeyPair  ecdsaP256KeypairFromPrivateKey({required List<int> privateKey }) => RustLib.instance.api.crateEcdsaEcdsaP256KeypairFromPrivateKey(privateKey: privateKey);
+
+/// Check that the bytes are a valid SEC1-encoded P-256 point.
+bool  ecdsaP256IsValidPublicKey({required List<int> publicKey }) => RustLib.instance.api.crateEcdsaEcdsaP256IsValidPublicKey(publicKey: publicKey);
+
+/// Sign with SHA-256, returning the 64-byte r || s encoding.
+Uint8List  ecdsaP256Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP256Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  ecdsaP256SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP256SignAsync(privateKey: privateKey, message: message);
+
+/// Sign with SHA-256, returning an ASN.1 DER signature.
+Uint8List  ecdsaP256SignDer({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP256SignDer(privateKey: privateKey, message: message);
+
+Future<Uint8List>  ecdsaP256SignDerAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP256SignDerAsync(privateKey: privateKey, message: message);
+
+/// Verify an r || s signature. Returns `Ok(false)` for a bad or malformed
+/// signature and an error only if the public key itself is unusable.
+bool  ecdsaP256Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP256Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  ecdsaP256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP256VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+/// Verify a DER signature, with the same error semantics as `ecdsa_p256_verify`.
+bool  ecdsaP256VerifyDer({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP256VerifyDer(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  ecdsaP256VerifyDerAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP256VerifyDerAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  ecdsaP256SignatureToDer({required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP256SignatureToDer(signature: signature);
+
+Uint8List  ecdsaP256SignatureFromDer({required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP256SignatureFromDer(signature: signature);
+
+EcdsaKeyPair  ecdsaP384GenerateKeypair() => RustLib.instance.api.crateEcdsaEcdsaP384GenerateKeypair();
+
+/// Rebuild a keypair from a previously exported 48-byte private scalar.
+EcdsaKeyPair  ecdsaP384KeypairFromPrivateKey({required List<int> privateKey }) => RustLib.instance.api.crateEcdsaEcdsaP384KeypairFromPrivateKey(privateKey: privateKey);
+
+/// Check that the bytes are a valid SEC1-encoded P-384 point.
+bool  ecdsaP384IsValidPublicKey({required List<int> publicKey }) => RustLib.instance.api.crateEcdsaEcdsaP384IsValidPublicKey(publicKey: publicKey);
+
+/// Sign with SHA-384, returning the 96-byte r || s encoding.
+Uint8List  ecdsaP384Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP384Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  ecdsaP384SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP384SignAsync(privateKey: privateKey, message: message);
+
+/// Sign with SHA-384, returning an ASN.1 DER signature.
+Uint8List  ecdsaP384SignDer({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP384SignDer(privateKey: privateKey, message: message);
+
+Future<Uint8List>  ecdsaP384SignDerAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateEcdsaEcdsaP384SignDerAsync(privateKey: privateKey, message: message);
+
+/// Verify an r || s signature. Returns `Ok(false)` for a bad or malformed
+/// signature and an error only if the public key itself is unusable.
+bool  ecdsaP384Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP384Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  ecdsaP384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP384VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+/// Verify a DER signature, with the same error semantics as `ecdsa_p384_verify`.
+bool  ecdsaP384VerifyDer({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP384VerifyDer(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  ecdsaP384VerifyDerAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP384VerifyDerAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  ecdsaP384SignatureToDer({required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP384SignatureToDer(signature: signature);
+
+Uint8List  ecdsaP384SignatureFromDer({required List<int> signature }) => RustLib.instance.api.crateEcdsaEcdsaP384SignatureFromDer(signature: signature);
+
+            class EcdsaKeyPair  {
+                final Uint8List privateKey;
+final Uint8List publicKey;
+
+                const EcdsaKeyPair({required this.privateKey ,required this.publicKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => privateKey.hashCode^publicKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EcdsaKeyPair &&
+                runtimeType == other.runtimeType
+                && privateKey == other.privateKey&& publicKey == other.publicKey;
+        
+            }
+            
\ No newline at end of file
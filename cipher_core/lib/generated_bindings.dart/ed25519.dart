@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `batchable_internal`, `ed25519_verify_batch_internal`, `ed25519_verify_internal`, `signature_internal`, `signing_key_internal`, `verifying_key_internal`
+
+
+            /// Generate a new keypair from a random seed.
+Ed25519KeyPair  ed25519GenerateKeypair() => RustLib.instance.api.crateEd25519Ed25519GenerateKeypair();
+
+/// Rebuild a keypair from a previously exported 32-byte seed.
+Ed25519KeyPair  ed25519KeypairFromSeed({required List<int> seed }) => RustLib.instance.api.crateEd25519Ed25519KeypairFromSeed(seed: seed);
+
+/// Check that 32 bytes decode to a valid Ed25519 public key.
+bool  ed25519IsValidPublicKey({required List<int> publicKey }) => RustLib.instance.api.crateEd25519Ed25519IsValidPublicKey(publicKey: publicKey);
+
+U8Array64  ed25519Sign({required List<int> seed , required List<int> message }) => RustLib.instance.api.crateEd25519Ed25519Sign(seed: seed, message: message);
+
+Future<U8Array64>  ed25519SignAsync({required List<int> seed , required List<int> message }) => RustLib.instance.api.crateEd25519Ed25519SignAsync(seed: seed, message: message);
+
+/// Returns `Ok(false)` for a bad or malformed signature and an error only
+/// if the public key itself is unusable.
+bool  ed25519Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEd25519Ed25519Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  ed25519VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateEd25519Ed25519VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+bool  ed25519VerifyBatch({required List<Uint8List> publicKeys , required List<Uint8List> messages , required List<Uint8List> signatures }) => RustLib.instance.api.crateEd25519Ed25519VerifyBatch(publicKeys: publicKeys, messages: messages, signatures: signatures);
+
+Future<bool>  ed25519VerifyBatchAsync({required List<Uint8List> publicKeys , required List<Uint8List> messages , required List<Uint8List> signatures }) => RustLib.instance.api.crateEd25519Ed25519VerifyBatchAsync(publicKeys: publicKeys, messages: messages, signatures: signatures);
+
+            class Ed25519KeyPair  {
+                final U8Array32 seed;
+final U8Array32 publicKey;
+
+                const Ed25519KeyPair({required this.seed ,required this.publicKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => seed.hashCode^publicKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Ed25519KeyPair &&
+                runtimeType == other.runtimeType
+                && seed == other.seed&& publicKey == other.publicKey;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_distinct_paths`, `decrypt_file_internal`, `decrypt_range_internal`, `encrypt_file_internal`, `from_file_len`, `open_encrypted`, `parse`, `sealed_len`, `sealed_offset`, `stream`, `to_bytes`, `write_output_internal`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FileHeader`, `Layout`
+
+
+            /// Encrypt `input_path` into `output_path` using `chunk_size`-byte chunks
+/// (0 selects `DEFAULT_CHUNK_SIZE`, at most `MAX_CHUNK_SIZE`). Returns the
+/// plaintext length.
+/// The output is written to a temporary file and renamed into place, so on
+/// failure an existing file at `output_path` is left untouched.
+BigInt  encryptFile({required String inputPath , required String outputPath , required List<int> key , required int chunkSize }) => RustLib.instance.api.crateEncryptedFileEncryptFile(inputPath: inputPath, outputPath: outputPath, key: key, chunkSize: chunkSize);
+
+Future<BigInt>  encryptFileAsync({required String inputPath , required String outputPath , required List<int> key , required int chunkSize }) => RustLib.instance.api.crateEncryptedFileEncryptFileAsync(inputPath: inputPath, outputPath: outputPath, key: key, chunkSize: chunkSize);
+
+/// Decrypt a whole encrypted file into `output_path`. Returns the plaintext
+/// length. Written like `encrypt_file`: nothing at `output_path` changes
+/// unless the whole file authenticates.
+BigInt  decryptFile({required String inputPath , required String outputPath , required List<int> key }) => RustLib.instance.api.crateEncryptedFileDecryptFile(inputPath: inputPath, outputPath: outputPath, key: key);
+
+Future<BigInt>  decryptFileAsync({required String inputPath , required String outputPath , required List<int> key }) => RustLib.instance.api.crateEncryptedFileDecryptFileAsync(inputPath: inputPath, outputPath: outputPath, key: key);
+
+/// Decrypt `len` plaintext bytes starting at `offset`, touching only the
+/// chunks that cover the range. The result is clamped to the end of the file.
+Uint8List  decryptRange({required String path , required List<int> key , required BigInt offset , required BigInt len }) => RustLib.instance.api.crateEncryptedFileDecryptRange(path: path, key: key, offset: offset, len: len);
+
+Future<Uint8List>  decryptRangeAsync({required String path , required List<int> key , required BigInt offset , required BigInt len }) => RustLib.instance.api.crateEncryptedFileDecryptRangeAsync(path: path, key: key, offset: offset, len: len);
+
+/// Plaintext length of an encrypted file, computed from its header and size.
+BigInt  encryptedFilePlaintextLen({required String path }) => RustLib.instance.api.crateEncryptedFileEncryptedFilePlaintextLen(path: path);
+
+            
+            
\ No newline at end of file
@@ -3,3207 +3,16914 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'aes_modes.dart';
+import 'batch.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'ecdsa.dart';
+import 'ed25519.dart';
+import 'encrypted_file.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'hash_algorithm.dart';
+import 'jwe.dart';
+import 'jws.dart';
+import 'kdf.dart';
+import 'key_wrap.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'rsa_crypto.dart';
+import 'siv.dart';
+import 'stream.dart';
+import 'x25519.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1818424557;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  U8Array20 crateSha1HasherFinalize({required Sha1Hasher that});
+                  RustLib._();
 
-  Sha1Hasher crateSha1HasherNew();
-
-  void crateSha1HasherUpdate({
-    required Sha1Hasher that,
-    required List<int> data,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  U8Array32 crateSha256HasherFinalize({required Sha256Hasher that});
-
-  Sha256Hasher crateSha256HasherNew();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  void crateSha256HasherUpdate({
-    required Sha256Hasher that,
-    required List<int> data,
-  });
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  U8Array32 crateSha256HmacHasherFinalize({required Sha256HmacHasher that});
-
-  Sha256HmacHasher? crateSha256HmacHasherNew({required List<int> key});
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  void crateSha256HmacHasherUpdate({
-    required Sha256HmacHasher that,
-    required List<int> data,
-  });
-
-  U8Array64 crateSha512HasherFinalize({required Sha512Hasher that});
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Sha512Hasher crateSha512HasherNew();
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  void crateSha512HasherUpdate({
-    required Sha512Hasher that,
-    required List<int> data,
-  });
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Uint8List? crateAes256Decrypt({
-    required List<int> ciphertext,
-    required List<int> key,
-  });
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  Future<Uint8List?> crateAes256DecryptAsync({
-    required List<int> ciphertext,
-    required List<int> key,
-  });
-
-  Uint8List? crateAes256Encrypt({
-    required List<int> plaintext,
-    required List<int> key,
-  });
+                  @override
+                  int get rustContentHash => -2115765333;
 
-  Future<Uint8List?> crateAes256EncryptAsync({
-    required List<int> plaintext,
-    required List<int> key,
-  });
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  (Uint8List, U8Array32)? crateEncryptThenHmac({
-    required List<int> plaintext,
-    required List<int> encKey,
-    required List<int> macKey,
-  });
+                abstract class RustLibApi extends BaseApi {
+                  Uint8List crateStreamAes256GcmStreamDecryptorDecryptChunk({required Aes256GcmStreamDecryptor that , required List<int> chunk });
 
-  Future<(Uint8List, U8Array32)?> crateEncryptThenHmacAsync({
-    required List<int> plaintext,
-    required List<int> encKey,
-    required List<int> macKey,
-  });
+Uint8List crateStreamAes256GcmStreamDecryptorFinalize({required Aes256GcmStreamDecryptor that , required List<int> chunk });
 
-  Uint8List? crateFromHex({required String hexString});
+Aes256GcmStreamDecryptor crateStreamAes256GcmStreamDecryptorNew({required List<int> key , required List<int> header });
 
-  List<String> crateGetAllAlgorithms();
+Uint8List crateStreamAes256GcmStreamEncryptorEncryptChunk({required Aes256GcmStreamEncryptor that , required List<int> chunk });
 
-  BigInt crateHashSize({required String algorithm});
+Uint8List crateStreamAes256GcmStreamEncryptorFinalize({required Aes256GcmStreamEncryptor that , required List<int> chunk });
 
-  Uint8List? crateHashThenEncrypt({
-    required List<int> data,
-    required List<int> key,
-  });
+Uint8List crateStreamAes256GcmStreamEncryptorHeader({required Aes256GcmStreamEncryptor that });
 
-  Future<Uint8List?> crateHashThenEncryptAsync({
-    required List<int> data,
-    required List<int> key,
-  });
+Aes256GcmStreamEncryptor crateStreamAes256GcmStreamEncryptorNew({required List<int> key });
 
-  U8Array16 crateHmacMd5({required List<int> key, required List<int> data});
+Uint8List crateBlake2BHasherFinalize({required Blake2BHasher that });
 
-  Future<U8Array16> crateHmacMd5Async({
-    required List<int> key,
-    required List<int> data,
-  });
+Blake2BHasher crateBlake2BHasherFork({required Blake2BHasher that });
 
-  U8Array20 crateHmacSha1({required List<int> key, required List<int> data});
+Blake2BHasher crateBlake2BHasherNew();
 
-  Future<U8Array20> crateHmacSha1Async({
-    required List<int> key,
-    required List<int> data,
-  });
+Blake2BHasher crateBlake2BHasherNewKeyed({required List<int> key , required BigInt outputLen });
 
-  U8Array28 crateHmacSha224({required List<int> key, required List<int> data});
+void crateBlake2BHasherReset({required Blake2BHasher that });
 
-  Future<U8Array28> crateHmacSha224Async({
-    required List<int> key,
-    required List<int> data,
-  });
+void crateBlake2BHasherUpdate({required Blake2BHasher that , required List<int> data });
 
-  U8Array32 crateHmacSha256({required List<int> key, required List<int> data});
+Uint8List crateBlake2SHasherFinalize({required Blake2SHasher that });
 
-  Future<U8Array32> crateHmacSha256Async({
-    required List<int> key,
-    required List<int> data,
-  });
+Blake2SHasher crateBlake2SHasherFork({required Blake2SHasher that });
 
-  List<U8Array32> crateHmacSha256Batch({
-    required List<int> key,
-    required List<Uint8List> messages,
-  });
+Blake2SHasher crateBlake2SHasherNew();
 
-  Future<List<U8Array32>> crateHmacSha256BatchAsync({
-    required List<int> key,
-    required List<Uint8List> messages,
-  });
+Blake2SHasher crateBlake2SHasherNewKeyed({required List<int> key , required BigInt outputLen });
 
-  U8Array48 crateHmacSha384({required List<int> key, required List<int> data});
+void crateBlake2SHasherReset({required Blake2SHasher that });
 
-  Future<U8Array48> crateHmacSha384Async({
-    required List<int> key,
-    required List<int> data,
-  });
+void crateBlake2SHasherUpdate({required Blake2SHasher that , required List<int> data });
 
-  U8Array64 crateHmacSha512({required List<int> key, required List<int> data});
+U8Array32 crateBlake3HasherFinalize({required Blake3Hasher that });
 
-  Future<U8Array64> crateHmacSha512Async({
-    required List<int> key,
-    required List<int> data,
-  });
+Blake3Hasher crateBlake3HasherFork({required Blake3Hasher that });
 
-  List<U8Array64> crateHmacSha512Batch({
-    required List<int> key,
-    required List<Uint8List> messages,
-  });
+Blake3Hasher crateBlake3HasherNew();
 
-  Future<List<U8Array64>> crateHmacSha512BatchAsync({
-    required List<int> key,
-    required List<Uint8List> messages,
-  });
+Blake3Hasher crateBlake3HasherNewDeriveKey({required String context });
 
-  U8Array16 crateMd5({required List<int> data});
+Blake3Hasher crateBlake3HasherNewKeyed({required List<int> key });
 
-  Future<U8Array16> crateMd5Async({required List<int> data});
+void crateBlake3HasherReset({required Blake3Hasher that });
 
-  U8Array20 crateSha1({required List<int> data});
+void crateBlake3HasherUpdate({required Blake3Hasher that , required List<int> data });
 
-  Future<U8Array20> crateSha1Async({required List<int> data});
+HashAlgorithm crateHashAlgorithmHasherAlgorithm({required Hasher that });
 
-  U8Array28 crateSha224({required List<int> data});
+Uint8List crateHashAlgorithmHasherExportState({required Hasher that });
 
-  Future<U8Array28> crateSha224Async({required List<int> data});
+Uint8List crateHashAlgorithmHasherFinalize({required Hasher that });
 
-  U8Array32 crateSha256({required List<int> data});
+Hasher crateHashAlgorithmHasherFork({required Hasher that });
 
-  Future<U8Array32> crateSha256Async({required List<int> data});
+Hasher crateHashAlgorithmHasherImportState({required List<int> state });
 
-  List<U8Array32> crateSha256Batch({required List<Uint8List> inputs});
+void crateHashAlgorithmHasherReset({required Hasher that });
 
-  Future<List<U8Array32>> crateSha256BatchAsync({
-    required List<Uint8List> inputs,
-  });
+void crateHashAlgorithmHasherUpdate({required Hasher that , required List<int> data });
 
-  U8Array48 crateSha384({required List<int> data});
+Uint8List crateKeccak256HasherExportState({required Keccak256Hasher that });
 
-  Future<U8Array48> crateSha384Async({required List<int> data});
+U8Array32 crateKeccak256HasherFinalize({required Keccak256Hasher that });
 
-  U8Array64 crateSha512({required List<int> data});
+Keccak256Hasher crateKeccak256HasherFork({required Keccak256Hasher that });
 
-  U8Array28 crateSha512224({required List<int> data});
+Keccak256Hasher crateKeccak256HasherImportState({required List<int> state });
 
-  Future<U8Array28> crateSha512224Async({required List<int> data});
+Keccak256Hasher crateKeccak256HasherNew();
 
-  U8Array32 crateSha512256({required List<int> data});
+void crateKeccak256HasherReset({required Keccak256Hasher that });
 
-  Future<U8Array32> crateSha512256Async({required List<int> data});
+void crateKeccak256HasherUpdate({required Keccak256Hasher that , required List<int> data });
 
-  Future<U8Array64> crateSha512Async({required List<int> data});
+Uint8List crateMd5HasherExportState({required Md5Hasher that });
 
-  List<U8Array64> crateSha512Batch({required List<Uint8List> inputs});
+U8Array16 crateMd5HasherFinalize({required Md5Hasher that });
 
-  Future<List<U8Array64>> crateSha512BatchAsync({
-    required List<Uint8List> inputs,
-  });
+Md5Hasher crateMd5HasherFork({required Md5Hasher that });
 
-  String crateToHex({required List<int> bytes});
+Md5Hasher crateMd5HasherImportState({required List<int> state });
 
-  Uint8List? crateVerifyHmacThenDecrypt({
-    required List<int> ciphertext,
-    required List<int> mac,
-    required List<int> encKey,
-    required List<int> macKey,
-  });
+Md5Hasher crateMd5HasherNew();
 
-  Future<Uint8List?> crateVerifyHmacThenDecryptAsync({
-    required List<int> ciphertext,
-    required List<int> mac,
-    required List<int> encKey,
-    required List<int> macKey,
-  });
+void crateMd5HasherReset({required Md5Hasher that });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha1Hasher;
+void crateMd5HasherUpdate({required Md5Hasher that , required List<int> data });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha1Hasher;
+Uint8List crateMd5HmacHasherExportState({required Md5HmacHasher that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha1HasherPtr;
+U8Array16 crateMd5HmacHasherFinalize({required Md5HmacHasher that });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha256Hasher;
+Md5HmacHasher crateMd5HmacHasherFork({required Md5HmacHasher that });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha256Hasher;
+Md5HmacHasher crateMd5HmacHasherImportState({required List<int> state });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha256HasherPtr;
+Md5HmacHasher crateMd5HmacHasherNew({required List<int> key });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha256HmacHasher;
+void crateMd5HmacHasherReset({required Md5HmacHasher that });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha256HmacHasher;
+void crateMd5HmacHasherUpdate({required Md5HmacHasher that , required List<int> data });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_Sha256HmacHasherPtr;
+Uint8List crateSha1HasherExportState({required Sha1Hasher that });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha512Hasher;
+U8Array20 crateSha1HasherFinalize({required Sha1Hasher that });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha512Hasher;
+Sha1Hasher crateSha1HasherFork({required Sha1Hasher that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha512HasherPtr;
-}
+Sha1Hasher crateSha1HasherImportState({required List<int> state });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+Sha1Hasher crateSha1HasherNew();
 
-  @override
-  U8Array20 crateSha1HasherFinalize({required Sha1Hasher that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+void crateSha1HasherReset({required Sha1Hasher that });
+
+void crateSha1HasherUpdate({required Sha1Hasher that , required List<int> data });
+
+Uint8List crateSha1HmacHasherExportState({required Sha1HmacHasher that });
+
+U8Array20 crateSha1HmacHasherFinalize({required Sha1HmacHasher that });
+
+Sha1HmacHasher crateSha1HmacHasherFork({required Sha1HmacHasher that });
+
+Sha1HmacHasher crateSha1HmacHasherImportState({required List<int> state });
+
+Sha1HmacHasher crateSha1HmacHasherNew({required List<int> key });
+
+void crateSha1HmacHasherReset({required Sha1HmacHasher that });
+
+void crateSha1HmacHasherUpdate({required Sha1HmacHasher that , required List<int> data });
+
+Uint8List crateSha224HasherExportState({required Sha224Hasher that });
+
+U8Array28 crateSha224HasherFinalize({required Sha224Hasher that });
+
+Sha224Hasher crateSha224HasherFork({required Sha224Hasher that });
+
+Sha224Hasher crateSha224HasherImportState({required List<int> state });
+
+Sha224Hasher crateSha224HasherNew();
+
+void crateSha224HasherReset({required Sha224Hasher that });
+
+void crateSha224HasherUpdate({required Sha224Hasher that , required List<int> data });
+
+Uint8List crateSha224HmacHasherExportState({required Sha224HmacHasher that });
+
+U8Array28 crateSha224HmacHasherFinalize({required Sha224HmacHasher that });
+
+Sha224HmacHasher crateSha224HmacHasherFork({required Sha224HmacHasher that });
+
+Sha224HmacHasher crateSha224HmacHasherImportState({required List<int> state });
+
+Sha224HmacHasher crateSha224HmacHasherNew({required List<int> key });
+
+void crateSha224HmacHasherReset({required Sha224HmacHasher that });
+
+void crateSha224HmacHasherUpdate({required Sha224HmacHasher that , required List<int> data });
+
+Uint8List crateSha256HasherExportState({required Sha256Hasher that });
+
+U8Array32 crateSha256HasherFinalize({required Sha256Hasher that });
+
+Sha256Hasher crateSha256HasherFork({required Sha256Hasher that });
+
+Sha256Hasher crateSha256HasherImportState({required List<int> state });
+
+Sha256Hasher crateSha256HasherNew();
+
+void crateSha256HasherReset({required Sha256Hasher that });
+
+void crateSha256HasherUpdate({required Sha256Hasher that , required List<int> data });
+
+Uint8List crateSha256HmacHasherExportState({required Sha256HmacHasher that });
+
+U8Array32 crateSha256HmacHasherFinalize({required Sha256HmacHasher that });
+
+Sha256HmacHasher crateSha256HmacHasherFork({required Sha256HmacHasher that });
+
+Sha256HmacHasher crateSha256HmacHasherImportState({required List<int> state });
+
+Sha256HmacHasher crateSha256HmacHasherNew({required List<int> key });
+
+void crateSha256HmacHasherReset({required Sha256HmacHasher that });
+
+void crateSha256HmacHasherUpdate({required Sha256HmacHasher that , required List<int> data });
+
+Uint8List crateSha3224HasherExportState({required Sha3224Hasher that });
+
+U8Array28 crateSha3224HasherFinalize({required Sha3224Hasher that });
+
+Sha3224Hasher crateSha3224HasherFork({required Sha3224Hasher that });
+
+Sha3224Hasher crateSha3224HasherImportState({required List<int> state });
+
+Sha3224Hasher crateSha3224HasherNew();
+
+void crateSha3224HasherReset({required Sha3224Hasher that });
+
+void crateSha3224HasherUpdate({required Sha3224Hasher that , required List<int> data });
+
+Uint8List crateSha3256HasherExportState({required Sha3256Hasher that });
+
+U8Array32 crateSha3256HasherFinalize({required Sha3256Hasher that });
+
+Sha3256Hasher crateSha3256HasherFork({required Sha3256Hasher that });
+
+Sha3256Hasher crateSha3256HasherImportState({required List<int> state });
+
+Sha3256Hasher crateSha3256HasherNew();
+
+void crateSha3256HasherReset({required Sha3256Hasher that });
+
+void crateSha3256HasherUpdate({required Sha3256Hasher that , required List<int> data });
+
+Uint8List crateSha3384HasherExportState({required Sha3384Hasher that });
+
+U8Array48 crateSha3384HasherFinalize({required Sha3384Hasher that });
+
+Sha3384Hasher crateSha3384HasherFork({required Sha3384Hasher that });
+
+Sha3384Hasher crateSha3384HasherImportState({required List<int> state });
+
+Sha3384Hasher crateSha3384HasherNew();
+
+void crateSha3384HasherReset({required Sha3384Hasher that });
+
+void crateSha3384HasherUpdate({required Sha3384Hasher that , required List<int> data });
+
+Uint8List crateSha3512HasherExportState({required Sha3512Hasher that });
+
+U8Array64 crateSha3512HasherFinalize({required Sha3512Hasher that });
+
+Sha3512Hasher crateSha3512HasherFork({required Sha3512Hasher that });
+
+Sha3512Hasher crateSha3512HasherImportState({required List<int> state });
+
+Sha3512Hasher crateSha3512HasherNew();
+
+void crateSha3512HasherReset({required Sha3512Hasher that });
+
+void crateSha3512HasherUpdate({required Sha3512Hasher that , required List<int> data });
+
+Uint8List crateSha384HasherExportState({required Sha384Hasher that });
+
+U8Array48 crateSha384HasherFinalize({required Sha384Hasher that });
+
+Sha384Hasher crateSha384HasherFork({required Sha384Hasher that });
+
+Sha384Hasher crateSha384HasherImportState({required List<int> state });
+
+Sha384Hasher crateSha384HasherNew();
+
+void crateSha384HasherReset({required Sha384Hasher that });
+
+void crateSha384HasherUpdate({required Sha384Hasher that , required List<int> data });
+
+Uint8List crateSha384HmacHasherExportState({required Sha384HmacHasher that });
+
+U8Array48 crateSha384HmacHasherFinalize({required Sha384HmacHasher that });
+
+Sha384HmacHasher crateSha384HmacHasherFork({required Sha384HmacHasher that });
+
+Sha384HmacHasher crateSha384HmacHasherImportState({required List<int> state });
+
+Sha384HmacHasher crateSha384HmacHasherNew({required List<int> key });
+
+void crateSha384HmacHasherReset({required Sha384HmacHasher that });
+
+void crateSha384HmacHasherUpdate({required Sha384HmacHasher that , required List<int> data });
+
+Uint8List crateSha512224HasherExportState({required Sha512224Hasher that });
+
+U8Array28 crateSha512224HasherFinalize({required Sha512224Hasher that });
+
+Sha512224Hasher crateSha512224HasherFork({required Sha512224Hasher that });
+
+Sha512224Hasher crateSha512224HasherImportState({required List<int> state });
+
+Sha512224Hasher crateSha512224HasherNew();
+
+void crateSha512224HasherReset({required Sha512224Hasher that });
+
+void crateSha512224HasherUpdate({required Sha512224Hasher that , required List<int> data });
+
+Uint8List crateSha512256HasherExportState({required Sha512256Hasher that });
+
+U8Array32 crateSha512256HasherFinalize({required Sha512256Hasher that });
+
+Sha512256Hasher crateSha512256HasherFork({required Sha512256Hasher that });
+
+Sha512256Hasher crateSha512256HasherImportState({required List<int> state });
+
+Sha512256Hasher crateSha512256HasherNew();
+
+void crateSha512256HasherReset({required Sha512256Hasher that });
+
+void crateSha512256HasherUpdate({required Sha512256Hasher that , required List<int> data });
+
+Uint8List crateSha512HasherExportState({required Sha512Hasher that });
+
+U8Array64 crateSha512HasherFinalize({required Sha512Hasher that });
+
+Sha512Hasher crateSha512HasherFork({required Sha512Hasher that });
+
+Sha512Hasher crateSha512HasherImportState({required List<int> state });
+
+Sha512Hasher crateSha512HasherNew();
+
+void crateSha512HasherReset({required Sha512Hasher that });
+
+void crateSha512HasherUpdate({required Sha512Hasher that , required List<int> data });
+
+Uint8List crateSha512HmacHasherExportState({required Sha512HmacHasher that });
+
+U8Array64 crateSha512HmacHasherFinalize({required Sha512HmacHasher that });
+
+Sha512HmacHasher crateSha512HmacHasherFork({required Sha512HmacHasher that });
+
+Sha512HmacHasher crateSha512HmacHasherImportState({required List<int> state });
+
+Sha512HmacHasher crateSha512HmacHasherNew({required List<int> key });
+
+void crateSha512HmacHasherReset({required Sha512HmacHasher that });
+
+void crateSha512HmacHasherUpdate({required Sha512HmacHasher that , required List<int> data });
+
+Uint8List crateShake128HasherExportState({required Shake128Hasher that });
+
+Uint8List crateShake128HasherFinalize({required Shake128Hasher that , required BigInt outputLen });
+
+Shake128Hasher crateShake128HasherFork({required Shake128Hasher that });
+
+Shake128Hasher crateShake128HasherImportState({required List<int> state });
+
+Shake128Hasher crateShake128HasherNew();
+
+void crateShake128HasherReset({required Shake128Hasher that });
+
+void crateShake128HasherUpdate({required Shake128Hasher that , required List<int> data });
+
+Uint8List crateShake256HasherExportState({required Shake256Hasher that });
+
+Uint8List crateShake256HasherFinalize({required Shake256Hasher that , required BigInt outputLen });
+
+Shake256Hasher crateShake256HasherFork({required Shake256Hasher that });
+
+Shake256Hasher crateShake256HasherImportState({required List<int> state });
+
+Shake256Hasher crateShake256HasherNew();
+
+void crateShake256HasherReset({required Shake256Hasher that });
+
+void crateShake256HasherUpdate({required Shake256Hasher that , required List<int> data });
+
+Uint8List crateAes256Decrypt({required List<int> ciphertext , required List<int> key });
+
+Future<Uint8List> crateAes256DecryptAsync({required List<int> ciphertext , required List<int> key });
+
+Uint8List crateAes256DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateAes256DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateAes256Encrypt({required List<int> plaintext , required List<int> key });
+
+Future<Uint8List> crateAes256EncryptAsync({required List<int> plaintext , required List<int> key });
+
+Uint8List crateAes256EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateAes256EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAes256GcmSivDecrypt({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAes256GcmSivDecryptAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAes256GcmSivDecryptDeterministic({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAes256GcmSivDecryptDeterministicAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAes256GcmSivEncrypt({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAes256GcmSivEncryptAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAes256GcmSivEncryptDeterministic({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAes256GcmSivEncryptDeterministicAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Uint8List crateAesModesAesCbcDecrypt({required List<int> ciphertext , required List<int> key });
+
+Future<Uint8List> crateAesModesAesCbcDecryptAsync({required List<int> ciphertext , required List<int> key });
+
+Uint8List crateAesModesAesCbcDecryptWithIv({required List<int> ciphertext , required List<int> key , required List<int> iv });
+
+Future<Uint8List> crateAesModesAesCbcDecryptWithIvAsync({required List<int> ciphertext , required List<int> key , required List<int> iv });
+
+Uint8List crateAesModesAesCbcEncrypt({required List<int> plaintext , required List<int> key });
+
+Future<Uint8List> crateAesModesAesCbcEncryptAsync({required List<int> plaintext , required List<int> key });
+
+Uint8List crateAesModesAesCbcEncryptWithIv({required List<int> plaintext , required List<int> key , required List<int> iv });
+
+Future<Uint8List> crateAesModesAesCbcEncryptWithIvAsync({required List<int> plaintext , required List<int> key , required List<int> iv });
+
+Uint8List crateAesModesAesCbcHmacSha256Decrypt({required List<int> ciphertext , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+Future<Uint8List> crateAesModesAesCbcHmacSha256DecryptAsync({required List<int> ciphertext , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+Uint8List crateAesModesAesCbcHmacSha256Encrypt({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+Future<Uint8List> crateAesModesAesCbcHmacSha256EncryptAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+Uint8List crateAesModesAesCtrApplyKeystream({required List<int> data , required List<int> key , required List<int> iv });
+
+Future<Uint8List> crateAesModesAesCtrApplyKeystreamAsync({required List<int> data , required List<int> key , required List<int> iv });
+
+Uint8List crateAesModesAesCtrDecrypt({required List<int> ciphertext , required List<int> key });
+
+Future<Uint8List> crateAesModesAesCtrDecryptAsync({required List<int> ciphertext , required List<int> key });
+
+Uint8List crateAesModesAesCtrEncrypt({required List<int> plaintext , required List<int> key });
+
+Future<Uint8List> crateAesModesAesCtrEncryptAsync({required List<int> plaintext , required List<int> key });
+
+Uint8List crateKeyWrapAesKeyUnwrap({required List<int> wrappedKey , required List<int> kek });
+
+Future<Uint8List> crateKeyWrapAesKeyUnwrapAsync({required List<int> wrappedKey , required List<int> kek });
+
+Uint8List crateKeyWrapAesKeyUnwrapWithPadding({required List<int> wrappedKey , required List<int> kek });
+
+Future<Uint8List> crateKeyWrapAesKeyUnwrapWithPaddingAsync({required List<int> wrappedKey , required List<int> kek });
+
+Uint8List crateKeyWrapAesKeyWrap({required List<int> key , required List<int> kek });
+
+Future<Uint8List> crateKeyWrapAesKeyWrapAsync({required List<int> key , required List<int> kek });
+
+Uint8List crateKeyWrapAesKeyWrapWithPadding({required List<int> key , required List<int> kek });
+
+Future<Uint8List> crateKeyWrapAesKeyWrapWithPaddingAsync({required List<int> key , required List<int> kek });
+
+Uint8List crateSivAesSivDecrypt({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAesSivDecryptAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAesSivDecryptDeterministic({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAesSivDecryptDeterministicAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAesSivEncrypt({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAesSivEncryptAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Uint8List crateSivAesSivEncryptDeterministic({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateSivAesSivEncryptDeterministicAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+List<HashAlgorithmInfo> crateHashAlgorithmAllHashAlgorithms();
+
+bool crateKdfArgon2VerifyPassword({required List<int> password , required String phc });
+
+Future<bool> crateKdfArgon2VerifyPasswordAsync({required List<int> password , required String phc });
+
+Uint8List crateKdfArgon2IdDeriveKey({required List<int> password , required List<int> salt , required int memoryKib , required int iterations , required int parallelism , required BigInt outputLen });
+
+Future<Uint8List> crateKdfArgon2IdDeriveKeyAsync({required List<int> password , required List<int> salt , required int memoryKib , required int iterations , required int parallelism , required BigInt outputLen });
+
+String crateKdfArgon2IdHashPassword({required List<int> password , required int memoryKib , required int iterations , required int parallelism });
+
+Future<String> crateKdfArgon2IdHashPasswordAsync({required List<int> password , required int memoryKib , required int iterations , required int parallelism });
+
+BigInt crateBatchBatchParallelism();
+
+String crateKdfBcryptHash({required List<int> password , required int cost });
+
+Future<String> crateKdfBcryptHashAsync({required List<int> password , required int cost });
+
+bool crateKdfBcryptVerify({required List<int> password , required String hash });
+
+Future<bool> crateKdfBcryptVerifyAsync({required List<int> password , required String hash });
+
+U8Array64 crateBlake2B({required List<int> data });
+
+Future<U8Array64> crateBlake2BAsync({required List<int> data });
+
+List<U8Array64> crateBlake2BBatch({required List<Uint8List> inputs });
+
+Future<List<U8Array64>> crateBlake2BBatchAsync({required List<Uint8List> inputs });
+
+Uint8List crateBlake2BKeyed({required List<int> key , required List<int> data , required BigInt outputLen });
+
+Future<Uint8List> crateBlake2BKeyedAsync({required List<int> key , required List<int> data , required BigInt outputLen });
+
+Uint8List crateBlake2BWithLen({required List<int> data , required BigInt outputLen });
+
+Future<Uint8List> crateBlake2BWithLenAsync({required List<int> data , required BigInt outputLen });
+
+U8Array32 crateBlake2S({required List<int> data });
+
+Future<U8Array32> crateBlake2SAsync({required List<int> data });
+
+List<U8Array32> crateBlake2SBatch({required List<Uint8List> inputs });
+
+Future<List<U8Array32>> crateBlake2SBatchAsync({required List<Uint8List> inputs });
+
+Uint8List crateBlake2SKeyed({required List<int> key , required List<int> data , required BigInt outputLen });
+
+Future<Uint8List> crateBlake2SKeyedAsync({required List<int> key , required List<int> data , required BigInt outputLen });
+
+Uint8List crateBlake2SWithLen({required List<int> data , required BigInt outputLen });
+
+Future<Uint8List> crateBlake2SWithLenAsync({required List<int> data , required BigInt outputLen });
+
+U8Array32 crateBlake3({required List<int> data });
+
+Future<U8Array32> crateBlake3Async({required List<int> data });
+
+List<U8Array32> crateBlake3Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array32>> crateBlake3BatchAsync({required List<Uint8List> inputs });
+
+U8Array32 crateBlake3DeriveKey({required String context , required List<int> keyMaterial });
+
+Future<U8Array32> crateBlake3DeriveKeyAsync({required String context , required List<int> keyMaterial });
+
+U8Array32 crateBlake3Keyed({required List<int> key , required List<int> data });
+
+Future<U8Array32> crateBlake3KeyedAsync({required List<int> key , required List<int> data });
+
+Uint8List crateChacha20Poly1305Decrypt({required List<int> ciphertext , required List<int> key });
+
+Future<Uint8List> crateChacha20Poly1305DecryptAsync({required List<int> ciphertext , required List<int> key });
+
+Uint8List crateChacha20Poly1305DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateChacha20Poly1305DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateChacha20Poly1305Encrypt({required List<int> plaintext , required List<int> key });
+
+Future<Uint8List> crateChacha20Poly1305EncryptAsync({required List<int> plaintext , required List<int> key });
+
+Uint8List crateChacha20Poly1305EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateChacha20Poly1305EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+bool crateConstantTimeEq({required List<int> a , required List<int> b });
+
+BigInt crateEncryptedFileDecryptFile({required String inputPath , required String outputPath , required List<int> key });
+
+Future<BigInt> crateEncryptedFileDecryptFileAsync({required String inputPath , required String outputPath , required List<int> key });
+
+Uint8List crateEncryptedFileDecryptRange({required String path , required List<int> key , required BigInt offset , required BigInt len });
+
+Future<Uint8List> crateEncryptedFileDecryptRangeAsync({required String path , required List<int> key , required BigInt offset , required BigInt len });
+
+EcdsaKeyPair crateEcdsaEcdsaP256GenerateKeypair();
+
+bool crateEcdsaEcdsaP256IsValidPublicKey({required List<int> publicKey });
+
+EcdsaKeyPair crateEcdsaEcdsaP256KeypairFromPrivateKey({required List<int> privateKey });
+
+Uint8List crateEcdsaEcdsaP256Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateEcdsaEcdsaP256SignAsync({required List<int> privateKey , required List<int> message });
+
+Uint8List crateEcdsaEcdsaP256SignDer({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateEcdsaEcdsaP256SignDerAsync({required List<int> privateKey , required List<int> message });
+
+Uint8List crateEcdsaEcdsaP256SignatureFromDer({required List<int> signature });
+
+Uint8List crateEcdsaEcdsaP256SignatureToDer({required List<int> signature });
+
+bool crateEcdsaEcdsaP256Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateEcdsaEcdsaP256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+bool crateEcdsaEcdsaP256VerifyDer({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateEcdsaEcdsaP256VerifyDerAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+EcdsaKeyPair crateEcdsaEcdsaP384GenerateKeypair();
+
+bool crateEcdsaEcdsaP384IsValidPublicKey({required List<int> publicKey });
+
+EcdsaKeyPair crateEcdsaEcdsaP384KeypairFromPrivateKey({required List<int> privateKey });
+
+Uint8List crateEcdsaEcdsaP384Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateEcdsaEcdsaP384SignAsync({required List<int> privateKey , required List<int> message });
+
+Uint8List crateEcdsaEcdsaP384SignDer({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateEcdsaEcdsaP384SignDerAsync({required List<int> privateKey , required List<int> message });
+
+Uint8List crateEcdsaEcdsaP384SignatureFromDer({required List<int> signature });
+
+Uint8List crateEcdsaEcdsaP384SignatureToDer({required List<int> signature });
+
+bool crateEcdsaEcdsaP384Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateEcdsaEcdsaP384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+bool crateEcdsaEcdsaP384VerifyDer({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateEcdsaEcdsaP384VerifyDerAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Ed25519KeyPair crateEd25519Ed25519GenerateKeypair();
+
+bool crateEd25519Ed25519IsValidPublicKey({required List<int> publicKey });
+
+Ed25519KeyPair crateEd25519Ed25519KeypairFromSeed({required List<int> seed });
+
+U8Array64 crateEd25519Ed25519Sign({required List<int> seed , required List<int> message });
+
+Future<U8Array64> crateEd25519Ed25519SignAsync({required List<int> seed , required List<int> message });
+
+bool crateEd25519Ed25519Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateEd25519Ed25519VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+bool crateEd25519Ed25519VerifyBatch({required List<Uint8List> publicKeys , required List<Uint8List> messages , required List<Uint8List> signatures });
+
+Future<bool> crateEd25519Ed25519VerifyBatchAsync({required List<Uint8List> publicKeys , required List<Uint8List> messages , required List<Uint8List> signatures });
+
+BigInt crateEncryptedFileEncryptFile({required String inputPath , required String outputPath , required List<int> key , required int chunkSize });
+
+Future<BigInt> crateEncryptedFileEncryptFileAsync({required String inputPath , required String outputPath , required List<int> key , required int chunkSize });
+
+(Uint8List,U8Array32) crateEncryptThenHmac({required List<int> plaintext , required List<int> encKey , required List<int> macKey });
+
+Future<(Uint8List,U8Array32)> crateEncryptThenHmacAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey });
+
+(Uint8List,U8Array32) crateEncryptThenHmacWithAad({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+Future<(Uint8List,U8Array32)> crateEncryptThenHmacWithAadAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+BigInt crateEncryptedFileEncryptedFilePlaintextLen({required String path });
+
+Uint8List crateFromHex({required String hexString });
+
+List<String> crateGetAllAlgorithms();
+
+Uint8List crateHashAlgorithmHash({required HashAlgorithm algorithm , required List<int> data });
+
+HashAlgorithm crateHashAlgorithmHashAlgorithmFromName({required String name });
+
+HashAlgorithmInfo crateHashAlgorithmHashAlgorithmInfo({required HashAlgorithm algorithm });
+
+Future<Uint8List> crateHashAlgorithmHashAsync({required HashAlgorithm algorithm , required List<int> data });
+
+List<Uint8List> crateBatchHashBatch({required HashAlgorithm algorithm , required List<Uint8List> inputs });
+
+Future<List<Uint8List>> crateBatchHashBatchAsync({required HashAlgorithm algorithm , required List<Uint8List> inputs });
+
+BigInt crateHashSize({required String algorithm });
+
+Uint8List crateHashThenEncrypt({required List<int> data , required List<int> key });
+
+Future<Uint8List> crateHashThenEncryptAsync({required List<int> data , required List<int> key });
+
+Uint8List crateHashThenEncryptWithAad({required List<int> data , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateHashThenEncryptWithAadAsync({required List<int> data , required List<int> key , required List<int> aad });
+
+Uint8List crateKdfHkdfSha256({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen });
+
+Future<Uint8List> crateKdfHkdfSha256Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen });
+
+Uint8List crateKdfHkdfSha256Expand({required List<int> prk , required List<int> info , required BigInt outputLen });
+
+Future<Uint8List> crateKdfHkdfSha256ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen });
+
+U8Array32 crateKdfHkdfSha256Extract({required List<int> salt , required List<int> ikm });
+
+Future<U8Array32> crateKdfHkdfSha256ExtractAsync({required List<int> salt , required List<int> ikm });
+
+Uint8List crateKdfHkdfSha384({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen });
+
+Future<Uint8List> crateKdfHkdfSha384Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen });
+
+Uint8List crateKdfHkdfSha384Expand({required List<int> prk , required List<int> info , required BigInt outputLen });
+
+Future<Uint8List> crateKdfHkdfSha384ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen });
+
+U8Array48 crateKdfHkdfSha384Extract({required List<int> salt , required List<int> ikm });
+
+Future<U8Array48> crateKdfHkdfSha384ExtractAsync({required List<int> salt , required List<int> ikm });
+
+Uint8List crateKdfHkdfSha512({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen });
+
+Future<Uint8List> crateKdfHkdfSha512Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen });
+
+Uint8List crateKdfHkdfSha512Expand({required List<int> prk , required List<int> info , required BigInt outputLen });
+
+Future<Uint8List> crateKdfHkdfSha512ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen });
+
+U8Array64 crateKdfHkdfSha512Extract({required List<int> salt , required List<int> ikm });
+
+Future<U8Array64> crateKdfHkdfSha512ExtractAsync({required List<int> salt , required List<int> ikm });
+
+Uint8List crateHashAlgorithmHmac({required HashAlgorithm algorithm , required List<int> key , required List<int> data });
+
+Future<Uint8List> crateHashAlgorithmHmacAsync({required HashAlgorithm algorithm , required List<int> key , required List<int> data });
+
+List<Uint8List> crateBatchHmacBatch({required HashAlgorithm algorithm , required List<int> key , required List<Uint8List> messages });
+
+Future<List<Uint8List>> crateBatchHmacBatchAsync({required HashAlgorithm algorithm , required List<int> key , required List<Uint8List> messages });
+
+U8Array16 crateHmacMd5({required List<int> key , required List<int> data });
+
+Future<U8Array16> crateHmacMd5Async({required List<int> key , required List<int> data });
+
+U8Array20 crateHmacSha1({required List<int> key , required List<int> data });
+
+Future<U8Array20> crateHmacSha1Async({required List<int> key , required List<int> data });
+
+U8Array28 crateHmacSha224({required List<int> key , required List<int> data });
+
+Future<U8Array28> crateHmacSha224Async({required List<int> key , required List<int> data });
+
+U8Array32 crateHmacSha256({required List<int> key , required List<int> data });
+
+Future<U8Array32> crateHmacSha256Async({required List<int> key , required List<int> data });
+
+List<U8Array32> crateHmacSha256Batch({required List<int> key , required List<Uint8List> messages });
+
+Future<List<U8Array32>> crateHmacSha256BatchAsync({required List<int> key , required List<Uint8List> messages });
+
+U8Array48 crateHmacSha384({required List<int> key , required List<int> data });
+
+Future<U8Array48> crateHmacSha384Async({required List<int> key , required List<int> data });
+
+U8Array64 crateHmacSha512({required List<int> key , required List<int> data });
+
+Future<U8Array64> crateHmacSha512Async({required List<int> key , required List<int> data });
+
+List<U8Array64> crateHmacSha512Batch({required List<int> key , required List<Uint8List> messages });
+
+Future<List<U8Array64>> crateHmacSha512BatchAsync({required List<int> key , required List<Uint8List> messages });
+
+String crateJweJweDecodeHeader({required String token });
+
+JweDecrypted crateJweJweDecrypt({required String token , required String algorithm , required List<int> key });
+
+Future<JweDecrypted> crateJweJweDecryptAsync({required String token , required String algorithm , required List<int> key });
+
+String crateJweJweEncrypt({required String algorithm , required List<int> key , required List<int> plaintext , String? headerJson });
+
+Future<String> crateJweJweEncryptAsync({required String algorithm , required List<int> key , required List<int> plaintext , String? headerJson });
+
+JwsToken crateJwsJwsDecodeUnverified({required String token });
+
+String crateJwsJwsSign({required String algorithm , required List<int> key , required String claimsJson , String? headerJson });
+
+Future<String> crateJwsJwsSignAsync({required String algorithm , required List<int> key , required String claimsJson , String? headerJson });
+
+JwsToken crateJwsJwsVerify({required String token , required String algorithm , required List<int> key , required BigInt leewaySeconds });
+
+Future<JwsToken> crateJwsJwsVerifyAsync({required String token , required String algorithm , required List<int> key , required BigInt leewaySeconds });
+
+U8Array32 crateKeccak256({required List<int> data });
+
+Future<U8Array32> crateKeccak256Async({required List<int> data });
+
+List<U8Array32> crateKeccak256Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array32>> crateKeccak256BatchAsync({required List<Uint8List> inputs });
+
+U8Array16 crateMd5({required List<int> data });
+
+Future<U8Array16> crateMd5Async({required List<int> data });
+
+Hasher crateHashAlgorithmNewHasher({required HashAlgorithm algorithm });
+
+Uint8List crateX25519Open({required List<int> secretKey , required List<int> ciphertext });
+
+Future<Uint8List> crateX25519OpenAsync({required List<int> secretKey , required List<int> ciphertext });
+
+Uint8List crateKdfPbkdf2HmacSha1({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen });
+
+Future<Uint8List> crateKdfPbkdf2HmacSha1Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen });
+
+Uint8List crateKdfPbkdf2HmacSha256({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen });
+
+Future<Uint8List> crateKdfPbkdf2HmacSha256Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen });
+
+Uint8List crateKdfPbkdf2HmacSha512({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen });
+
+Future<Uint8List> crateKdfPbkdf2HmacSha512Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen });
+
+RsaKeyPair crateRsaCryptoRsaGenerateKeypair({required int bits });
+
+Future<RsaKeyPair> crateRsaCryptoRsaGenerateKeypairAsync({required int bits });
+
+Uint8List crateRsaCryptoRsaImportPrivateKey({required List<int> key });
+
+Uint8List crateRsaCryptoRsaImportPublicKey({required List<int> key });
+
+int crateRsaCryptoRsaKeySizeBits({required List<int> publicKey });
+
+Uint8List crateRsaCryptoRsaOaepSha256Decrypt({required List<int> privateKey , required List<int> ciphertext });
+
+Future<Uint8List> crateRsaCryptoRsaOaepSha256DecryptAsync({required List<int> privateKey , required List<int> ciphertext });
+
+Uint8List crateRsaCryptoRsaOaepSha256Encrypt({required List<int> publicKey , required List<int> plaintext });
+
+Future<Uint8List> crateRsaCryptoRsaOaepSha256EncryptAsync({required List<int> publicKey , required List<int> plaintext });
+
+Uint8List crateRsaCryptoRsaPkcs1V15Sha256Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateRsaCryptoRsaPkcs1V15Sha256SignAsync({required List<int> privateKey , required List<int> message });
+
+bool crateRsaCryptoRsaPkcs1V15Sha256Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateRsaCryptoRsaPkcs1V15Sha256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Uint8List crateRsaCryptoRsaPkcs1V15Sha384Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateRsaCryptoRsaPkcs1V15Sha384SignAsync({required List<int> privateKey , required List<int> message });
+
+bool crateRsaCryptoRsaPkcs1V15Sha384Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateRsaCryptoRsaPkcs1V15Sha384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Uint8List crateRsaCryptoRsaPkcs1V15Sha512Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateRsaCryptoRsaPkcs1V15Sha512SignAsync({required List<int> privateKey , required List<int> message });
+
+bool crateRsaCryptoRsaPkcs1V15Sha512Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateRsaCryptoRsaPkcs1V15Sha512VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+String crateRsaCryptoRsaPrivateKeyToPem({required List<int> privateKey });
+
+Uint8List crateRsaCryptoRsaPssSha256Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateRsaCryptoRsaPssSha256SignAsync({required List<int> privateKey , required List<int> message });
+
+bool crateRsaCryptoRsaPssSha256Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateRsaCryptoRsaPssSha256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Uint8List crateRsaCryptoRsaPssSha384Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateRsaCryptoRsaPssSha384SignAsync({required List<int> privateKey , required List<int> message });
+
+bool crateRsaCryptoRsaPssSha384Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateRsaCryptoRsaPssSha384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Uint8List crateRsaCryptoRsaPssSha512Sign({required List<int> privateKey , required List<int> message });
+
+Future<Uint8List> crateRsaCryptoRsaPssSha512SignAsync({required List<int> privateKey , required List<int> message });
+
+bool crateRsaCryptoRsaPssSha512Verify({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Future<bool> crateRsaCryptoRsaPssSha512VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature });
+
+Uint8List crateRsaCryptoRsaPublicKeyFromPrivateKey({required List<int> privateKey });
+
+String crateRsaCryptoRsaPublicKeyToPem({required List<int> publicKey });
+
+Uint8List crateKdfScryptDeriveKey({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required BigInt outputLen });
+
+Future<Uint8List> crateKdfScryptDeriveKeyAsync({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required BigInt outputLen });
+
+bool crateKdfScryptVerify({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required List<int> expected });
+
+Future<bool> crateKdfScryptVerifyAsync({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required List<int> expected });
+
+Uint8List crateX25519Seal({required List<int> recipientPk , required List<int> plaintext });
+
+Future<Uint8List> crateX25519SealAsync({required List<int> recipientPk , required List<int> plaintext });
+
+void crateBatchSetBatchParallelism({required BigInt maxThreads });
+
+U8Array20 crateSha1({required List<int> data });
+
+Future<U8Array20> crateSha1Async({required List<int> data });
+
+U8Array28 crateSha224({required List<int> data });
+
+Future<U8Array28> crateSha224Async({required List<int> data });
+
+U8Array32 crateSha256({required List<int> data });
+
+Future<U8Array32> crateSha256Async({required List<int> data });
+
+List<U8Array32> crateSha256Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array32>> crateSha256BatchAsync({required List<Uint8List> inputs });
+
+U8Array48 crateSha384({required List<int> data });
+
+Future<U8Array48> crateSha384Async({required List<int> data });
+
+U8Array28 crateSha3224({required List<int> data });
+
+Future<U8Array28> crateSha3224Async({required List<int> data });
+
+List<U8Array28> crateSha3224Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array28>> crateSha3224BatchAsync({required List<Uint8List> inputs });
+
+U8Array32 crateSha3256({required List<int> data });
+
+Future<U8Array32> crateSha3256Async({required List<int> data });
+
+List<U8Array32> crateSha3256Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array32>> crateSha3256BatchAsync({required List<Uint8List> inputs });
+
+U8Array48 crateSha3384({required List<int> data });
+
+Future<U8Array48> crateSha3384Async({required List<int> data });
+
+List<U8Array48> crateSha3384Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array48>> crateSha3384BatchAsync({required List<Uint8List> inputs });
+
+U8Array64 crateSha3512({required List<int> data });
+
+Future<U8Array64> crateSha3512Async({required List<int> data });
+
+List<U8Array64> crateSha3512Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array64>> crateSha3512BatchAsync({required List<Uint8List> inputs });
+
+U8Array64 crateSha512({required List<int> data });
+
+U8Array28 crateSha512224({required List<int> data });
+
+Future<U8Array28> crateSha512224Async({required List<int> data });
+
+U8Array32 crateSha512256({required List<int> data });
+
+Future<U8Array32> crateSha512256Async({required List<int> data });
+
+Future<U8Array64> crateSha512Async({required List<int> data });
+
+List<U8Array64> crateSha512Batch({required List<Uint8List> inputs });
+
+Future<List<U8Array64>> crateSha512BatchAsync({required List<Uint8List> inputs });
+
+Uint8List crateShake128({required List<int> data , required BigInt outputLen });
+
+Future<Uint8List> crateShake128Async({required List<int> data , required BigInt outputLen });
+
+List<Uint8List> crateShake128Batch({required List<Uint8List> inputs , required BigInt outputLen });
+
+Future<List<Uint8List>> crateShake128BatchAsync({required List<Uint8List> inputs , required BigInt outputLen });
+
+Uint8List crateShake256({required List<int> data , required BigInt outputLen });
+
+Future<Uint8List> crateShake256Async({required List<int> data , required BigInt outputLen });
+
+List<Uint8List> crateShake256Batch({required List<Uint8List> inputs , required BigInt outputLen });
+
+Future<List<Uint8List>> crateShake256BatchAsync({required List<Uint8List> inputs , required BigInt outputLen });
+
+String crateToHex({required List<int> bytes });
+
+bool crateVerifyHmacMd5({required List<int> key , required List<int> data , required List<int> mac });
+
+Future<bool> crateVerifyHmacMd5Async({required List<int> key , required List<int> data , required List<int> mac });
+
+bool crateVerifyHmacSha1({required List<int> key , required List<int> data , required List<int> mac });
+
+Future<bool> crateVerifyHmacSha1Async({required List<int> key , required List<int> data , required List<int> mac });
+
+bool crateVerifyHmacSha224({required List<int> key , required List<int> data , required List<int> mac });
+
+Future<bool> crateVerifyHmacSha224Async({required List<int> key , required List<int> data , required List<int> mac });
+
+bool crateVerifyHmacSha256({required List<int> key , required List<int> data , required List<int> mac });
+
+Future<bool> crateVerifyHmacSha256Async({required List<int> key , required List<int> data , required List<int> mac });
+
+bool crateVerifyHmacSha384({required List<int> key , required List<int> data , required List<int> mac });
+
+Future<bool> crateVerifyHmacSha384Async({required List<int> key , required List<int> data , required List<int> mac });
+
+bool crateVerifyHmacSha512({required List<int> key , required List<int> data , required List<int> mac });
+
+Future<bool> crateVerifyHmacSha512Async({required List<int> key , required List<int> data , required List<int> mac });
+
+Uint8List crateVerifyHmacThenDecrypt({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey });
+
+Future<Uint8List> crateVerifyHmacThenDecryptAsync({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey });
+
+Uint8List crateVerifyHmacThenDecryptWithAad({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+Future<Uint8List> crateVerifyHmacThenDecryptWithAadAsync({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey , required List<int> aad });
+
+U8Array32 crateX25519X25519DiffieHellman({required List<int> secretKey , required List<int> publicKey });
+
+Future<U8Array32> crateX25519X25519DiffieHellmanAsync({required List<int> secretKey , required List<int> publicKey });
+
+X25519KeyPair crateX25519X25519GenerateKeypair();
+
+X25519KeyPair crateX25519X25519KeypairFromSecret({required List<int> secretKey });
+
+Uint8List crateXchacha20Poly1305Decrypt({required List<int> ciphertext , required List<int> key });
+
+Future<Uint8List> crateXchacha20Poly1305DecryptAsync({required List<int> ciphertext , required List<int> key });
+
+Uint8List crateXchacha20Poly1305DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateXchacha20Poly1305DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad });
+
+Uint8List crateXchacha20Poly1305Encrypt({required List<int> plaintext , required List<int> key });
+
+Future<Uint8List> crateXchacha20Poly1305EncryptAsync({required List<int> plaintext , required List<int> key });
+
+Uint8List crateXchacha20Poly1305EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+Future<Uint8List> crateXchacha20Poly1305EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Aes256GcmStreamDecryptor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Aes256GcmStreamDecryptor;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Aes256GcmStreamDecryptorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Aes256GcmStreamEncryptor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Aes256GcmStreamEncryptor;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Aes256GcmStreamEncryptorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Blake2BHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Blake2BHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Blake2BHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Blake2SHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Blake2SHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Blake2SHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Blake3Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Blake3Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Blake3HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Keccak256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Keccak256Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Keccak256HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Md5Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Md5Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Md5HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Md5HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Md5HmacHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Md5HmacHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha1Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha1Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha1HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha1HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha1HmacHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha1HmacHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha224Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha224Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha224HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha224HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha224HmacHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha224HmacHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha256Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha256HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha256HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha256HmacHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha256HmacHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha384Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha384Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha384HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha384HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha384HmacHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha384HmacHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3224Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3224Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha3224HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3256Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha3256HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3384Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3384Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha3384HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3512Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3512Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha3512HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha512HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512HmacHasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha512HmacHasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512224Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512224Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha512224HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512256Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Sha512256HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Shake128Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Shake128Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Shake128HasherPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Shake256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Shake256Hasher;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Shake256HasherPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Uint8List crateStreamAes256GcmStreamDecryptorDecryptChunk({required Aes256GcmStreamDecryptor that , required List<int> chunk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(that, serializer);
+sse_encode_list_prim_u_8_loose(chunk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamDecryptorDecryptChunkConstMeta,
+            argValues: [that, chunk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamDecryptorDecryptChunkConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamDecryptor_decrypt_chunk",
+            argNames: ["that", "chunk"],
+        );
+        
+
+@override Uint8List crateStreamAes256GcmStreamDecryptorFinalize({required Aes256GcmStreamDecryptor that , required List<int> chunk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(that, serializer);
+sse_encode_list_prim_u_8_loose(chunk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamDecryptorFinalizeConstMeta,
+            argValues: [that, chunk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamDecryptorFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamDecryptor_finalize",
+            argNames: ["that", "chunk"],
+        );
+        
+
+@override Aes256GcmStreamDecryptor crateStreamAes256GcmStreamDecryptorNew({required List<int> key , required List<int> header })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(header, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamDecryptorNewConstMeta,
+            argValues: [key, header],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamDecryptorNewConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamDecryptor_new",
+            argNames: ["key", "header"],
+        );
+        
+
+@override Uint8List crateStreamAes256GcmStreamEncryptorEncryptChunk({required Aes256GcmStreamEncryptor that , required List<int> chunk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(that, serializer);
+sse_encode_list_prim_u_8_loose(chunk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamEncryptorEncryptChunkConstMeta,
+            argValues: [that, chunk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamEncryptorEncryptChunkConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamEncryptor_encrypt_chunk",
+            argNames: ["that", "chunk"],
+        );
+        
+
+@override Uint8List crateStreamAes256GcmStreamEncryptorFinalize({required Aes256GcmStreamEncryptor that , required List<int> chunk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(that, serializer);
+sse_encode_list_prim_u_8_loose(chunk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamEncryptorFinalizeConstMeta,
+            argValues: [that, chunk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamEncryptorFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamEncryptor_finalize",
+            argNames: ["that", "chunk"],
+        );
+        
+
+@override Uint8List crateStreamAes256GcmStreamEncryptorHeader({required Aes256GcmStreamEncryptor that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamEncryptorHeaderConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamEncryptorHeaderConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamEncryptor_header",
+            argNames: ["that"],
+        );
+        
+
+@override Aes256GcmStreamEncryptor crateStreamAes256GcmStreamEncryptorNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateStreamAes256GcmStreamEncryptorNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateStreamAes256GcmStreamEncryptorNewConstMeta => const TaskConstMeta(
+            debugName: "Aes256GcmStreamEncryptor_new",
+            argNames: ["key"],
+        );
+        
+
+@override Uint8List crateBlake2BHasherFinalize({required Blake2BHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Blake2BHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Blake2BHasher crateBlake2BHasherFork({required Blake2BHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Blake2BHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Blake2BHasher crateBlake2BHasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BHasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Blake2BHasher_new",
+            argNames: [],
+        );
+        
+
+@override Blake2BHasher crateBlake2BHasherNewKeyed({required List<int> key , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2BHasherNewKeyedConstMeta,
+            argValues: [key, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BHasherNewKeyedConstMeta => const TaskConstMeta(
+            debugName: "Blake2BHasher_new_keyed",
+            argNames: ["key", "outputLen"],
+        );
+        
+
+@override void crateBlake2BHasherReset({required Blake2BHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Blake2BHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBlake2BHasherUpdate({required Blake2BHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Blake2BHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateBlake2SHasherFinalize({required Blake2SHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Blake2SHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Blake2SHasher crateBlake2SHasherFork({required Blake2SHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Blake2SHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Blake2SHasher crateBlake2SHasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SHasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Blake2SHasher_new",
+            argNames: [],
+        );
+        
+
+@override Blake2SHasher crateBlake2SHasherNewKeyed({required List<int> key , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2SHasherNewKeyedConstMeta,
+            argValues: [key, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SHasherNewKeyedConstMeta => const TaskConstMeta(
+            debugName: "Blake2SHasher_new_keyed",
+            argNames: ["key", "outputLen"],
+        );
+        
+
+@override void crateBlake2SHasherReset({required Blake2SHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Blake2SHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBlake2SHasherUpdate({required Blake2SHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Blake2SHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override U8Array32 crateBlake3HasherFinalize({required Blake3Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Blake3Hasher crateBlake3HasherFork({required Blake3Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Blake3Hasher crateBlake3HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_new",
+            argNames: [],
+        );
+        
+
+@override Blake3Hasher crateBlake3HasherNewDeriveKey({required String context })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(context, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3HasherNewDeriveKeyConstMeta,
+            argValues: [context],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherNewDeriveKeyConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_new_derive_key",
+            argNames: ["context"],
+        );
+        
+
+@override Blake3Hasher crateBlake3HasherNewKeyed({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake3HasherNewKeyedConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherNewKeyedConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_new_keyed",
+            argNames: ["key"],
+        );
+        
+
+@override void crateBlake3HasherReset({required Blake3Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateBlake3HasherUpdate({required Blake3Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Blake3Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override HashAlgorithm crateHashAlgorithmHasherAlgorithm({required Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hash_algorithm,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherAlgorithmConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherAlgorithmConstMeta => const TaskConstMeta(
+            debugName: "Hasher_algorithm",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateHashAlgorithmHasherExportState({required Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateHashAlgorithmHasherFinalize({required Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Hasher crateHashAlgorithmHasherFork({required Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Hasher crateHashAlgorithmHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override void crateHashAlgorithmHasherReset({required Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateHashAlgorithmHasherUpdate({required Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateKeccak256HasherExportState({required Keccak256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array32 crateKeccak256HasherFinalize({required Keccak256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Keccak256Hasher crateKeccak256HasherFork({required Keccak256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Keccak256Hasher crateKeccak256HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Keccak256Hasher crateKeccak256HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateKeccak256HasherReset({required Keccak256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateKeccak256HasherUpdate({required Keccak256Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Keccak256Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateMd5HasherExportState({required Md5Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array16 crateMd5HasherFinalize({required Md5Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_16,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Md5Hasher crateMd5HasherFork({required Md5Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Md5Hasher crateMd5HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateMd5HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Md5Hasher crateMd5HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateMd5HasherReset({required Md5Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMd5HasherUpdate({required Md5Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Md5Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateMd5HmacHasherExportState({required Md5HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array16 crateMd5HmacHasherFinalize({required Md5HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_16,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Md5HmacHasher crateMd5HmacHasherFork({required Md5HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Md5HmacHasher crateMd5HmacHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Md5HmacHasher crateMd5HmacHasherNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_new",
+            argNames: ["key"],
+        );
+        
+
+@override void crateMd5HmacHasherReset({required Md5HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMd5HmacHasherUpdate({required Md5HmacHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMd5HmacHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5HmacHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Md5HmacHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha1HasherExportState({required Sha1Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array20 crateSha1HasherFinalize({required Sha1Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_20,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha1Hasher crateSha1HasherFork({required Sha1Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha1Hasher crateSha1HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha1HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha1Hasher crateSha1HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha1HasherReset({required Sha1Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha1HasherUpdate({required Sha1Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha1Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha1HmacHasherExportState({required Sha1HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array20 crateSha1HmacHasherFinalize({required Sha1HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_20,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha1HasherFinalizeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha1HasherFinalizeConstMeta =>
-      const TaskConstMeta(debugName: "Sha1Hasher_finalize", argNames: ["that"]);
-
-  @override
-  Sha1Hasher crateSha1HasherNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateSha1HasherNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha1HasherNewConstMeta =>
-      const TaskConstMeta(debugName: "Sha1Hasher_new", argNames: []);
-
-  @override
-  void crateSha1HasherUpdate({
-    required Sha1Hasher that,
-    required List<int> data,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha1HmacHasher crateSha1HmacHasherFork({required Sha1HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha1HmacHasher crateSha1HmacHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha1HmacHasher crateSha1HmacHasherNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_new",
+            argNames: ["key"],
+        );
+        
+
+@override void crateSha1HmacHasherReset({required Sha1HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha1HmacHasherUpdate({required Sha1HmacHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha1HmacHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1HmacHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha1HmacHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha224HasherExportState({required Sha224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array28 crateSha224HasherFinalize({required Sha224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha224Hasher crateSha224HasherFork({required Sha224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha224Hasher crateSha224HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha224HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha224Hasher crateSha224HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha224HasherReset({required Sha224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha224HasherUpdate({required Sha224Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha224Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha224HmacHasherExportState({required Sha224HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array28 crateSha224HmacHasherFinalize({required Sha224HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha224HmacHasher crateSha224HmacHasherFork({required Sha224HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha224HmacHasher crateSha224HmacHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha224HmacHasher crateSha224HmacHasherNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_new",
+            argNames: ["key"],
+        );
+        
+
+@override void crateSha224HmacHasherReset({required Sha224HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha224HmacHasherUpdate({required Sha224HmacHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224HmacHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224HmacHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha224HmacHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha256HasherExportState({required Sha256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array32 crateSha256HasherFinalize({required Sha256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha256Hasher crateSha256HasherFork({required Sha256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha256Hasher crateSha256HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha256HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha256Hasher crateSha256HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha256HasherReset({required Sha256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha256HasherUpdate({required Sha256Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha256Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha256HmacHasherExportState({required Sha256HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array32 crateSha256HmacHasherFinalize({required Sha256HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha256HmacHasher crateSha256HmacHasherFork({required Sha256HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha256HmacHasher crateSha256HmacHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha256HmacHasher crateSha256HmacHasherNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_new",
+            argNames: ["key"],
+        );
+        
+
+@override void crateSha256HmacHasherReset({required Sha256HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha256HmacHasherUpdate({required Sha256HmacHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256HmacHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256HmacHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha256HmacHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha3224HasherExportState({required Sha3224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array28 crateSha3224HasherFinalize({required Sha3224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3224Hasher crateSha3224HasherFork({required Sha3224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3224Hasher crateSha3224HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha3224HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha3224Hasher crateSha3224HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha3224HasherReset({required Sha3224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha3224HasherUpdate({required Sha3224Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha1HasherUpdateConstMeta,
-        argValues: [that, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha1HasherUpdateConstMeta => const TaskConstMeta(
-    debugName: "Sha1Hasher_update",
-    argNames: ["that", "data"],
-  );
-
-  @override
-  U8Array32 crateSha256HasherFinalize({required Sha256Hasher that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3224HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha3224Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha3256HasherExportState({required Sha3256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3256HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array32 crateSha3256HasherFinalize({required Sha3256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256HasherFinalizeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256HasherFinalizeConstMeta => const TaskConstMeta(
-    debugName: "Sha256Hasher_finalize",
-    argNames: ["that"],
-  );
-
-  @override
-  Sha256Hasher crateSha256HasherNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256HasherNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256HasherNewConstMeta =>
-      const TaskConstMeta(debugName: "Sha256Hasher_new", argNames: []);
-
-  @override
-  void crateSha256HasherUpdate({
-    required Sha256Hasher that,
-    required List<int> data,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3256HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3256Hasher crateSha3256HasherFork({required Sha3256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3256HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3256Hasher crateSha3256HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha3256HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha3256Hasher crateSha3256HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3256HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha3256HasherReset({required Sha3256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3256HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha3256HasherUpdate({required Sha3256Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3256HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha3256Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha3384HasherExportState({required Sha3384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array48 crateSha3384HasherFinalize({required Sha3384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3384Hasher crateSha3384HasherFork({required Sha3384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3384Hasher crateSha3384HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha3384HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha3384Hasher crateSha3384HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha3384HasherReset({required Sha3384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha3384HasherUpdate({required Sha3384Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha3384Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha3512HasherExportState({required Sha3512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array64 crateSha3512HasherFinalize({required Sha3512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3512Hasher crateSha3512HasherFork({required Sha3512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha3512Hasher crateSha3512HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha3512HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha3512Hasher crateSha3512HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha3512HasherReset({required Sha3512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha3512HasherUpdate({required Sha3512Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha3512Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha384HasherExportState({required Sha384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array48 crateSha384HasherFinalize({required Sha384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha384Hasher crateSha384HasherFork({required Sha384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha384Hasher crateSha384HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha384HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha384Hasher crateSha384HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha384HasherReset({required Sha384Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha384HasherUpdate({required Sha384Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256HasherUpdateConstMeta,
-        argValues: [that, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256HasherUpdateConstMeta => const TaskConstMeta(
-    debugName: "Sha256Hasher_update",
-    argNames: ["that", "data"],
-  );
-
-  @override
-  U8Array32 crateSha256HmacHasherFinalize({required Sha256HmacHasher that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha384HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha384Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha384HmacHasherExportState({required Sha384HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array48 crateSha384HmacHasherFinalize({required Sha384HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha384HmacHasher crateSha384HmacHasherFork({required Sha384HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha384HmacHasher crateSha384HmacHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha384HmacHasher crateSha384HmacHasherNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_new",
+            argNames: ["key"],
+        );
+        
+
+@override void crateSha384HmacHasherReset({required Sha384HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha384HmacHasherUpdate({required Sha384HmacHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384HmacHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384HmacHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha384HmacHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha512224HasherExportState({required Sha512224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512224HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array28 crateSha512224HasherFinalize({required Sha512224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512224HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512224Hasher crateSha512224HasherFork({required Sha512224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512224HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512224Hasher crateSha512224HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha512224HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha512224Hasher crateSha512224HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512224HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha512224HasherReset({required Sha512224Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512224HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha512224HasherUpdate({required Sha512224Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512224HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha512224Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha512256HasherExportState({required Sha512256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512256HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array32 crateSha512256HasherFinalize({required Sha512256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256HmacHasherFinalizeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256HmacHasherFinalizeConstMeta =>
-      const TaskConstMeta(
-        debugName: "Sha256HmacHasher_finalize",
-        argNames: ["that"],
-      );
-
-  @override
-  Sha256HmacHasher? crateSha256HmacHasherNew({required List<int> key}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256HmacHasherNewConstMeta,
-        argValues: [key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256HmacHasherNewConstMeta =>
-      const TaskConstMeta(debugName: "Sha256HmacHasher_new", argNames: ["key"]);
-
-  @override
-  void crateSha256HmacHasherUpdate({
-    required Sha256HmacHasher that,
-    required List<int> data,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512256HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512256Hasher crateSha512256HasherFork({required Sha512256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512256HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512256Hasher crateSha512256HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha512256HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha512256Hasher crateSha512256HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512256HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha512256HasherReset({required Sha512256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512256HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha512256HasherUpdate({required Sha512256Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512256HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha512256Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha512HasherExportState({required Sha512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array64 crateSha512HasherFinalize({required Sha512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512Hasher crateSha512HasherFork({required Sha512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512Hasher crateSha512HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha512HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha512Hasher crateSha512HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateSha512HasherReset({required Sha512Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha512HasherUpdate({required Sha512Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256HmacHasherUpdateConstMeta,
-        argValues: [that, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256HmacHasherUpdateConstMeta =>
-      const TaskConstMeta(
-        debugName: "Sha256HmacHasher_update",
-        argNames: ["that", "data"],
-      );
-
-  @override
-  U8Array64 crateSha512HasherFinalize({required Sha512Hasher that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha512Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateSha512HmacHasherExportState({required Sha512HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override U8Array64 crateSha512HmacHasherFinalize({required Sha512HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512HasherFinalizeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512HasherFinalizeConstMeta => const TaskConstMeta(
-    debugName: "Sha512Hasher_finalize",
-    argNames: ["that"],
-  );
-
-  @override
-  Sha512Hasher crateSha512HasherNew() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512HasherNewConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512HasherNewConstMeta =>
-      const TaskConstMeta(debugName: "Sha512Hasher_new", argNames: []);
-
-  @override
-  void crateSha512HasherUpdate({
-    required Sha512Hasher that,
-    required List<int> data,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-            that,
-            serializer,
-          );
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_finalize",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512HmacHasher crateSha512HmacHasherFork({required Sha512HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherForkConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Sha512HmacHasher crateSha512HmacHasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Sha512HmacHasher crateSha512HmacHasherNew({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherNewConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherNewConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_new",
+            argNames: ["key"],
+        );
+        
+
+@override void crateSha512HmacHasherReset({required Sha512HmacHasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherResetConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSha512HmacHasherUpdate({required Sha512HmacHasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha512HmacHasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512HmacHasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Sha512HmacHasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateShake128HasherExportState({required Shake128Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake128HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateShake128HasherFinalize({required Shake128Hasher that , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(that, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake128HasherFinalizeConstMeta,
+            argValues: [that, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_finalize",
+            argNames: ["that", "outputLen"],
+        );
+        
+
+@override Shake128Hasher crateShake128HasherFork({required Shake128Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake128HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Shake128Hasher crateShake128HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake128HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Shake128Hasher crateShake128HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake128HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateShake128HasherReset({required Shake128Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake128HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateShake128HasherUpdate({required Shake128Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake128HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Shake128Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateShake256HasherExportState({required Shake256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake256HasherExportStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherExportStateConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_export_state",
+            argNames: ["that"],
+        );
+        
+
+@override Uint8List crateShake256HasherFinalize({required Shake256Hasher that , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(that, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake256HasherFinalizeConstMeta,
+            argValues: [that, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherFinalizeConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_finalize",
+            argNames: ["that", "outputLen"],
+        );
+        
+
+@override Shake256Hasher crateShake256HasherFork({required Shake256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake256HasherForkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherForkConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_fork",
+            argNames: ["that"],
+        );
+        
+
+@override Shake256Hasher crateShake256HasherImportState({required List<int> state })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(state, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake256HasherImportStateConstMeta,
+            argValues: [state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherImportStateConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_import_state",
+            argNames: ["state"],
+        );
+        
+
+@override Shake256Hasher crateShake256HasherNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake256HasherNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherNewConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_new",
+            argNames: [],
+        );
+        
+
+@override void crateShake256HasherReset({required Shake256Hasher that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShake256HasherResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherResetConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_reset",
+            argNames: ["that"],
+        );
+        
+
+@override void crateShake256HasherUpdate({required Shake256Hasher that , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(that, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512HasherUpdateConstMeta,
-        argValues: [that, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512HasherUpdateConstMeta => const TaskConstMeta(
-    debugName: "Sha512Hasher_update",
-    argNames: ["that", "data"],
-  );
-
-  @override
-  Uint8List? crateAes256Decrypt({
-    required List<int> ciphertext,
-    required List<int> key,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateAes256DecryptConstMeta,
-        argValues: [ciphertext, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateAes256DecryptConstMeta => const TaskConstMeta(
-    debugName: "aes256_decrypt",
-    argNames: ["ciphertext", "key"],
-  );
-
-  @override
-  Future<Uint8List?> crateAes256DecryptAsync({
-    required List<int> ciphertext,
-    required List<int> key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateAes256DecryptAsyncConstMeta,
-        argValues: [ciphertext, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateAes256DecryptAsyncConstMeta => const TaskConstMeta(
-    debugName: "aes256_decrypt_async",
-    argNames: ["ciphertext", "key"],
-  );
-
-  @override
-  Uint8List? crateAes256Encrypt({
-    required List<int> plaintext,
-    required List<int> key,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(plaintext, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateAes256EncryptConstMeta,
-        argValues: [plaintext, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateAes256EncryptConstMeta => const TaskConstMeta(
-    debugName: "aes256_encrypt",
-    argNames: ["plaintext", "key"],
-  );
-
-  @override
-  Future<Uint8List?> crateAes256EncryptAsync({
-    required List<int> plaintext,
-    required List<int> key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(plaintext, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateAes256EncryptAsyncConstMeta,
-        argValues: [plaintext, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateAes256EncryptAsyncConstMeta => const TaskConstMeta(
-    debugName: "aes256_encrypt_async",
-    argNames: ["plaintext", "key"],
-  );
-
-  @override
-  (Uint8List, U8Array32)? crateEncryptThenHmac({
-    required List<int> plaintext,
-    required List<int> encKey,
-    required List<int> macKey,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(plaintext, serializer);
-          sse_encode_list_prim_u_8_loose(encKey, serializer);
-          sse_encode_list_prim_u_8_loose(macKey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_record_list_prim_u_8_strict_u_8_array_32,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateEncryptThenHmacConstMeta,
-        argValues: [plaintext, encKey, macKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateEncryptThenHmacConstMeta => const TaskConstMeta(
-    debugName: "encrypt_then_hmac",
-    argNames: ["plaintext", "encKey", "macKey"],
-  );
-
-  @override
-  Future<(Uint8List, U8Array32)?> crateEncryptThenHmacAsync({
-    required List<int> plaintext,
-    required List<int> encKey,
-    required List<int> macKey,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(plaintext, serializer);
-          sse_encode_list_prim_u_8_loose(encKey, serializer);
-          sse_encode_list_prim_u_8_loose(macKey, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_record_list_prim_u_8_strict_u_8_array_32,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateEncryptThenHmacAsyncConstMeta,
-        argValues: [plaintext, encKey, macKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateEncryptThenHmacAsyncConstMeta => const TaskConstMeta(
-    debugName: "encrypt_then_hmac_async",
-    argNames: ["plaintext", "encKey", "macKey"],
-  );
-
-  @override
-  Uint8List? crateFromHex({required String hexString}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(hexString, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateFromHexConstMeta,
-        argValues: [hexString],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateFromHexConstMeta =>
-      const TaskConstMeta(debugName: "from_hex", argNames: ["hexString"]);
-
-  @override
-  List<String> crateGetAllAlgorithms() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateShake256HasherUpdateConstMeta,
+            argValues: [that, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256HasherUpdateConstMeta => const TaskConstMeta(
+            debugName: "Shake256Hasher_update",
+            argNames: ["that", "data"],
+        );
+        
+
+@override Uint8List crateAes256Decrypt({required List<int> ciphertext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256DecryptConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256DecryptConstMeta => const TaskConstMeta(
+            debugName: "aes256_decrypt",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateAes256DecryptAsync({required List<int> ciphertext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256DecryptAsyncConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256DecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_decrypt_async",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Uint8List crateAes256DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256DecryptWithAadConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256DecryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "aes256_decrypt_with_aad",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateAes256DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256DecryptWithAadAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256DecryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_decrypt_with_aad_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateAes256Encrypt({required List<int> plaintext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256EncryptConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256EncryptConstMeta => const TaskConstMeta(
+            debugName: "aes256_encrypt",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateAes256EncryptAsync({required List<int> plaintext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256EncryptAsyncConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256EncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_encrypt_async",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Uint8List crateAes256EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256EncryptWithAadConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256EncryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "aes256_encrypt_with_aad",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateAes256EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAes256EncryptWithAadAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAes256EncryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_encrypt_with_aad_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAes256GcmSivDecrypt({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivDecryptConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivDecryptConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_decrypt",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAes256GcmSivDecryptAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivDecryptAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivDecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_decrypt_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAes256GcmSivDecryptDeterministic({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivDecryptDeterministicConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivDecryptDeterministicConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_decrypt_deterministic",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAes256GcmSivDecryptDeterministicAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivDecryptDeterministicAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivDecryptDeterministicAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_decrypt_deterministic_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAes256GcmSivEncrypt({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivEncryptConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivEncryptConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_encrypt",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAes256GcmSivEncryptAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivEncryptAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivEncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_encrypt_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAes256GcmSivEncryptDeterministic({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivEncryptDeterministicConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivEncryptDeterministicConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_encrypt_deterministic",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAes256GcmSivEncryptDeterministicAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAes256GcmSivEncryptDeterministicAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAes256GcmSivEncryptDeterministicAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes256_gcm_siv_encrypt_deterministic_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateAesModesAesCbcDecrypt({required List<int> ciphertext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcDecryptConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcDecryptConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_decrypt",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCbcDecryptAsync({required List<int> ciphertext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcDecryptAsyncConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcDecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_decrypt_async",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Uint8List crateAesModesAesCbcDecryptWithIv({required List<int> ciphertext , required List<int> key , required List<int> iv })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(iv, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcDecryptWithIvConstMeta,
+            argValues: [ciphertext, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcDecryptWithIvConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_decrypt_with_iv",
+            argNames: ["ciphertext", "key", "iv"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCbcDecryptWithIvAsync({required List<int> ciphertext , required List<int> key , required List<int> iv })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(iv, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcDecryptWithIvAsyncConstMeta,
+            argValues: [ciphertext, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcDecryptWithIvAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_decrypt_with_iv_async",
+            argNames: ["ciphertext", "key", "iv"],
+        );
+        
+
+@override Uint8List crateAesModesAesCbcEncrypt({required List<int> plaintext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcEncryptConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcEncryptConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_encrypt",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCbcEncryptAsync({required List<int> plaintext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcEncryptAsyncConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcEncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_encrypt_async",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Uint8List crateAesModesAesCbcEncryptWithIv({required List<int> plaintext , required List<int> key , required List<int> iv })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(iv, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcEncryptWithIvConstMeta,
+            argValues: [plaintext, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcEncryptWithIvConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_encrypt_with_iv",
+            argNames: ["plaintext", "key", "iv"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCbcEncryptWithIvAsync({required List<int> plaintext , required List<int> key , required List<int> iv })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(iv, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcEncryptWithIvAsyncConstMeta,
+            argValues: [plaintext, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcEncryptWithIvAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_encrypt_with_iv_async",
+            argNames: ["plaintext", "key", "iv"],
+        );
+        
+
+@override Uint8List crateAesModesAesCbcHmacSha256Decrypt({required List<int> ciphertext , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcHmacSha256DecryptConstMeta,
+            argValues: [ciphertext, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcHmacSha256DecryptConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_hmac_sha256_decrypt",
+            argNames: ["ciphertext", "encKey", "macKey", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCbcHmacSha256DecryptAsync({required List<int> ciphertext , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcHmacSha256DecryptAsyncConstMeta,
+            argValues: [ciphertext, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcHmacSha256DecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_hmac_sha256_decrypt_async",
+            argNames: ["ciphertext", "encKey", "macKey", "aad"],
+        );
+        
+
+@override Uint8List crateAesModesAesCbcHmacSha256Encrypt({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcHmacSha256EncryptConstMeta,
+            argValues: [plaintext, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcHmacSha256EncryptConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_hmac_sha256_encrypt",
+            argNames: ["plaintext", "encKey", "macKey", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCbcHmacSha256EncryptAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCbcHmacSha256EncryptAsyncConstMeta,
+            argValues: [plaintext, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCbcHmacSha256EncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_cbc_hmac_sha256_encrypt_async",
+            argNames: ["plaintext", "encKey", "macKey", "aad"],
+        );
+        
+
+@override Uint8List crateAesModesAesCtrApplyKeystream({required List<int> data , required List<int> key , required List<int> iv })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(iv, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCtrApplyKeystreamConstMeta,
+            argValues: [data, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCtrApplyKeystreamConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr_apply_keystream",
+            argNames: ["data", "key", "iv"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCtrApplyKeystreamAsync({required List<int> data , required List<int> key , required List<int> iv })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(iv, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCtrApplyKeystreamAsyncConstMeta,
+            argValues: [data, key, iv],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCtrApplyKeystreamAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr_apply_keystream_async",
+            argNames: ["data", "key", "iv"],
+        );
+        
+
+@override Uint8List crateAesModesAesCtrDecrypt({required List<int> ciphertext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCtrDecryptConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCtrDecryptConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr_decrypt",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCtrDecryptAsync({required List<int> ciphertext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCtrDecryptAsyncConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCtrDecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr_decrypt_async",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Uint8List crateAesModesAesCtrEncrypt({required List<int> plaintext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCtrEncryptConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCtrEncryptConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr_encrypt",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateAesModesAesCtrEncryptAsync({required List<int> plaintext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateAesModesAesCtrEncryptAsyncConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAesModesAesCtrEncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_ctr_encrypt_async",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Uint8List crateKeyWrapAesKeyUnwrap({required List<int> wrappedKey , required List<int> kek })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(wrappedKey, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyUnwrapConstMeta,
+            argValues: [wrappedKey, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyUnwrapConstMeta => const TaskConstMeta(
+            debugName: "aes_key_unwrap",
+            argNames: ["wrappedKey", "kek"],
+        );
+        
+
+@override Future<Uint8List> crateKeyWrapAesKeyUnwrapAsync({required List<int> wrappedKey , required List<int> kek })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(wrappedKey, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyUnwrapAsyncConstMeta,
+            argValues: [wrappedKey, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyUnwrapAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_key_unwrap_async",
+            argNames: ["wrappedKey", "kek"],
+        );
+        
+
+@override Uint8List crateKeyWrapAesKeyUnwrapWithPadding({required List<int> wrappedKey , required List<int> kek })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(wrappedKey, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyUnwrapWithPaddingConstMeta,
+            argValues: [wrappedKey, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyUnwrapWithPaddingConstMeta => const TaskConstMeta(
+            debugName: "aes_key_unwrap_with_padding",
+            argNames: ["wrappedKey", "kek"],
+        );
+        
+
+@override Future<Uint8List> crateKeyWrapAesKeyUnwrapWithPaddingAsync({required List<int> wrappedKey , required List<int> kek })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(wrappedKey, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyUnwrapWithPaddingAsyncConstMeta,
+            argValues: [wrappedKey, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyUnwrapWithPaddingAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_key_unwrap_with_padding_async",
+            argNames: ["wrappedKey", "kek"],
+        );
+        
+
+@override Uint8List crateKeyWrapAesKeyWrap({required List<int> key , required List<int> kek })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyWrapConstMeta,
+            argValues: [key, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyWrapConstMeta => const TaskConstMeta(
+            debugName: "aes_key_wrap",
+            argNames: ["key", "kek"],
+        );
+        
+
+@override Future<Uint8List> crateKeyWrapAesKeyWrapAsync({required List<int> key , required List<int> kek })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyWrapAsyncConstMeta,
+            argValues: [key, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyWrapAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_key_wrap_async",
+            argNames: ["key", "kek"],
+        );
+        
+
+@override Uint8List crateKeyWrapAesKeyWrapWithPadding({required List<int> key , required List<int> kek })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyWrapWithPaddingConstMeta,
+            argValues: [key, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyWrapWithPaddingConstMeta => const TaskConstMeta(
+            debugName: "aes_key_wrap_with_padding",
+            argNames: ["key", "kek"],
+        );
+        
+
+@override Future<Uint8List> crateKeyWrapAesKeyWrapWithPaddingAsync({required List<int> key , required List<int> kek })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(kek, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKeyWrapAesKeyWrapWithPaddingAsyncConstMeta,
+            argValues: [key, kek],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeyWrapAesKeyWrapWithPaddingAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_key_wrap_with_padding_async",
+            argNames: ["key", "kek"],
+        );
+        
+
+@override Uint8List crateSivAesSivDecrypt({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivDecryptConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivDecryptConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_decrypt",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAesSivDecryptAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivDecryptAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivDecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_decrypt_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAesSivDecryptDeterministic({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivDecryptDeterministicConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivDecryptDeterministicConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_decrypt_deterministic",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAesSivDecryptDeterministicAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivDecryptDeterministicAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivDecryptDeterministicAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_decrypt_deterministic_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAesSivEncrypt({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivEncryptConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivEncryptConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_encrypt",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAesSivEncryptAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivEncryptAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivEncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_encrypt_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateSivAesSivEncryptDeterministic({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivEncryptDeterministicConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivEncryptDeterministicConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_encrypt_deterministic",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateSivAesSivEncryptDeterministicAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateSivAesSivEncryptDeterministicAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSivAesSivEncryptDeterministicAsyncConstMeta => const TaskConstMeta(
+            debugName: "aes_siv_encrypt_deterministic_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override List<HashAlgorithmInfo> crateHashAlgorithmAllHashAlgorithms()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_hash_algorithm_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmAllHashAlgorithmsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmAllHashAlgorithmsConstMeta => const TaskConstMeta(
+            debugName: "all_hash_algorithms",
+            argNames: [],
+        );
+        
+
+@override bool crateKdfArgon2VerifyPassword({required List<int> password , required String phc })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_String(phc, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfArgon2VerifyPasswordConstMeta,
+            argValues: [password, phc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfArgon2VerifyPasswordConstMeta => const TaskConstMeta(
+            debugName: "argon2_verify_password",
+            argNames: ["password", "phc"],
+        );
+        
+
+@override Future<bool> crateKdfArgon2VerifyPasswordAsync({required List<int> password , required String phc })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_String(phc, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfArgon2VerifyPasswordAsyncConstMeta,
+            argValues: [password, phc],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfArgon2VerifyPasswordAsyncConstMeta => const TaskConstMeta(
+            debugName: "argon2_verify_password_async",
+            argNames: ["password", "phc"],
+        );
+        
+
+@override Uint8List crateKdfArgon2IdDeriveKey({required List<int> password , required List<int> salt , required int memoryKib , required int iterations , required int parallelism , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(memoryKib, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_u_32(parallelism, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfArgon2IdDeriveKeyConstMeta,
+            argValues: [password, salt, memoryKib, iterations, parallelism, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfArgon2IdDeriveKeyConstMeta => const TaskConstMeta(
+            debugName: "argon2id_derive_key",
+            argNames: ["password", "salt", "memoryKib", "iterations", "parallelism", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfArgon2IdDeriveKeyAsync({required List<int> password , required List<int> salt , required int memoryKib , required int iterations , required int parallelism , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(memoryKib, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_u_32(parallelism, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfArgon2IdDeriveKeyAsyncConstMeta,
+            argValues: [password, salt, memoryKib, iterations, parallelism, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfArgon2IdDeriveKeyAsyncConstMeta => const TaskConstMeta(
+            debugName: "argon2id_derive_key_async",
+            argNames: ["password", "salt", "memoryKib", "iterations", "parallelism", "outputLen"],
+        );
+        
+
+@override String crateKdfArgon2IdHashPassword({required List<int> password , required int memoryKib , required int iterations , required int parallelism })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_u_32(memoryKib, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_u_32(parallelism, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfArgon2IdHashPasswordConstMeta,
+            argValues: [password, memoryKib, iterations, parallelism],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfArgon2IdHashPasswordConstMeta => const TaskConstMeta(
+            debugName: "argon2id_hash_password",
+            argNames: ["password", "memoryKib", "iterations", "parallelism"],
+        );
+        
+
+@override Future<String> crateKdfArgon2IdHashPasswordAsync({required List<int> password , required int memoryKib , required int iterations , required int parallelism })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_u_32(memoryKib, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_u_32(parallelism, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfArgon2IdHashPasswordAsyncConstMeta,
+            argValues: [password, memoryKib, iterations, parallelism],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfArgon2IdHashPasswordAsyncConstMeta => const TaskConstMeta(
+            debugName: "argon2id_hash_password_async",
+            argNames: ["password", "memoryKib", "iterations", "parallelism"],
+        );
+        
+
+@override BigInt crateBatchBatchParallelism()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBatchBatchParallelismConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBatchBatchParallelismConstMeta => const TaskConstMeta(
+            debugName: "batch_parallelism",
+            argNames: [],
+        );
+        
+
+@override String crateKdfBcryptHash({required List<int> password , required int cost })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_u_32(cost, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfBcryptHashConstMeta,
+            argValues: [password, cost],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfBcryptHashConstMeta => const TaskConstMeta(
+            debugName: "bcrypt_hash",
+            argNames: ["password", "cost"],
+        );
+        
+
+@override Future<String> crateKdfBcryptHashAsync({required List<int> password , required int cost })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_u_32(cost, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfBcryptHashAsyncConstMeta,
+            argValues: [password, cost],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfBcryptHashAsyncConstMeta => const TaskConstMeta(
+            debugName: "bcrypt_hash_async",
+            argNames: ["password", "cost"],
+        );
+        
+
+@override bool crateKdfBcryptVerify({required List<int> password , required String hash })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_String(hash, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfBcryptVerifyConstMeta,
+            argValues: [password, hash],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfBcryptVerifyConstMeta => const TaskConstMeta(
+            debugName: "bcrypt_verify",
+            argNames: ["password", "hash"],
+        );
+        
+
+@override Future<bool> crateKdfBcryptVerifyAsync({required List<int> password , required String hash })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_String(hash, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfBcryptVerifyAsyncConstMeta,
+            argValues: [password, hash],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfBcryptVerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "bcrypt_verify_async",
+            argNames: ["password", "hash"],
+        );
+        
+
+@override U8Array64 crateBlake2B({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BConstMeta => const TaskConstMeta(
+            debugName: "blake2b",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array64> crateBlake2BAsync({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BAsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2b_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array64> crateBlake2BBatch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BBatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BBatchConstMeta => const TaskConstMeta(
+            debugName: "blake2b_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array64>> crateBlake2BBatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2BBatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BBatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2b_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override Uint8List crateBlake2BKeyed({required List<int> key , required List<int> data , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2BKeyedConstMeta,
+            argValues: [key, data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BKeyedConstMeta => const TaskConstMeta(
+            debugName: "blake2b_keyed",
+            argNames: ["key", "data", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateBlake2BKeyedAsync({required List<int> key , required List<int> data , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2BKeyedAsyncConstMeta,
+            argValues: [key, data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BKeyedAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2b_keyed_async",
+            argNames: ["key", "data", "outputLen"],
+        );
+        
+
+@override Uint8List crateBlake2BWithLen({required List<int> data , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2BWithLenConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BWithLenConstMeta => const TaskConstMeta(
+            debugName: "blake2b_with_len",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateBlake2BWithLenAsync({required List<int> data , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2BWithLenAsyncConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2BWithLenAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2b_with_len_async",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override U8Array32 crateBlake2S({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SConstMeta => const TaskConstMeta(
+            debugName: "blake2s",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array32> crateBlake2SAsync({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SAsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2s_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array32> crateBlake2SBatch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SBatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SBatchConstMeta => const TaskConstMeta(
+            debugName: "blake2s_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array32>> crateBlake2SBatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake2SBatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SBatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2s_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override Uint8List crateBlake2SKeyed({required List<int> key , required List<int> data , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2SKeyedConstMeta,
+            argValues: [key, data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SKeyedConstMeta => const TaskConstMeta(
+            debugName: "blake2s_keyed",
+            argNames: ["key", "data", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateBlake2SKeyedAsync({required List<int> key , required List<int> data , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2SKeyedAsyncConstMeta,
+            argValues: [key, data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SKeyedAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2s_keyed_async",
+            argNames: ["key", "data", "outputLen"],
+        );
+        
+
+@override Uint8List crateBlake2SWithLen({required List<int> data , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2SWithLenConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SWithLenConstMeta => const TaskConstMeta(
+            debugName: "blake2s_with_len",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateBlake2SWithLenAsync({required List<int> data , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake2SWithLenAsyncConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake2SWithLenAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake2s_with_len_async",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override U8Array32 crateBlake3({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3ConstMeta => const TaskConstMeta(
+            debugName: "blake3",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array32> crateBlake3Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3AsyncConstMeta => const TaskConstMeta(
+            debugName: "blake3_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array32> crateBlake3Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3BatchConstMeta => const TaskConstMeta(
+            debugName: "blake3_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array32>> crateBlake3BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake3_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array32 crateBlake3DeriveKey({required String context , required List<int> keyMaterial })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(context, serializer);
+sse_encode_list_prim_u_8_loose(keyMaterial, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 263)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3DeriveKeyConstMeta,
+            argValues: [context, keyMaterial],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3DeriveKeyConstMeta => const TaskConstMeta(
+            debugName: "blake3_derive_key",
+            argNames: ["context", "keyMaterial"],
+        );
+        
+
+@override Future<U8Array32> crateBlake3DeriveKeyAsync({required String context , required List<int> keyMaterial })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(context, serializer);
+sse_encode_list_prim_u_8_loose(keyMaterial, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 264, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBlake3DeriveKeyAsyncConstMeta,
+            argValues: [context, keyMaterial],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3DeriveKeyAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake3_derive_key_async",
+            argNames: ["context", "keyMaterial"],
+        );
+        
+
+@override U8Array32 crateBlake3Keyed({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 265)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake3KeyedConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3KeyedConstMeta => const TaskConstMeta(
+            debugName: "blake3_keyed",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array32> crateBlake3KeyedAsync({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 266, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBlake3KeyedAsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBlake3KeyedAsyncConstMeta => const TaskConstMeta(
+            debugName: "blake3_keyed_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Uint8List crateChacha20Poly1305Decrypt({required List<int> ciphertext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 267)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305DecryptConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305DecryptConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_decrypt",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateChacha20Poly1305DecryptAsync({required List<int> ciphertext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 268, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305DecryptAsyncConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305DecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_decrypt_async",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Uint8List crateChacha20Poly1305DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 269)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305DecryptWithAadConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305DecryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_decrypt_with_aad",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateChacha20Poly1305DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 270, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305DecryptWithAadAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305DecryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_decrypt_with_aad_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateChacha20Poly1305Encrypt({required List<int> plaintext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 271)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305EncryptConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305EncryptConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_encrypt",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateChacha20Poly1305EncryptAsync({required List<int> plaintext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 272, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305EncryptAsyncConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305EncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_encrypt_async",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Uint8List crateChacha20Poly1305EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 273)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305EncryptWithAadConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305EncryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_encrypt_with_aad",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateChacha20Poly1305EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 274, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateChacha20Poly1305EncryptWithAadAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChacha20Poly1305EncryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "chacha20_poly1305_encrypt_with_aad_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override bool crateConstantTimeEq({required List<int> a , required List<int> b })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(a, serializer);
+sse_encode_list_prim_u_8_loose(b, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 275)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateConstantTimeEqConstMeta,
+            argValues: [a, b],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateConstantTimeEqConstMeta => const TaskConstMeta(
+            debugName: "constant_time_eq",
+            argNames: ["a", "b"],
+        );
+        
+
+@override BigInt crateEncryptedFileDecryptFile({required String inputPath , required String outputPath , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputPath, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 276)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileDecryptFileConstMeta,
+            argValues: [inputPath, outputPath, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileDecryptFileConstMeta => const TaskConstMeta(
+            debugName: "decrypt_file",
+            argNames: ["inputPath", "outputPath", "key"],
+        );
+        
+
+@override Future<BigInt> crateEncryptedFileDecryptFileAsync({required String inputPath , required String outputPath , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputPath, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 277, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileDecryptFileAsyncConstMeta,
+            argValues: [inputPath, outputPath, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileDecryptFileAsyncConstMeta => const TaskConstMeta(
+            debugName: "decrypt_file_async",
+            argNames: ["inputPath", "outputPath", "key"],
+        );
+        
+
+@override Uint8List crateEncryptedFileDecryptRange({required String path , required List<int> key , required BigInt offset , required BigInt len })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_u_64(offset, serializer);
+sse_encode_u_64(len, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 278)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileDecryptRangeConstMeta,
+            argValues: [path, key, offset, len],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileDecryptRangeConstMeta => const TaskConstMeta(
+            debugName: "decrypt_range",
+            argNames: ["path", "key", "offset", "len"],
+        );
+        
+
+@override Future<Uint8List> crateEncryptedFileDecryptRangeAsync({required String path , required List<int> key , required BigInt offset , required BigInt len })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_u_64(offset, serializer);
+sse_encode_u_64(len, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 279, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileDecryptRangeAsyncConstMeta,
+            argValues: [path, key, offset, len],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileDecryptRangeAsyncConstMeta => const TaskConstMeta(
+            debugName: "decrypt_range_async",
+            argNames: ["path", "key", "offset", "len"],
+        );
+        
+
+@override EcdsaKeyPair crateEcdsaEcdsaP256GenerateKeypair()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 280)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ecdsa_key_pair,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256GenerateKeypairConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256GenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_generate_keypair",
+            argNames: [],
+        );
+        
+
+@override bool crateEcdsaEcdsaP256IsValidPublicKey({required List<int> publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 281)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256IsValidPublicKeyConstMeta,
+            argValues: [publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256IsValidPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_is_valid_public_key",
+            argNames: ["publicKey"],
+        );
+        
+
+@override EcdsaKeyPair crateEcdsaEcdsaP256KeypairFromPrivateKey({required List<int> privateKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 282)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ecdsa_key_pair,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256KeypairFromPrivateKeyConstMeta,
+            argValues: [privateKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256KeypairFromPrivateKeyConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_keypair_from_private_key",
+            argNames: ["privateKey"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP256Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 283)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256SignConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateEcdsaEcdsaP256SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 284, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP256SignDer({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 285)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256SignDerConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256SignDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_sign_der",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateEcdsaEcdsaP256SignDerAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 286, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256SignDerAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256SignDerAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_sign_der_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP256SignatureFromDer({required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 287)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256SignatureFromDerConstMeta,
+            argValues: [signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256SignatureFromDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_signature_from_der",
+            argNames: ["signature"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP256SignatureToDer({required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 288)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256SignatureToDerConstMeta,
+            argValues: [signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256SignatureToDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_signature_to_der",
+            argNames: ["signature"],
+        );
+        
+
+@override bool crateEcdsaEcdsaP256Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 289)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256VerifyConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateEcdsaEcdsaP256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 290, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override bool crateEcdsaEcdsaP256VerifyDer({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 291)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256VerifyDerConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256VerifyDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_verify_der",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateEcdsaEcdsaP256VerifyDerAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 292, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP256VerifyDerAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP256VerifyDerAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p256_verify_der_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override EcdsaKeyPair crateEcdsaEcdsaP384GenerateKeypair()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 293)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ecdsa_key_pair,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384GenerateKeypairConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384GenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_generate_keypair",
+            argNames: [],
+        );
+        
+
+@override bool crateEcdsaEcdsaP384IsValidPublicKey({required List<int> publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 294)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384IsValidPublicKeyConstMeta,
+            argValues: [publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384IsValidPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_is_valid_public_key",
+            argNames: ["publicKey"],
+        );
+        
+
+@override EcdsaKeyPair crateEcdsaEcdsaP384KeypairFromPrivateKey({required List<int> privateKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 295)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ecdsa_key_pair,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384KeypairFromPrivateKeyConstMeta,
+            argValues: [privateKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384KeypairFromPrivateKeyConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_keypair_from_private_key",
+            argNames: ["privateKey"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP384Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 296)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384SignConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateEcdsaEcdsaP384SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 297, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP384SignDer({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 298)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384SignDerConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384SignDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_sign_der",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateEcdsaEcdsaP384SignDerAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 299, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384SignDerAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384SignDerAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_sign_der_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP384SignatureFromDer({required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 300)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384SignatureFromDerConstMeta,
+            argValues: [signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384SignatureFromDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_signature_from_der",
+            argNames: ["signature"],
+        );
+        
+
+@override Uint8List crateEcdsaEcdsaP384SignatureToDer({required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 301)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384SignatureToDerConstMeta,
+            argValues: [signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384SignatureToDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_signature_to_der",
+            argNames: ["signature"],
+        );
+        
+
+@override bool crateEcdsaEcdsaP384Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 302)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384VerifyConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateEcdsaEcdsaP384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 303, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override bool crateEcdsaEcdsaP384VerifyDer({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 304)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384VerifyDerConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384VerifyDerConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_verify_der",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateEcdsaEcdsaP384VerifyDerAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 305, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEcdsaEcdsaP384VerifyDerAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEcdsaEcdsaP384VerifyDerAsyncConstMeta => const TaskConstMeta(
+            debugName: "ecdsa_p384_verify_der_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Ed25519KeyPair crateEd25519Ed25519GenerateKeypair()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 306)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ed_25519_key_pair,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519GenerateKeypairConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519GenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "ed25519_generate_keypair",
+            argNames: [],
+        );
+        
+
+@override bool crateEd25519Ed25519IsValidPublicKey({required List<int> publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 307)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519IsValidPublicKeyConstMeta,
+            argValues: [publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519IsValidPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "ed25519_is_valid_public_key",
+            argNames: ["publicKey"],
+        );
+        
+
+@override Ed25519KeyPair crateEd25519Ed25519KeypairFromSeed({required List<int> seed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(seed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 308)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ed_25519_key_pair,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519KeypairFromSeedConstMeta,
+            argValues: [seed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519KeypairFromSeedConstMeta => const TaskConstMeta(
+            debugName: "ed25519_keypair_from_seed",
+            argNames: ["seed"],
+        );
+        
+
+@override U8Array64 crateEd25519Ed25519Sign({required List<int> seed , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(seed, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 309)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519SignConstMeta,
+            argValues: [seed, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519SignConstMeta => const TaskConstMeta(
+            debugName: "ed25519_sign",
+            argNames: ["seed", "message"],
+        );
+        
+
+@override Future<U8Array64> crateEd25519Ed25519SignAsync({required List<int> seed , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(seed, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 310, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519SignAsyncConstMeta,
+            argValues: [seed, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "ed25519_sign_async",
+            argNames: ["seed", "message"],
+        );
+        
+
+@override bool crateEd25519Ed25519Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 311)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519VerifyConstMeta => const TaskConstMeta(
+            debugName: "ed25519_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateEd25519Ed25519VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 312, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "ed25519_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override bool crateEd25519Ed25519VerifyBatch({required List<Uint8List> publicKeys , required List<Uint8List> messages , required List<Uint8List> signatures })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(publicKeys, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+sse_encode_list_list_prim_u_8_strict(signatures, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 313)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519VerifyBatchConstMeta,
+            argValues: [publicKeys, messages, signatures],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519VerifyBatchConstMeta => const TaskConstMeta(
+            debugName: "ed25519_verify_batch",
+            argNames: ["publicKeys", "messages", "signatures"],
+        );
+        
+
+@override Future<bool> crateEd25519Ed25519VerifyBatchAsync({required List<Uint8List> publicKeys , required List<Uint8List> messages , required List<Uint8List> signatures })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(publicKeys, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+sse_encode_list_list_prim_u_8_strict(signatures, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 314, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEd25519Ed25519VerifyBatchAsyncConstMeta,
+            argValues: [publicKeys, messages, signatures],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEd25519Ed25519VerifyBatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "ed25519_verify_batch_async",
+            argNames: ["publicKeys", "messages", "signatures"],
+        );
+        
+
+@override BigInt crateEncryptedFileEncryptFile({required String inputPath , required String outputPath , required List<int> key , required int chunkSize })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputPath, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_u_32(chunkSize, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 315)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileEncryptFileConstMeta,
+            argValues: [inputPath, outputPath, key, chunkSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileEncryptFileConstMeta => const TaskConstMeta(
+            debugName: "encrypt_file",
+            argNames: ["inputPath", "outputPath", "key", "chunkSize"],
+        );
+        
+
+@override Future<BigInt> crateEncryptedFileEncryptFileAsync({required String inputPath , required String outputPath , required List<int> key , required int chunkSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(inputPath, serializer);
+sse_encode_String(outputPath, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_u_32(chunkSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 316, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileEncryptFileAsyncConstMeta,
+            argValues: [inputPath, outputPath, key, chunkSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileEncryptFileAsyncConstMeta => const TaskConstMeta(
+            debugName: "encrypt_file_async",
+            argNames: ["inputPath", "outputPath", "key", "chunkSize"],
+        );
+        
+
+@override (Uint8List,U8Array32) crateEncryptThenHmac({required List<int> plaintext , required List<int> encKey , required List<int> macKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 317)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptThenHmacConstMeta,
+            argValues: [plaintext, encKey, macKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptThenHmacConstMeta => const TaskConstMeta(
+            debugName: "encrypt_then_hmac",
+            argNames: ["plaintext", "encKey", "macKey"],
+        );
+        
+
+@override Future<(Uint8List,U8Array32)> crateEncryptThenHmacAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 318, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptThenHmacAsyncConstMeta,
+            argValues: [plaintext, encKey, macKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptThenHmacAsyncConstMeta => const TaskConstMeta(
+            debugName: "encrypt_then_hmac_async",
+            argNames: ["plaintext", "encKey", "macKey"],
+        );
+        
+
+@override (Uint8List,U8Array32) crateEncryptThenHmacWithAad({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 319)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptThenHmacWithAadConstMeta,
+            argValues: [plaintext, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptThenHmacWithAadConstMeta => const TaskConstMeta(
+            debugName: "encrypt_then_hmac_with_aad",
+            argNames: ["plaintext", "encKey", "macKey", "aad"],
+        );
+        
+
+@override Future<(Uint8List,U8Array32)> crateEncryptThenHmacWithAadAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 320, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_8_strict_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptThenHmacWithAadAsyncConstMeta,
+            argValues: [plaintext, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptThenHmacWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "encrypt_then_hmac_with_aad_async",
+            argNames: ["plaintext", "encKey", "macKey", "aad"],
+        );
+        
+
+@override BigInt crateEncryptedFileEncryptedFilePlaintextLen({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 321)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateEncryptedFileEncryptedFilePlaintextLenConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateEncryptedFileEncryptedFilePlaintextLenConstMeta => const TaskConstMeta(
+            debugName: "encrypted_file_plaintext_len",
+            argNames: ["path"],
+        );
+        
+
+@override Uint8List crateFromHex({required String hexString })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(hexString, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 322)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateFromHexConstMeta,
+            argValues: [hexString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFromHexConstMeta => const TaskConstMeta(
+            debugName: "from_hex",
+            argNames: ["hexString"],
+        );
+        
+
+@override List<String> crateGetAllAlgorithms()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 323)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateGetAllAlgorithmsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateGetAllAlgorithmsConstMeta =>
-      const TaskConstMeta(debugName: "get_all_algorithms", argNames: []);
-
-  @override
-  BigInt crateHashSize({required String algorithm}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(algorithm, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateGetAllAlgorithmsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateGetAllAlgorithmsConstMeta => const TaskConstMeta(
+            debugName: "get_all_algorithms",
+            argNames: [],
+        );
+        
+
+@override Uint8List crateHashAlgorithmHash({required HashAlgorithm algorithm , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 324)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHashConstMeta,
+            argValues: [algorithm, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHashConstMeta => const TaskConstMeta(
+            debugName: "hash",
+            argNames: ["algorithm", "data"],
+        );
+        
+
+@override HashAlgorithm crateHashAlgorithmHashAlgorithmFromName({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 325)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hash_algorithm,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHashAlgorithmFromNameConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHashAlgorithmFromNameConstMeta => const TaskConstMeta(
+            debugName: "hash_algorithm_from_name",
+            argNames: ["name"],
+        );
+        
+
+@override HashAlgorithmInfo crateHashAlgorithmHashAlgorithmInfo({required HashAlgorithm algorithm })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 326)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hash_algorithm_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHashAlgorithmInfoConstMeta,
+            argValues: [algorithm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHashAlgorithmInfoConstMeta => const TaskConstMeta(
+            debugName: "hash_algorithm_info",
+            argNames: ["algorithm"],
+        );
+        
+
+@override Future<Uint8List> crateHashAlgorithmHashAsync({required HashAlgorithm algorithm , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 327, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHashAsyncConstMeta,
+            argValues: [algorithm, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHashAsyncConstMeta => const TaskConstMeta(
+            debugName: "hash_async",
+            argNames: ["algorithm", "data"],
+        );
+        
+
+@override List<Uint8List> crateBatchHashBatch({required HashAlgorithm algorithm , required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 328)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBatchHashBatchConstMeta,
+            argValues: [algorithm, inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBatchHashBatchConstMeta => const TaskConstMeta(
+            debugName: "hash_batch",
+            argNames: ["algorithm", "inputs"],
+        );
+        
+
+@override Future<List<Uint8List>> crateBatchHashBatchAsync({required HashAlgorithm algorithm , required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 329, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBatchHashBatchAsyncConstMeta,
+            argValues: [algorithm, inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBatchHashBatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "hash_batch_async",
+            argNames: ["algorithm", "inputs"],
+        );
+        
+
+@override BigInt crateHashSize({required String algorithm })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(algorithm, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 330)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_usize,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHashSizeConstMeta,
-        argValues: [algorithm],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHashSizeConstMeta =>
-      const TaskConstMeta(debugName: "hash_size", argNames: ["algorithm"]);
-
-  @override
-  Uint8List? crateHashThenEncrypt({
-    required List<int> data,
-    required List<int> key,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateHashThenEncryptConstMeta,
-        argValues: [data, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHashThenEncryptConstMeta => const TaskConstMeta(
-    debugName: "hash_then_encrypt",
-    argNames: ["data", "key"],
-  );
-
-  @override
-  Future<Uint8List?> crateHashThenEncryptAsync({
-    required List<int> data,
-    required List<int> key,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateHashThenEncryptAsyncConstMeta,
-        argValues: [data, key],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHashThenEncryptAsyncConstMeta => const TaskConstMeta(
-    debugName: "hash_then_encrypt_async",
-    argNames: ["data", "key"],
-  );
-
-  @override
-  U8Array16 crateHmacMd5({required List<int> key, required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHashSizeConstMeta,
+            argValues: [algorithm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashSizeConstMeta => const TaskConstMeta(
+            debugName: "hash_size",
+            argNames: ["algorithm"],
+        );
+        
+
+@override Uint8List crateHashThenEncrypt({required List<int> data , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 331)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashThenEncryptConstMeta,
+            argValues: [data, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashThenEncryptConstMeta => const TaskConstMeta(
+            debugName: "hash_then_encrypt",
+            argNames: ["data", "key"],
+        );
+        
+
+@override Future<Uint8List> crateHashThenEncryptAsync({required List<int> data , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 332, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashThenEncryptAsyncConstMeta,
+            argValues: [data, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashThenEncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "hash_then_encrypt_async",
+            argNames: ["data", "key"],
+        );
+        
+
+@override Uint8List crateHashThenEncryptWithAad({required List<int> data , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 333)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashThenEncryptWithAadConstMeta,
+            argValues: [data, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashThenEncryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "hash_then_encrypt_with_aad",
+            argNames: ["data", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateHashThenEncryptWithAadAsync({required List<int> data , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 334, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashThenEncryptWithAadAsyncConstMeta,
+            argValues: [data, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashThenEncryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "hash_then_encrypt_with_aad_async",
+            argNames: ["data", "key", "aad"],
+        );
+        
+
+@override Uint8List crateKdfHkdfSha256({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ikm, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 335)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha256ConstMeta,
+            argValues: [ikm, salt, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha256ConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha256",
+            argNames: ["ikm", "salt", "info", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfHkdfSha256Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ikm, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 336, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha256AsyncConstMeta,
+            argValues: [ikm, salt, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha256AsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha256_async",
+            argNames: ["ikm", "salt", "info", "outputLen"],
+        );
+        
+
+@override Uint8List crateKdfHkdfSha256Expand({required List<int> prk , required List<int> info , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(prk, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 337)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha256ExpandConstMeta,
+            argValues: [prk, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha256ExpandConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha256_expand",
+            argNames: ["prk", "info", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfHkdfSha256ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(prk, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 338, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha256ExpandAsyncConstMeta,
+            argValues: [prk, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha256ExpandAsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha256_expand_async",
+            argNames: ["prk", "info", "outputLen"],
+        );
+        
+
+@override U8Array32 crateKdfHkdfSha256Extract({required List<int> salt , required List<int> ikm })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(ikm, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 339)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha256ExtractConstMeta,
+            argValues: [salt, ikm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha256ExtractConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha256_extract",
+            argNames: ["salt", "ikm"],
+        );
+        
+
+@override Future<U8Array32> crateKdfHkdfSha256ExtractAsync({required List<int> salt , required List<int> ikm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(ikm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 340, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha256ExtractAsyncConstMeta,
+            argValues: [salt, ikm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha256ExtractAsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha256_extract_async",
+            argNames: ["salt", "ikm"],
+        );
+        
+
+@override Uint8List crateKdfHkdfSha384({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ikm, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 341)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha384ConstMeta,
+            argValues: [ikm, salt, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha384ConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha384",
+            argNames: ["ikm", "salt", "info", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfHkdfSha384Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ikm, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 342, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha384AsyncConstMeta,
+            argValues: [ikm, salt, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha384AsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha384_async",
+            argNames: ["ikm", "salt", "info", "outputLen"],
+        );
+        
+
+@override Uint8List crateKdfHkdfSha384Expand({required List<int> prk , required List<int> info , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(prk, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 343)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha384ExpandConstMeta,
+            argValues: [prk, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha384ExpandConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha384_expand",
+            argNames: ["prk", "info", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfHkdfSha384ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(prk, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 344, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha384ExpandAsyncConstMeta,
+            argValues: [prk, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha384ExpandAsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha384_expand_async",
+            argNames: ["prk", "info", "outputLen"],
+        );
+        
+
+@override U8Array48 crateKdfHkdfSha384Extract({required List<int> salt , required List<int> ikm })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(ikm, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 345)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha384ExtractConstMeta,
+            argValues: [salt, ikm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha384ExtractConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha384_extract",
+            argNames: ["salt", "ikm"],
+        );
+        
+
+@override Future<U8Array48> crateKdfHkdfSha384ExtractAsync({required List<int> salt , required List<int> ikm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(ikm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 346, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha384ExtractAsyncConstMeta,
+            argValues: [salt, ikm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha384ExtractAsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha384_extract_async",
+            argNames: ["salt", "ikm"],
+        );
+        
+
+@override Uint8List crateKdfHkdfSha512({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ikm, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 347)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha512ConstMeta,
+            argValues: [ikm, salt, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha512ConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha512",
+            argNames: ["ikm", "salt", "info", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfHkdfSha512Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ikm, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 348, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha512AsyncConstMeta,
+            argValues: [ikm, salt, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha512AsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha512_async",
+            argNames: ["ikm", "salt", "info", "outputLen"],
+        );
+        
+
+@override Uint8List crateKdfHkdfSha512Expand({required List<int> prk , required List<int> info , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(prk, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 349)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha512ExpandConstMeta,
+            argValues: [prk, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha512ExpandConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha512_expand",
+            argNames: ["prk", "info", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfHkdfSha512ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(prk, serializer);
+sse_encode_list_prim_u_8_loose(info, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 350, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha512ExpandAsyncConstMeta,
+            argValues: [prk, info, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha512ExpandAsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha512_expand_async",
+            argNames: ["prk", "info", "outputLen"],
+        );
+        
+
+@override U8Array64 crateKdfHkdfSha512Extract({required List<int> salt , required List<int> ikm })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(ikm, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 351)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha512ExtractConstMeta,
+            argValues: [salt, ikm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha512ExtractConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha512_extract",
+            argNames: ["salt", "ikm"],
+        );
+        
+
+@override Future<U8Array64> crateKdfHkdfSha512ExtractAsync({required List<int> salt , required List<int> ikm })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_list_prim_u_8_loose(ikm, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 352, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKdfHkdfSha512ExtractAsyncConstMeta,
+            argValues: [salt, ikm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfHkdfSha512ExtractAsyncConstMeta => const TaskConstMeta(
+            debugName: "hkdf_sha512_extract_async",
+            argNames: ["salt", "ikm"],
+        );
+        
+
+@override Uint8List crateHashAlgorithmHmac({required HashAlgorithm algorithm , required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 353)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHmacConstMeta,
+            argValues: [algorithm, key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHmacConstMeta => const TaskConstMeta(
+            debugName: "hmac",
+            argNames: ["algorithm", "key", "data"],
+        );
+        
+
+@override Future<Uint8List> crateHashAlgorithmHmacAsync({required HashAlgorithm algorithm , required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 354, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmHmacAsyncConstMeta,
+            argValues: [algorithm, key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmHmacAsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_async",
+            argNames: ["algorithm", "key", "data"],
+        );
+        
+
+@override List<Uint8List> crateBatchHmacBatch({required HashAlgorithm algorithm , required List<int> key , required List<Uint8List> messages })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 355)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBatchHmacBatchConstMeta,
+            argValues: [algorithm, key, messages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBatchHmacBatchConstMeta => const TaskConstMeta(
+            debugName: "hmac_batch",
+            argNames: ["algorithm", "key", "messages"],
+        );
+        
+
+@override Future<List<Uint8List>> crateBatchHmacBatchAsync({required HashAlgorithm algorithm , required List<int> key , required List<Uint8List> messages })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 356, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBatchHmacBatchAsyncConstMeta,
+            argValues: [algorithm, key, messages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBatchHmacBatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_batch_async",
+            argNames: ["algorithm", "key", "messages"],
+        );
+        
+
+@override U8Array16 crateHmacMd5({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 357)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_16,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacMd5ConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacMd5ConstMeta =>
-      const TaskConstMeta(debugName: "hmac_md5", argNames: ["key", "data"]);
-
-  @override
-  Future<U8Array16> crateHmacMd5Async({
-    required List<int> key,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacMd5ConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacMd5ConstMeta => const TaskConstMeta(
+            debugName: "hmac_md5",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array16> crateHmacMd5Async({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 358, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_16,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacMd5AsyncConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacMd5AsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_md5_async",
-    argNames: ["key", "data"],
-  );
-
-  @override
-  U8Array20 crateHmacSha1({required List<int> key, required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacMd5AsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacMd5AsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_md5_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override U8Array20 crateHmacSha1({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 359)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_20,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha1ConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha1ConstMeta =>
-      const TaskConstMeta(debugName: "hmac_sha1", argNames: ["key", "data"]);
-
-  @override
-  Future<U8Array20> crateHmacSha1Async({
-    required List<int> key,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha1ConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha1ConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha1",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array20> crateHmacSha1Async({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 360, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_20,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha1AsyncConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha1AsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha1_async",
-    argNames: ["key", "data"],
-  );
-
-  @override
-  U8Array28 crateHmacSha224({required List<int> key, required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha1AsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha1AsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha1_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override U8Array28 crateHmacSha224({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 361)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_28,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha224ConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha224ConstMeta =>
-      const TaskConstMeta(debugName: "hmac_sha224", argNames: ["key", "data"]);
-
-  @override
-  Future<U8Array28> crateHmacSha224Async({
-    required List<int> key,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha224ConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha224ConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha224",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array28> crateHmacSha224Async({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 362, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_28,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha224AsyncConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha224AsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha224_async",
-    argNames: ["key", "data"],
-  );
-
-  @override
-  U8Array32 crateHmacSha256({required List<int> key, required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha224AsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha224AsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha224_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override U8Array32 crateHmacSha256({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 363)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha256ConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha256ConstMeta =>
-      const TaskConstMeta(debugName: "hmac_sha256", argNames: ["key", "data"]);
-
-  @override
-  Future<U8Array32> crateHmacSha256Async({
-    required List<int> key,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha256ConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha256ConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha256",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array32> crateHmacSha256Async({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 364, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha256AsyncConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha256AsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha256_async",
-    argNames: ["key", "data"],
-  );
-
-  @override
-  List<U8Array32> crateHmacSha256Batch({
-    required List<int> key,
-    required List<Uint8List> messages,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_list_prim_u_8_strict(messages, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha256AsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha256AsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha256_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override List<U8Array32> crateHmacSha256Batch({required List<int> key , required List<Uint8List> messages })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 365)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha256BatchConstMeta,
-        argValues: [key, messages],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha256BatchConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha256_batch",
-    argNames: ["key", "messages"],
-  );
-
-  @override
-  Future<List<U8Array32>> crateHmacSha256BatchAsync({
-    required List<int> key,
-    required List<Uint8List> messages,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_list_prim_u_8_strict(messages, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha256BatchConstMeta,
+            argValues: [key, messages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha256BatchConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha256_batch",
+            argNames: ["key", "messages"],
+        );
+        
+
+@override Future<List<U8Array32>> crateHmacSha256BatchAsync({required List<int> key , required List<Uint8List> messages })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 366, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha256BatchAsyncConstMeta,
-        argValues: [key, messages],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha256BatchAsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha256_batch_async",
-    argNames: ["key", "messages"],
-  );
-
-  @override
-  U8Array48 crateHmacSha384({required List<int> key, required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha256BatchAsyncConstMeta,
+            argValues: [key, messages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha256BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha256_batch_async",
+            argNames: ["key", "messages"],
+        );
+        
+
+@override U8Array48 crateHmacSha384({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 367)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_48,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha384ConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha384ConstMeta =>
-      const TaskConstMeta(debugName: "hmac_sha384", argNames: ["key", "data"]);
-
-  @override
-  Future<U8Array48> crateHmacSha384Async({
-    required List<int> key,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha384ConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha384ConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha384",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array48> crateHmacSha384Async({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 368, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_48,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha384AsyncConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha384AsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha384_async",
-    argNames: ["key", "data"],
-  );
-
-  @override
-  U8Array64 crateHmacSha512({required List<int> key, required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha384AsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha384AsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha384_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override U8Array64 crateHmacSha512({required List<int> key , required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 369)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha512ConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha512ConstMeta =>
-      const TaskConstMeta(debugName: "hmac_sha512", argNames: ["key", "data"]);
-
-  @override
-  Future<U8Array64> crateHmacSha512Async({
-    required List<int> key,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha512ConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha512ConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha512",
+            argNames: ["key", "data"],
+        );
+        
+
+@override Future<U8Array64> crateHmacSha512Async({required List<int> key , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 370, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha512AsyncConstMeta,
-        argValues: [key, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha512AsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha512_async",
-    argNames: ["key", "data"],
-  );
-
-  @override
-  List<U8Array64> crateHmacSha512Batch({
-    required List<int> key,
-    required List<Uint8List> messages,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_list_prim_u_8_strict(messages, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha512AsyncConstMeta,
+            argValues: [key, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha512AsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha512_async",
+            argNames: ["key", "data"],
+        );
+        
+
+@override List<U8Array64> crateHmacSha512Batch({required List<int> key , required List<Uint8List> messages })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 371)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha512BatchConstMeta,
-        argValues: [key, messages],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha512BatchConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha512_batch",
-    argNames: ["key", "messages"],
-  );
-
-  @override
-  Future<List<U8Array64>> crateHmacSha512BatchAsync({
-    required List<int> key,
-    required List<Uint8List> messages,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(key, serializer);
-          sse_encode_list_list_prim_u_8_strict(messages, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha512BatchConstMeta,
+            argValues: [key, messages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha512BatchConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha512_batch",
+            argNames: ["key", "messages"],
+        );
+        
+
+@override Future<List<U8Array64>> crateHmacSha512BatchAsync({required List<int> key , required List<Uint8List> messages })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_list_prim_u_8_strict(messages, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 372, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateHmacSha512BatchAsyncConstMeta,
-        argValues: [key, messages],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateHmacSha512BatchAsyncConstMeta => const TaskConstMeta(
-    debugName: "hmac_sha512_batch_async",
-    argNames: ["key", "messages"],
-  );
-
-  @override
-  U8Array16 crateMd5({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateHmacSha512BatchAsyncConstMeta,
+            argValues: [key, messages],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHmacSha512BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "hmac_sha512_batch_async",
+            argNames: ["key", "messages"],
+        );
+        
+
+@override String crateJweJweDecodeHeader({required String token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 373)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJweJweDecodeHeaderConstMeta,
+            argValues: [token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJweJweDecodeHeaderConstMeta => const TaskConstMeta(
+            debugName: "jwe_decode_header",
+            argNames: ["token"],
+        );
+        
+
+@override JweDecrypted crateJweJweDecrypt({required String token , required String algorithm , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 374)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_jwe_decrypted,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJweJweDecryptConstMeta,
+            argValues: [token, algorithm, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJweJweDecryptConstMeta => const TaskConstMeta(
+            debugName: "jwe_decrypt",
+            argNames: ["token", "algorithm", "key"],
+        );
+        
+
+@override Future<JweDecrypted> crateJweJweDecryptAsync({required String token , required String algorithm , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 375, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_jwe_decrypted,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJweJweDecryptAsyncConstMeta,
+            argValues: [token, algorithm, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJweJweDecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "jwe_decrypt_async",
+            argNames: ["token", "algorithm", "key"],
+        );
+        
+
+@override String crateJweJweEncrypt({required String algorithm , required List<int> key , required List<int> plaintext , String? headerJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_opt_String(headerJson, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 376)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJweJweEncryptConstMeta,
+            argValues: [algorithm, key, plaintext, headerJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJweJweEncryptConstMeta => const TaskConstMeta(
+            debugName: "jwe_encrypt",
+            argNames: ["algorithm", "key", "plaintext", "headerJson"],
+        );
+        
+
+@override Future<String> crateJweJweEncryptAsync({required String algorithm , required List<int> key , required List<int> plaintext , String? headerJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_opt_String(headerJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 377, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJweJweEncryptAsyncConstMeta,
+            argValues: [algorithm, key, plaintext, headerJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJweJweEncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "jwe_encrypt_async",
+            argNames: ["algorithm", "key", "plaintext", "headerJson"],
+        );
+        
+
+@override JwsToken crateJwsJwsDecodeUnverified({required String token })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 378)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_jws_token,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJwsJwsDecodeUnverifiedConstMeta,
+            argValues: [token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJwsJwsDecodeUnverifiedConstMeta => const TaskConstMeta(
+            debugName: "jws_decode_unverified",
+            argNames: ["token"],
+        );
+        
+
+@override String crateJwsJwsSign({required String algorithm , required List<int> key , required String claimsJson , String? headerJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_String(claimsJson, serializer);
+sse_encode_opt_String(headerJson, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 379)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJwsJwsSignConstMeta,
+            argValues: [algorithm, key, claimsJson, headerJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJwsJwsSignConstMeta => const TaskConstMeta(
+            debugName: "jws_sign",
+            argNames: ["algorithm", "key", "claimsJson", "headerJson"],
+        );
+        
+
+@override Future<String> crateJwsJwsSignAsync({required String algorithm , required List<int> key , required String claimsJson , String? headerJson })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_String(claimsJson, serializer);
+sse_encode_opt_String(headerJson, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 380, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJwsJwsSignAsyncConstMeta,
+            argValues: [algorithm, key, claimsJson, headerJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJwsJwsSignAsyncConstMeta => const TaskConstMeta(
+            debugName: "jws_sign_async",
+            argNames: ["algorithm", "key", "claimsJson", "headerJson"],
+        );
+        
+
+@override JwsToken crateJwsJwsVerify({required String token , required String algorithm , required List<int> key , required BigInt leewaySeconds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_u_64(leewaySeconds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 381)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_jws_token,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJwsJwsVerifyConstMeta,
+            argValues: [token, algorithm, key, leewaySeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJwsJwsVerifyConstMeta => const TaskConstMeta(
+            debugName: "jws_verify",
+            argNames: ["token", "algorithm", "key", "leewaySeconds"],
+        );
+        
+
+@override Future<JwsToken> crateJwsJwsVerifyAsync({required String token , required String algorithm , required List<int> key , required BigInt leewaySeconds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(token, serializer);
+sse_encode_String(algorithm, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_u_64(leewaySeconds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 382, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_jws_token,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateJwsJwsVerifyAsyncConstMeta,
+            argValues: [token, algorithm, key, leewaySeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateJwsJwsVerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "jws_verify_async",
+            argNames: ["token", "algorithm", "key", "leewaySeconds"],
+        );
+        
+
+@override U8Array32 crateKeccak256({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 383)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256ConstMeta => const TaskConstMeta(
+            debugName: "keccak256",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array32> crateKeccak256Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 384, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256AsyncConstMeta => const TaskConstMeta(
+            debugName: "keccak256_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array32> crateKeccak256Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 385)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256BatchConstMeta => const TaskConstMeta(
+            debugName: "keccak256_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array32>> crateKeccak256BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 386, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateKeccak256BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKeccak256BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "keccak256_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array16 crateMd5({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 387)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_16,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateMd5ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMd5ConstMeta =>
-      const TaskConstMeta(debugName: "md5", argNames: ["data"]);
-
-  @override
-  Future<U8Array16> crateMd5Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateMd5ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5ConstMeta => const TaskConstMeta(
+            debugName: "md5",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array16> crateMd5Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 388, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_16,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateMd5AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMd5AsyncConstMeta =>
-      const TaskConstMeta(debugName: "md5_async", argNames: ["data"]);
-
-  @override
-  U8Array20 crateSha1({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateMd5AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMd5AsyncConstMeta => const TaskConstMeta(
+            debugName: "md5_async",
+            argNames: ["data"],
+        );
+        
+
+@override Hasher crateHashAlgorithmNewHasher({required HashAlgorithm algorithm })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_hash_algorithm(algorithm, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 389)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateHashAlgorithmNewHasherConstMeta,
+            argValues: [algorithm],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateHashAlgorithmNewHasherConstMeta => const TaskConstMeta(
+            debugName: "new_hasher",
+            argNames: ["algorithm"],
+        );
+        
+
+@override Uint8List crateX25519Open({required List<int> secretKey , required List<int> ciphertext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 390)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519OpenConstMeta,
+            argValues: [secretKey, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519OpenConstMeta => const TaskConstMeta(
+            debugName: "open",
+            argNames: ["secretKey", "ciphertext"],
+        );
+        
+
+@override Future<Uint8List> crateX25519OpenAsync({required List<int> secretKey , required List<int> ciphertext })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 391, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519OpenAsyncConstMeta,
+            argValues: [secretKey, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519OpenAsyncConstMeta => const TaskConstMeta(
+            debugName: "open_async",
+            argNames: ["secretKey", "ciphertext"],
+        );
+        
+
+@override Uint8List crateKdfPbkdf2HmacSha1({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 392)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfPbkdf2HmacSha1ConstMeta,
+            argValues: [password, salt, iterations, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfPbkdf2HmacSha1ConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2_hmac_sha1",
+            argNames: ["password", "salt", "iterations", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfPbkdf2HmacSha1Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 393, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfPbkdf2HmacSha1AsyncConstMeta,
+            argValues: [password, salt, iterations, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfPbkdf2HmacSha1AsyncConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2_hmac_sha1_async",
+            argNames: ["password", "salt", "iterations", "outputLen"],
+        );
+        
+
+@override Uint8List crateKdfPbkdf2HmacSha256({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 394)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfPbkdf2HmacSha256ConstMeta,
+            argValues: [password, salt, iterations, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfPbkdf2HmacSha256ConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2_hmac_sha256",
+            argNames: ["password", "salt", "iterations", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfPbkdf2HmacSha256Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 395, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfPbkdf2HmacSha256AsyncConstMeta,
+            argValues: [password, salt, iterations, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfPbkdf2HmacSha256AsyncConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2_hmac_sha256_async",
+            argNames: ["password", "salt", "iterations", "outputLen"],
+        );
+        
+
+@override Uint8List crateKdfPbkdf2HmacSha512({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 396)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfPbkdf2HmacSha512ConstMeta,
+            argValues: [password, salt, iterations, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfPbkdf2HmacSha512ConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2_hmac_sha512",
+            argNames: ["password", "salt", "iterations", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfPbkdf2HmacSha512Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_32(iterations, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 397, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfPbkdf2HmacSha512AsyncConstMeta,
+            argValues: [password, salt, iterations, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfPbkdf2HmacSha512AsyncConstMeta => const TaskConstMeta(
+            debugName: "pbkdf2_hmac_sha512_async",
+            argNames: ["password", "salt", "iterations", "outputLen"],
+        );
+        
+
+@override RsaKeyPair crateRsaCryptoRsaGenerateKeypair({required int bits })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(bits, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 398)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rsa_key_pair,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaGenerateKeypairConstMeta,
+            argValues: [bits],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaGenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "rsa_generate_keypair",
+            argNames: ["bits"],
+        );
+        
+
+@override Future<RsaKeyPair> crateRsaCryptoRsaGenerateKeypairAsync({required int bits })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(bits, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 399, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rsa_key_pair,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaGenerateKeypairAsyncConstMeta,
+            argValues: [bits],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaGenerateKeypairAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_generate_keypair_async",
+            argNames: ["bits"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaImportPrivateKey({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 400)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaImportPrivateKeyConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaImportPrivateKeyConstMeta => const TaskConstMeta(
+            debugName: "rsa_import_private_key",
+            argNames: ["key"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaImportPublicKey({required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 401)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaImportPublicKeyConstMeta,
+            argValues: [key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaImportPublicKeyConstMeta => const TaskConstMeta(
+            debugName: "rsa_import_public_key",
+            argNames: ["key"],
+        );
+        
+
+@override int crateRsaCryptoRsaKeySizeBits({required List<int> publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 402)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaKeySizeBitsConstMeta,
+            argValues: [publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaKeySizeBitsConstMeta => const TaskConstMeta(
+            debugName: "rsa_key_size_bits",
+            argNames: ["publicKey"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaOaepSha256Decrypt({required List<int> privateKey , required List<int> ciphertext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 403)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaOaepSha256DecryptConstMeta,
+            argValues: [privateKey, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaOaepSha256DecryptConstMeta => const TaskConstMeta(
+            debugName: "rsa_oaep_sha256_decrypt",
+            argNames: ["privateKey", "ciphertext"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaOaepSha256DecryptAsync({required List<int> privateKey , required List<int> ciphertext })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 404, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaOaepSha256DecryptAsyncConstMeta,
+            argValues: [privateKey, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaOaepSha256DecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_oaep_sha256_decrypt_async",
+            argNames: ["privateKey", "ciphertext"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaOaepSha256Encrypt({required List<int> publicKey , required List<int> plaintext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(plaintext, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 405)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaOaepSha256EncryptConstMeta,
+            argValues: [publicKey, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaOaepSha256EncryptConstMeta => const TaskConstMeta(
+            debugName: "rsa_oaep_sha256_encrypt",
+            argNames: ["publicKey", "plaintext"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaOaepSha256EncryptAsync({required List<int> publicKey , required List<int> plaintext })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(plaintext, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 406, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaOaepSha256EncryptAsyncConstMeta,
+            argValues: [publicKey, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaOaepSha256EncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_oaep_sha256_encrypt_async",
+            argNames: ["publicKey", "plaintext"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPkcs1V15Sha256Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 407)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha256SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha256SignConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha256_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaPkcs1V15Sha256SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 408, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha256SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha256SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha256_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override bool crateRsaCryptoRsaPkcs1V15Sha256Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 409)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha256VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha256VerifyConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha256_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateRsaCryptoRsaPkcs1V15Sha256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 410, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha256VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha256VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha256_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPkcs1V15Sha384Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 411)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha384SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha384SignConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha384_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaPkcs1V15Sha384SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 412, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha384SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha384SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha384_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override bool crateRsaCryptoRsaPkcs1V15Sha384Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 413)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha384VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha384VerifyConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha384_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateRsaCryptoRsaPkcs1V15Sha384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 414, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha384VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha384VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha384_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPkcs1V15Sha512Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 415)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha512SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha512SignConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha512_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaPkcs1V15Sha512SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 416, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha512SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha512SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha512_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override bool crateRsaCryptoRsaPkcs1V15Sha512Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 417)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha512VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha512VerifyConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha512_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateRsaCryptoRsaPkcs1V15Sha512VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 418, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPkcs1V15Sha512VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPkcs1V15Sha512VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pkcs1v15_sha512_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override String crateRsaCryptoRsaPrivateKeyToPem({required List<int> privateKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 419)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPrivateKeyToPemConstMeta,
+            argValues: [privateKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPrivateKeyToPemConstMeta => const TaskConstMeta(
+            debugName: "rsa_private_key_to_pem",
+            argNames: ["privateKey"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPssSha256Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 420)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha256SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha256SignConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha256_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaPssSha256SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 421, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha256SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha256SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha256_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override bool crateRsaCryptoRsaPssSha256Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 422)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha256VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha256VerifyConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha256_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateRsaCryptoRsaPssSha256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 423, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha256VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha256VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha256_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPssSha384Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 424)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha384SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha384SignConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha384_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaPssSha384SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 425, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha384SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha384SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha384_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override bool crateRsaCryptoRsaPssSha384Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 426)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha384VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha384VerifyConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha384_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateRsaCryptoRsaPssSha384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 427, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha384VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha384VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha384_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPssSha512Sign({required List<int> privateKey , required List<int> message })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 428)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha512SignConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha512SignConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha512_sign",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override Future<Uint8List> crateRsaCryptoRsaPssSha512SignAsync({required List<int> privateKey , required List<int> message })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 429, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha512SignAsyncConstMeta,
+            argValues: [privateKey, message],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha512SignAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha512_sign_async",
+            argNames: ["privateKey", "message"],
+        );
+        
+
+@override bool crateRsaCryptoRsaPssSha512Verify({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 430)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha512VerifyConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha512VerifyConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha512_verify",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Future<bool> crateRsaCryptoRsaPssSha512VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+sse_encode_list_prim_u_8_loose(message, serializer);
+sse_encode_list_prim_u_8_loose(signature, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 431, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPssSha512VerifyAsyncConstMeta,
+            argValues: [publicKey, message, signature],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPssSha512VerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "rsa_pss_sha512_verify_async",
+            argNames: ["publicKey", "message", "signature"],
+        );
+        
+
+@override Uint8List crateRsaCryptoRsaPublicKeyFromPrivateKey({required List<int> privateKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(privateKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 432)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPublicKeyFromPrivateKeyConstMeta,
+            argValues: [privateKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPublicKeyFromPrivateKeyConstMeta => const TaskConstMeta(
+            debugName: "rsa_public_key_from_private_key",
+            argNames: ["privateKey"],
+        );
+        
+
+@override String crateRsaCryptoRsaPublicKeyToPem({required List<int> publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 433)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateRsaCryptoRsaPublicKeyToPemConstMeta,
+            argValues: [publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRsaCryptoRsaPublicKeyToPemConstMeta => const TaskConstMeta(
+            debugName: "rsa_public_key_to_pem",
+            argNames: ["publicKey"],
+        );
+        
+
+@override Uint8List crateKdfScryptDeriveKey({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_8(logN, serializer);
+sse_encode_u_32(r, serializer);
+sse_encode_u_32(p, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 434)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfScryptDeriveKeyConstMeta,
+            argValues: [password, salt, logN, r, p, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfScryptDeriveKeyConstMeta => const TaskConstMeta(
+            debugName: "scrypt_derive_key",
+            argNames: ["password", "salt", "logN", "r", "p", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateKdfScryptDeriveKeyAsync({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_8(logN, serializer);
+sse_encode_u_32(r, serializer);
+sse_encode_u_32(p, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 435, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfScryptDeriveKeyAsyncConstMeta,
+            argValues: [password, salt, logN, r, p, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfScryptDeriveKeyAsyncConstMeta => const TaskConstMeta(
+            debugName: "scrypt_derive_key_async",
+            argNames: ["password", "salt", "logN", "r", "p", "outputLen"],
+        );
+        
+
+@override bool crateKdfScryptVerify({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required List<int> expected })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_8(logN, serializer);
+sse_encode_u_32(r, serializer);
+sse_encode_u_32(p, serializer);
+sse_encode_list_prim_u_8_loose(expected, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 436)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfScryptVerifyConstMeta,
+            argValues: [password, salt, logN, r, p, expected],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfScryptVerifyConstMeta => const TaskConstMeta(
+            debugName: "scrypt_verify",
+            argNames: ["password", "salt", "logN", "r", "p", "expected"],
+        );
+        
+
+@override Future<bool> crateKdfScryptVerifyAsync({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required List<int> expected })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(password, serializer);
+sse_encode_list_prim_u_8_loose(salt, serializer);
+sse_encode_u_8(logN, serializer);
+sse_encode_u_32(r, serializer);
+sse_encode_u_32(p, serializer);
+sse_encode_list_prim_u_8_loose(expected, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 437, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateKdfScryptVerifyAsyncConstMeta,
+            argValues: [password, salt, logN, r, p, expected],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateKdfScryptVerifyAsyncConstMeta => const TaskConstMeta(
+            debugName: "scrypt_verify_async",
+            argNames: ["password", "salt", "logN", "r", "p", "expected"],
+        );
+        
+
+@override Uint8List crateX25519Seal({required List<int> recipientPk , required List<int> plaintext })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(recipientPk, serializer);
+sse_encode_list_prim_u_8_loose(plaintext, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 438)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519SealConstMeta,
+            argValues: [recipientPk, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519SealConstMeta => const TaskConstMeta(
+            debugName: "seal",
+            argNames: ["recipientPk", "plaintext"],
+        );
+        
+
+@override Future<Uint8List> crateX25519SealAsync({required List<int> recipientPk , required List<int> plaintext })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(recipientPk, serializer);
+sse_encode_list_prim_u_8_loose(plaintext, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 439, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519SealAsyncConstMeta,
+            argValues: [recipientPk, plaintext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519SealAsyncConstMeta => const TaskConstMeta(
+            debugName: "seal_async",
+            argNames: ["recipientPk", "plaintext"],
+        );
+        
+
+@override void crateBatchSetBatchParallelism({required BigInt maxThreads })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_usize(maxThreads, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 440)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateBatchSetBatchParallelismConstMeta,
+            argValues: [maxThreads],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBatchSetBatchParallelismConstMeta => const TaskConstMeta(
+            debugName: "set_batch_parallelism",
+            argNames: ["maxThreads"],
+        );
+        
+
+@override U8Array20 crateSha1({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 441)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_20,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha1ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha1ConstMeta =>
-      const TaskConstMeta(debugName: "sha1", argNames: ["data"]);
-
-  @override
-  Future<U8Array20> crateSha1Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha1ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1ConstMeta => const TaskConstMeta(
+            debugName: "sha1",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array20> crateSha1Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 442, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_20,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha1AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha1AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha1_async", argNames: ["data"]);
-
-  @override
-  U8Array28 crateSha224({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha1AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha1AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha1_async",
+            argNames: ["data"],
+        );
+        
+
+@override U8Array28 crateSha224({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 443)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224ConstMeta => const TaskConstMeta(
+            debugName: "sha224",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array28> crateSha224Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 444, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha224AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha224AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha224_async",
+            argNames: ["data"],
+        );
+        
+
+@override U8Array32 crateSha256({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 445)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256ConstMeta => const TaskConstMeta(
+            debugName: "sha256",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array32> crateSha256Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 446, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha256_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array32> crateSha256Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 447)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256BatchConstMeta => const TaskConstMeta(
+            debugName: "sha256_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array32>> crateSha256BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 448, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha256BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha256BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "sha256_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array48 crateSha384({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 449)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384ConstMeta => const TaskConstMeta(
+            debugName: "sha384",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array48> crateSha384Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 450, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha384AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha384AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha384_async",
+            argNames: ["data"],
+        );
+        
+
+@override U8Array28 crateSha3224({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 451)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_28,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha224ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha224ConstMeta =>
-      const TaskConstMeta(debugName: "sha224", argNames: ["data"]);
-
-  @override
-  Future<U8Array28> crateSha224Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3224ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224ConstMeta => const TaskConstMeta(
+            debugName: "sha3_224",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array28> crateSha3224Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 452, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_28,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha224AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha224AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha224_async", argNames: ["data"]);
-
-  @override
-  U8Array32 crateSha256({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3224AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_224_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array28> crateSha3224Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 453)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224BatchConstMeta => const TaskConstMeta(
+            debugName: "sha3_224_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array28>> crateSha3224BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 454, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_28,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3224BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3224BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_224_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array32 crateSha3256({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 455)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256ConstMeta =>
-      const TaskConstMeta(debugName: "sha256", argNames: ["data"]);
-
-  @override
-  Future<U8Array32> crateSha256Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3256ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256ConstMeta => const TaskConstMeta(
+            debugName: "sha3_256",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array32> crateSha3256Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 456, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha256_async", argNames: ["data"]);
-
-  @override
-  List<U8Array32> crateSha256Batch({required List<Uint8List> inputs}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(inputs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3256AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_256_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array32> crateSha3256Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 457)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256BatchConstMeta,
-        argValues: [inputs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256BatchConstMeta =>
-      const TaskConstMeta(debugName: "sha256_batch", argNames: ["inputs"]);
-
-  @override
-  Future<List<U8Array32>> crateSha256BatchAsync({
-    required List<Uint8List> inputs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(inputs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3256BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256BatchConstMeta => const TaskConstMeta(
+            debugName: "sha3_256_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array32>> crateSha3256BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 458, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha256BatchAsyncConstMeta,
-        argValues: [inputs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha256BatchAsyncConstMeta => const TaskConstMeta(
-    debugName: "sha256_batch_async",
-    argNames: ["inputs"],
-  );
-
-  @override
-  U8Array48 crateSha384({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3256BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3256BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_256_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array48 crateSha3384({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 459)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_48,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha384ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha384ConstMeta =>
-      const TaskConstMeta(debugName: "sha384", argNames: ["data"]);
-
-  @override
-  Future<U8Array48> crateSha384Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3384ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384ConstMeta => const TaskConstMeta(
+            debugName: "sha3_384",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array48> crateSha3384Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 460, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_48,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha384AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha384AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha384_async", argNames: ["data"]);
-
-  @override
-  U8Array64 crateSha512({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha3384AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_384_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array48> crateSha3384Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 461)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384BatchConstMeta => const TaskConstMeta(
+            debugName: "sha3_384_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array48>> crateSha3384BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 462, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_48,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3384BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3384BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_384_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array64 crateSha3512({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 463)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512ConstMeta => const TaskConstMeta(
+            debugName: "sha3_512",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array64> crateSha3512Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 464, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_512_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array64> crateSha3512Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 465)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512BatchConstMeta => const TaskConstMeta(
+            debugName: "sha3_512_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array64>> crateSha3512BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 466, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_u_8_array_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSha3512BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha3512BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "sha3_512_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override U8Array64 crateSha512({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 467)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512ConstMeta =>
-      const TaskConstMeta(debugName: "sha512", argNames: ["data"]);
-
-  @override
-  U8Array28 crateSha512224({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512ConstMeta => const TaskConstMeta(
+            debugName: "sha512",
+            argNames: ["data"],
+        );
+        
+
+@override U8Array28 crateSha512224({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 468)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_28,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512224ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512224ConstMeta =>
-      const TaskConstMeta(debugName: "sha512_224", argNames: ["data"]);
-
-  @override
-  Future<U8Array28> crateSha512224Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512224ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224ConstMeta => const TaskConstMeta(
+            debugName: "sha512_224",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array28> crateSha512224Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 469, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_28,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512224AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512224AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha512_224_async", argNames: ["data"]);
-
-  @override
-  U8Array32 crateSha512256({required List<int> data}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512224AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512224AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha512_224_async",
+            argNames: ["data"],
+        );
+        
+
+@override U8Array32 crateSha512256({required List<int> data })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 470)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512256ConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512256ConstMeta =>
-      const TaskConstMeta(debugName: "sha512_256", argNames: ["data"]);
-
-  @override
-  Future<U8Array32> crateSha512256Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 56,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512256ConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256ConstMeta => const TaskConstMeta(
+            debugName: "sha512_256",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array32> crateSha512256Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 471, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512256AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512256AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha512_256_async", argNames: ["data"]);
-
-  @override
-  Future<U8Array64> crateSha512Async({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 57,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512256AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512256AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha512_256_async",
+            argNames: ["data"],
+        );
+        
+
+@override Future<U8Array64> crateSha512Async({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 472, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512AsyncConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512AsyncConstMeta =>
-      const TaskConstMeta(debugName: "sha512_async", argNames: ["data"]);
-
-  @override
-  List<U8Array64> crateSha512Batch({required List<Uint8List> inputs}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(inputs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512AsyncConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512AsyncConstMeta => const TaskConstMeta(
+            debugName: "sha512_async",
+            argNames: ["data"],
+        );
+        
+
+@override List<U8Array64> crateSha512Batch({required List<Uint8List> inputs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 473)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512BatchConstMeta,
-        argValues: [inputs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512BatchConstMeta =>
-      const TaskConstMeta(debugName: "sha512_batch", argNames: ["inputs"]);
-
-  @override
-  Future<List<U8Array64>> crateSha512BatchAsync({
-    required List<Uint8List> inputs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(inputs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 59,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512BatchConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512BatchConstMeta => const TaskConstMeta(
+            debugName: "sha512_batch",
+            argNames: ["inputs"],
+        );
+        
+
+@override Future<List<U8Array64>> crateSha512BatchAsync({required List<Uint8List> inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 474, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_u_8_array_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateSha512BatchAsyncConstMeta,
-        argValues: [inputs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSha512BatchAsyncConstMeta => const TaskConstMeta(
-    debugName: "sha512_batch_async",
-    argNames: ["inputs"],
-  );
-
-  @override
-  String crateToHex({required List<int> bytes}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateSha512BatchAsyncConstMeta,
+            argValues: [inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSha512BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "sha512_batch_async",
+            argNames: ["inputs"],
+        );
+        
+
+@override Uint8List crateShake128({required List<int> data , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 475)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake128ConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128ConstMeta => const TaskConstMeta(
+            debugName: "shake128",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateShake128Async({required List<int> data , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 476, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake128AsyncConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128AsyncConstMeta => const TaskConstMeta(
+            debugName: "shake128_async",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override List<Uint8List> crateShake128Batch({required List<Uint8List> inputs , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 477)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake128BatchConstMeta,
+            argValues: [inputs, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128BatchConstMeta => const TaskConstMeta(
+            debugName: "shake128_batch",
+            argNames: ["inputs", "outputLen"],
+        );
+        
+
+@override Future<List<Uint8List>> crateShake128BatchAsync({required List<Uint8List> inputs , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 478, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake128BatchAsyncConstMeta,
+            argValues: [inputs, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake128BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "shake128_batch_async",
+            argNames: ["inputs", "outputLen"],
+        );
+        
+
+@override Uint8List crateShake256({required List<int> data , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 479)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake256ConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256ConstMeta => const TaskConstMeta(
+            debugName: "shake256",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override Future<Uint8List> crateShake256Async({required List<int> data , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 480, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake256AsyncConstMeta,
+            argValues: [data, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256AsyncConstMeta => const TaskConstMeta(
+            debugName: "shake256_async",
+            argNames: ["data", "outputLen"],
+        );
+        
+
+@override List<Uint8List> crateShake256Batch({required List<Uint8List> inputs , required BigInt outputLen })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+sse_encode_usize(outputLen, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 481)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake256BatchConstMeta,
+            argValues: [inputs, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256BatchConstMeta => const TaskConstMeta(
+            debugName: "shake256_batch",
+            argNames: ["inputs", "outputLen"],
+        );
+        
+
+@override Future<List<Uint8List>> crateShake256BatchAsync({required List<Uint8List> inputs , required BigInt outputLen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(inputs, serializer);
+sse_encode_usize(outputLen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 482, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateShake256BatchAsyncConstMeta,
+            argValues: [inputs, outputLen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShake256BatchAsyncConstMeta => const TaskConstMeta(
+            debugName: "shake256_batch_async",
+            argNames: ["inputs", "outputLen"],
+        );
+        
+
+@override String crateToHex({required List<int> bytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 483)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateToHexConstMeta,
-        argValues: [bytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateToHexConstMeta =>
-      const TaskConstMeta(debugName: "to_hex", argNames: ["bytes"]);
-
-  @override
-  Uint8List? crateVerifyHmacThenDecrypt({
-    required List<int> ciphertext,
-    required List<int> mac,
-    required List<int> encKey,
-    required List<int> macKey,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-          sse_encode_list_prim_u_8_loose(mac, serializer);
-          sse_encode_list_prim_u_8_loose(encKey, serializer);
-          sse_encode_list_prim_u_8_loose(macKey, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateVerifyHmacThenDecryptConstMeta,
-        argValues: [ciphertext, mac, encKey, macKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateVerifyHmacThenDecryptConstMeta => const TaskConstMeta(
-    debugName: "verify_hmac_then_decrypt",
-    argNames: ["ciphertext", "mac", "encKey", "macKey"],
-  );
-
-  @override
-  Future<Uint8List?> crateVerifyHmacThenDecryptAsync({
-    required List<int> ciphertext,
-    required List<int> mac,
-    required List<int> encKey,
-    required List<int> macKey,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(ciphertext, serializer);
-          sse_encode_list_prim_u_8_loose(mac, serializer);
-          sse_encode_list_prim_u_8_loose(encKey, serializer);
-          sse_encode_list_prim_u_8_loose(macKey, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 62,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateVerifyHmacThenDecryptAsyncConstMeta,
-        argValues: [ciphertext, mac, encKey, macKey],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateVerifyHmacThenDecryptAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "verify_hmac_then_decrypt_async",
-        argNames: ["ciphertext", "mac", "encKey", "macKey"],
-      );
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha1Hasher => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha1Hasher => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha256Hasher => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha256Hasher => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha256HmacHasher => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha256HmacHasher => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_Sha512Hasher => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_Sha512Hasher => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher;
-
-  @protected
-  Sha1Hasher
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha256Hasher
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha256HmacHasher
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha512Hasher
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha1Hasher
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha256Hasher
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha256HmacHasher
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha512Hasher
-  dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha1Hasher
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha256Hasher
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha256HmacHasher
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Sha512Hasher
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Sha256HmacHasher
-  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-      raw,
-    );
-  }
-
-  @protected
-  (Uint8List, U8Array32)
-  dco_decode_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as (Uint8List, U8Array32);
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<U8Array32> dco_decode_list_u_8_array_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_u_8_array_32).toList();
-  }
-
-  @protected
-  List<U8Array64> dco_decode_list_u_8_array_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_u_8_array_64).toList();
-  }
-
-  @protected
-  Sha256HmacHasher?
-  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-            raw,
-          );
-  }
-
-  @protected
-  (Uint8List, U8Array32)?
-  dco_decode_opt_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(raw);
-  }
-
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  (Uint8List, U8Array32) dco_decode_record_list_prim_u_8_strict_u_8_array_32(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_list_prim_u_8_strict(arr[0]),
-      dco_decode_u_8_array_32(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  U8Array16 dco_decode_u_8_array_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array16(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  U8Array20 dco_decode_u_8_array_20(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array20(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  U8Array28 dco_decode_u_8_array_28(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array28(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  U8Array32 dco_decode_u_8_array_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array32(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  U8Array48 dco_decode_u_8_array_48(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array48(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  U8Array64 dco_decode_u_8_array_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return U8Array64(dco_decode_list_prim_u_8_strict(raw));
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  Sha1Hasher
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha1HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha256Hasher
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha256HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha256HmacHasher
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha256HmacHasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha512Hasher
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha512HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha1Hasher
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha1HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha256Hasher
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha256HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha256HmacHasher
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha256HmacHasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha512Hasher
-  sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha512HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha1Hasher
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha1HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha256Hasher
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha256HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha256HmacHasher
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha256HmacHasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Sha512Hasher
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return Sha512HasherImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Sha256HmacHasher
-  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-      deserializer,
-    ));
-  }
-
-  @protected
-  (Uint8List, U8Array32)
-  sse_decode_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_record_list_prim_u_8_strict_u_8_array_32(deserializer));
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<U8Array32> sse_decode_list_u_8_array_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <U8Array32>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_u_8_array_32(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<U8Array64> sse_decode_list_u_8_array_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <U8Array64>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_u_8_array_64(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Sha256HmacHasher?
-  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (Uint8List, U8Array32)?
-  sse_decode_opt_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (Uint8List, U8Array32) sse_decode_record_list_prim_u_8_strict_u_8_array_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_field1 = sse_decode_u_8_array_32(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  U8Array16 sse_decode_u_8_array_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array16(inner);
-  }
-
-  @protected
-  U8Array20 sse_decode_u_8_array_20(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array20(inner);
-  }
-
-  @protected
-  U8Array28 sse_decode_u_8_array_28(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array28(inner);
-  }
-
-  @protected
-  U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array32(inner);
-  }
-
-  @protected
-  U8Array48 sse_decode_u_8_array_48(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array48(inner);
-  }
-
-  @protected
-  U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return U8Array64(inner);
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    Sha1Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha1HasherImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    Sha256Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha256HasherImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    Sha256HmacHasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha256HmacHasherImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    Sha512Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha512HasherImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    Sha1Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha1HasherImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    Sha256Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha256HasherImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    Sha256HmacHasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha256HmacHasherImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    Sha512Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha512HasherImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(
-    Sha1Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha1HasherImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(
-    Sha256Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha256HasherImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    Sha256HmacHasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha256HmacHasherImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(
-    Sha512Hasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as Sha512HasherImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void
-  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    Sha256HmacHasher self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-      self,
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-    (Uint8List, U8Array32) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_record_list_prim_u_8_strict_u_8_array_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_u_8_array_32(
-    List<U8Array32> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_u_8_array_32(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_u_8_array_64(
-    List<U8Array64> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_u_8_array_64(item, serializer);
-    }
-  }
-
-  @protected
-  void
-  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-    Sha256HmacHasher? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(
-        self,
-        serializer,
-      );
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-    (Uint8List, U8Array32)? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_record_list_prim_u_8_strict_u_8_array_32(
-        self,
-        serializer,
-      );
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-    Uint8List? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_list_prim_u_8_strict_u_8_array_32(
-    (Uint8List, U8Array32) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.$1, serializer);
-    sse_encode_u_8_array_32(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_u_8_array_16(U8Array16 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8_array_20(U8Array20 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8_array_28(U8Array28 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8_array_48(U8Array48 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
-
-@sealed
-class Sha1HasherImpl extends RustOpaque implements Sha1Hasher {
-  // Not to be used by end users
-  Sha1HasherImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Sha1HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Sha1Hasher,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha1Hasher,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha1HasherPtr,
-  );
-
-  U8Array20 finalize() =>
-      RustLib.instance.api.crateSha1HasherFinalize(that: this);
-
-  void update({required List<int> data}) =>
-      RustLib.instance.api.crateSha1HasherUpdate(that: this, data: data);
-}
-
-@sealed
-class Sha256HasherImpl extends RustOpaque implements Sha256Hasher {
-  // Not to be used by end users
-  Sha256HasherImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Sha256HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Sha256Hasher,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha256Hasher,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha256HasherPtr,
-  );
-
-  U8Array32 finalize() =>
-      RustLib.instance.api.crateSha256HasherFinalize(that: this);
-
-  void update({required List<int> data}) =>
-      RustLib.instance.api.crateSha256HasherUpdate(that: this, data: data);
-}
-
-@sealed
-class Sha256HmacHasherImpl extends RustOpaque implements Sha256HmacHasher {
-  // Not to be used by end users
-  Sha256HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Sha256HmacHasherImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Sha256HmacHasher,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha256HmacHasher,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_Sha256HmacHasherPtr,
-  );
-
-  U8Array32 finalize() =>
-      RustLib.instance.api.crateSha256HmacHasherFinalize(that: this);
-
-  void update({required List<int> data}) =>
-      RustLib.instance.api.crateSha256HmacHasherUpdate(that: this, data: data);
-}
-
-@sealed
-class Sha512HasherImpl extends RustOpaque implements Sha512Hasher {
-  // Not to be used by end users
-  Sha512HasherImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  Sha512HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Sha512Hasher,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha512Hasher,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Sha512HasherPtr,
-  );
-
-  U8Array64 finalize() =>
-      RustLib.instance.api.crateSha512HasherFinalize(that: this);
-
-  void update({required List<int> data}) =>
-      RustLib.instance.api.crateSha512HasherUpdate(that: this, data: data);
-}
+        )
+        ,
+            constMeta: kCrateToHexConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateToHexConstMeta => const TaskConstMeta(
+            debugName: "to_hex",
+            argNames: ["bytes"],
+        );
+        
+
+@override bool crateVerifyHmacMd5({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 484)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacMd5ConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacMd5ConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_md5",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Future<bool> crateVerifyHmacMd5Async({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 485, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacMd5AsyncConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacMd5AsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_md5_async",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override bool crateVerifyHmacSha1({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 486)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha1ConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha1ConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha1",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Future<bool> crateVerifyHmacSha1Async({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 487, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha1AsyncConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha1AsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha1_async",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override bool crateVerifyHmacSha224({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 488)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha224ConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha224ConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha224",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Future<bool> crateVerifyHmacSha224Async({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 489, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha224AsyncConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha224AsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha224_async",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override bool crateVerifyHmacSha256({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 490)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha256ConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha256ConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha256",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Future<bool> crateVerifyHmacSha256Async({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 491, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha256AsyncConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha256AsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha256_async",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override bool crateVerifyHmacSha384({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 492)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha384ConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha384ConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha384",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Future<bool> crateVerifyHmacSha384Async({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 493, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha384AsyncConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha384AsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha384_async",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override bool crateVerifyHmacSha512({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 494)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha512ConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha512ConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha512",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Future<bool> crateVerifyHmacSha512Async({required List<int> key , required List<int> data , required List<int> mac })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 495, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateVerifyHmacSha512AsyncConstMeta,
+            argValues: [key, data, mac],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacSha512AsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_sha512_async",
+            argNames: ["key", "data", "mac"],
+        );
+        
+
+@override Uint8List crateVerifyHmacThenDecrypt({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 496)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateVerifyHmacThenDecryptConstMeta,
+            argValues: [ciphertext, mac, encKey, macKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacThenDecryptConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_then_decrypt",
+            argNames: ["ciphertext", "mac", "encKey", "macKey"],
+        );
+        
+
+@override Future<Uint8List> crateVerifyHmacThenDecryptAsync({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 497, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateVerifyHmacThenDecryptAsyncConstMeta,
+            argValues: [ciphertext, mac, encKey, macKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacThenDecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_then_decrypt_async",
+            argNames: ["ciphertext", "mac", "encKey", "macKey"],
+        );
+        
+
+@override Uint8List crateVerifyHmacThenDecryptWithAad({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 498)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateVerifyHmacThenDecryptWithAadConstMeta,
+            argValues: [ciphertext, mac, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacThenDecryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_then_decrypt_with_aad",
+            argNames: ["ciphertext", "mac", "encKey", "macKey", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateVerifyHmacThenDecryptWithAadAsync({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(mac, serializer);
+sse_encode_list_prim_u_8_loose(encKey, serializer);
+sse_encode_list_prim_u_8_loose(macKey, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 499, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateVerifyHmacThenDecryptWithAadAsyncConstMeta,
+            argValues: [ciphertext, mac, encKey, macKey, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateVerifyHmacThenDecryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "verify_hmac_then_decrypt_with_aad_async",
+            argNames: ["ciphertext", "mac", "encKey", "macKey", "aad"],
+        );
+        
+
+@override U8Array32 crateX25519X25519DiffieHellman({required List<int> secretKey , required List<int> publicKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 500)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519X25519DiffieHellmanConstMeta,
+            argValues: [secretKey, publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519X25519DiffieHellmanConstMeta => const TaskConstMeta(
+            debugName: "x25519_diffie_hellman",
+            argNames: ["secretKey", "publicKey"],
+        );
+        
+
+@override Future<U8Array32> crateX25519X25519DiffieHellmanAsync({required List<int> secretKey , required List<int> publicKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+sse_encode_list_prim_u_8_loose(publicKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 501, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8_array_32,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519X25519DiffieHellmanAsyncConstMeta,
+            argValues: [secretKey, publicKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519X25519DiffieHellmanAsyncConstMeta => const TaskConstMeta(
+            debugName: "x25519_diffie_hellman_async",
+            argNames: ["secretKey", "publicKey"],
+        );
+        
+
+@override X25519KeyPair crateX25519X25519GenerateKeypair()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 502)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_x_25519_key_pair,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateX25519X25519GenerateKeypairConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519X25519GenerateKeypairConstMeta => const TaskConstMeta(
+            debugName: "x25519_generate_keypair",
+            argNames: [],
+        );
+        
+
+@override X25519KeyPair crateX25519X25519KeypairFromSecret({required List<int> secretKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 503)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_x_25519_key_pair,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateX25519X25519KeypairFromSecretConstMeta,
+            argValues: [secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateX25519X25519KeypairFromSecretConstMeta => const TaskConstMeta(
+            debugName: "x25519_keypair_from_secret",
+            argNames: ["secretKey"],
+        );
+        
+
+@override Uint8List crateXchacha20Poly1305Decrypt({required List<int> ciphertext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 504)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305DecryptConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305DecryptConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_decrypt",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateXchacha20Poly1305DecryptAsync({required List<int> ciphertext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 505, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305DecryptAsyncConstMeta,
+            argValues: [ciphertext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305DecryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_decrypt_async",
+            argNames: ["ciphertext", "key"],
+        );
+        
+
+@override Uint8List crateXchacha20Poly1305DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 506)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305DecryptWithAadConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305DecryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_decrypt_with_aad",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateXchacha20Poly1305DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 507, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305DecryptWithAadAsyncConstMeta,
+            argValues: [ciphertext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305DecryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_decrypt_with_aad_async",
+            argNames: ["ciphertext", "key", "aad"],
+        );
+        
+
+@override Uint8List crateXchacha20Poly1305Encrypt({required List<int> plaintext , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 508)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305EncryptConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305EncryptConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_encrypt",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Future<Uint8List> crateXchacha20Poly1305EncryptAsync({required List<int> plaintext , required List<int> key })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 509, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305EncryptAsyncConstMeta,
+            argValues: [plaintext, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305EncryptAsyncConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_encrypt_async",
+            argNames: ["plaintext", "key"],
+        );
+        
+
+@override Uint8List crateXchacha20Poly1305EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 510)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305EncryptWithAadConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305EncryptWithAadConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_encrypt_with_aad",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+@override Future<Uint8List> crateXchacha20Poly1305EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(plaintext, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_prim_u_8_loose(aad, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 511, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_cipher_error,
+        )
+        ,
+            constMeta: kCrateXchacha20Poly1305EncryptWithAadAsyncConstMeta,
+            argValues: [plaintext, key, aad],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateXchacha20Poly1305EncryptWithAadAsyncConstMeta => const TaskConstMeta(
+            debugName: "xchacha20_poly1305_encrypt_with_aad_async",
+            argNames: ["plaintext", "key", "aad"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Aes256GcmStreamDecryptor => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Aes256GcmStreamDecryptor => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Aes256GcmStreamEncryptor => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Aes256GcmStreamEncryptor => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Blake2BHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Blake2BHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Blake2SHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Blake2SHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Blake3Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Blake3Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Keccak256Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Keccak256Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Md5Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Md5Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Md5HmacHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Md5HmacHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha1Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha1Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha1HmacHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha1HmacHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha224Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha224Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha224HmacHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha224HmacHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha256Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha256Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha256HmacHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha256HmacHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha384Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha384Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha384HmacHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha384HmacHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3224Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3224Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3256Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3256Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3384Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3384Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha3512Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha3512Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512HmacHasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512HmacHasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512224Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512224Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Sha512256Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Sha512256Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Shake128Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Shake128Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Shake256Hasher => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Shake256Hasher => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher;
+
+
+
+                  @protected Aes256GcmStreamDecryptor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamDecryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Aes256GcmStreamEncryptor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2BHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2SHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake3Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Keccak256Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5HmacHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1HmacHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224HmacHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256HmacHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384HmacHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3224Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3256Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3384Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3512Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512HmacHasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512224Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512256Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake128Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake256Hasher dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Aes256GcmStreamDecryptor dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamDecryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Aes256GcmStreamEncryptor dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2BHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2SHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake3Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Keccak256Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5HmacHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1HmacHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224HmacHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256HmacHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384HmacHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3224Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3256Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3384Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3512Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512HmacHasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512224Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512256Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake128Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake256Hasher dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Aes256GcmStreamEncryptor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2BHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2SHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake3Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Keccak256Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5HmacHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1HmacHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224HmacHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256HmacHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384HmacHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3224Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3256Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3384Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3512Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512HmacHasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512224Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512256Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake128Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake256Hasher dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Aes256GcmStreamDecryptor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamDecryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Aes256GcmStreamEncryptor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2BHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake2SHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Blake3Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Keccak256Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Md5HmacHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha1HmacHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha224HmacHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha256HmacHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha384HmacHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3224Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3256Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3384Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha3512Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512HmacHasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512224Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Sha512256Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake128Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Shake256Hasher dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CipherError dco_decode_cipher_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CipherError_InvalidKeyLength(expected: dco_decode_usize(raw[1]),actual: dco_decode_usize(raw[2]),);
+case 1: return CipherError_InvalidKey();
+case 2: return CipherError_CiphertextTooShort(minimum: dco_decode_usize(raw[1]),actual: dco_decode_usize(raw[2]),);
+case 3: return CipherError_AuthenticationFailed();
+case 4: return CipherError_EncryptionFailed();
+case 5: return CipherError_StreamExhausted();
+case 6: return CipherError_InvalidFormat();
+case 7: return CipherError_InvalidHex();
+case 8: return CipherError_Io(message: dco_decode_String(raw[1]),);
+case 9: return CipherError_InvalidParameter(message: dco_decode_String(raw[1]),);
+case 10: return CipherError_UnsupportedAlgorithm(algorithm: dco_decode_String(raw[1]),);
+case 11: return CipherError_TokenExpired();
+case 12: return CipherError_TokenNotYetValid();
+                default: throw Exception("unreachable");
+            } }
+
+@protected EcdsaKeyPair dco_decode_ecdsa_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return EcdsaKeyPair(privateKey: dco_decode_list_prim_u_8_strict(arr[0]),
+publicKey: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected Ed25519KeyPair dco_decode_ed_25519_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Ed25519KeyPair(seed: dco_decode_u_8_array_32(arr[0]),
+publicKey: dco_decode_u_8_array_32(arr[1]),); }
+
+@protected HashAlgorithm dco_decode_hash_algorithm(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HashAlgorithm.values[raw as int]; }
+
+@protected HashAlgorithmInfo dco_decode_hash_algorithm_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return HashAlgorithmInfo(algorithm: dco_decode_hash_algorithm(arr[0]),
+name: dco_decode_String(arr[1]),
+digestSize: dco_decode_usize(arr[2]),
+blockSize: dco_decode_usize(arr[3]),
+isXof: dco_decode_bool(arr[4]),
+isBroken: dco_decode_bool(arr[5]),
+supportsHmac: dco_decode_bool(arr[6]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected JweDecrypted dco_decode_jwe_decrypted(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return JweDecrypted(headerJson: dco_decode_String(arr[0]),
+plaintext: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected JwsToken dco_decode_jws_token(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return JwsToken(headerJson: dco_decode_String(arr[0]),
+claimsJson: dco_decode_String(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<HashAlgorithmInfo> dco_decode_list_hash_algorithm_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_hash_algorithm_info).toList(); }
+
+@protected List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<U8Array28> dco_decode_list_u_8_array_28(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_u_8_array_28).toList(); }
+
+@protected List<U8Array32> dco_decode_list_u_8_array_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_u_8_array_32).toList(); }
+
+@protected List<U8Array48> dco_decode_list_u_8_array_48(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_u_8_array_48).toList(); }
+
+@protected List<U8Array64> dco_decode_list_u_8_array_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_u_8_array_64).toList(); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected (Uint8List,U8Array32) dco_decode_record_list_prim_u_8_strict_u_8_array_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_prim_u_8_strict(arr[0]),dco_decode_u_8_array_32(arr[1]),); }
+
+@protected RsaKeyPair dco_decode_rsa_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RsaKeyPair(privateKey: dco_decode_list_prim_u_8_strict(arr[0]),
+publicKey: dco_decode_list_prim_u_8_strict(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected U8Array16 dco_decode_u_8_array_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array16(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected U8Array20 dco_decode_u_8_array_20(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array20(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected U8Array28 dco_decode_u_8_array_28(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array28(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected U8Array32 dco_decode_u_8_array_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array32(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected U8Array48 dco_decode_u_8_array_48(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array48(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected U8Array64 dco_decode_u_8_array_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return U8Array64(dco_decode_list_prim_u_8_strict(raw)); }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected X25519KeyPair dco_decode_x_25519_key_pair(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return X25519KeyPair(secretKey: dco_decode_u_8_array_32(arr[0]),
+publicKey: dco_decode_u_8_array_32(arr[1]),); }
+
+@protected Aes256GcmStreamDecryptor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamDecryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Aes256GcmStreamEncryptor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2BHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2SHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake3Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Keccak256Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5HmacHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1HmacHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224HmacHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256HmacHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384HmacHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3224Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3256Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3384Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3512Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512HmacHasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512224Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512256Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake128Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake256Hasher sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Aes256GcmStreamDecryptor sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamDecryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Aes256GcmStreamEncryptor sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2BHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2SHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake3Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Keccak256Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5HmacHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1HmacHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224HmacHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256HmacHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384HmacHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3224Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3256Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3384Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3512Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512HmacHasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512224Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512256Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake128Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake256Hasher sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Aes256GcmStreamEncryptor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2BHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2SHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake3Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Keccak256Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5HmacHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1HmacHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224HmacHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256HmacHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384HmacHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3224Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3256Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3384Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3512Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512HmacHasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512224Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512256Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake128Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake256Hasher sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Aes256GcmStreamDecryptor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamDecryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Aes256GcmStreamEncryptor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Aes256GcmStreamEncryptorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2BHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2BHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake2SHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake2SHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Blake3Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Blake3HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Keccak256Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Keccak256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Md5HmacHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Md5HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha1HmacHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha1HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha224HmacHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha224HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha256HmacHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha256HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha384HmacHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha384HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3224Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3256Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3384Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3384HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha3512Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha3512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512HmacHasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512HmacHasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512224Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512224HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Sha512256Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Sha512256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake128Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake128HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Shake256Hasher sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return Shake256HasherImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected CipherError sse_decode_cipher_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_expected = sse_decode_usize(deserializer);
+var var_actual = sse_decode_usize(deserializer);
+return CipherError_InvalidKeyLength(expected: var_expected, actual: var_actual);case 1: return CipherError_InvalidKey();case 2: var var_minimum = sse_decode_usize(deserializer);
+var var_actual = sse_decode_usize(deserializer);
+return CipherError_CiphertextTooShort(minimum: var_minimum, actual: var_actual);case 3: return CipherError_AuthenticationFailed();case 4: return CipherError_EncryptionFailed();case 5: return CipherError_StreamExhausted();case 6: return CipherError_InvalidFormat();case 7: return CipherError_InvalidHex();case 8: var var_message = sse_decode_String(deserializer);
+return CipherError_Io(message: var_message);case 9: var var_message = sse_decode_String(deserializer);
+return CipherError_InvalidParameter(message: var_message);case 10: var var_algorithm = sse_decode_String(deserializer);
+return CipherError_UnsupportedAlgorithm(algorithm: var_algorithm);case 11: return CipherError_TokenExpired();case 12: return CipherError_TokenNotYetValid(); default: throw UnimplementedError(''); }
+             }
+
+@protected EcdsaKeyPair sse_decode_ecdsa_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_privateKey = sse_decode_list_prim_u_8_strict(deserializer);
+var var_publicKey = sse_decode_list_prim_u_8_strict(deserializer);
+return EcdsaKeyPair(privateKey: var_privateKey, publicKey: var_publicKey); }
+
+@protected Ed25519KeyPair sse_decode_ed_25519_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_seed = sse_decode_u_8_array_32(deserializer);
+var var_publicKey = sse_decode_u_8_array_32(deserializer);
+return Ed25519KeyPair(seed: var_seed, publicKey: var_publicKey); }
+
+@protected HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HashAlgorithm.values[inner]; }
+
+@protected HashAlgorithmInfo sse_decode_hash_algorithm_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_algorithm = sse_decode_hash_algorithm(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_digestSize = sse_decode_usize(deserializer);
+var var_blockSize = sse_decode_usize(deserializer);
+var var_isXof = sse_decode_bool(deserializer);
+var var_isBroken = sse_decode_bool(deserializer);
+var var_supportsHmac = sse_decode_bool(deserializer);
+return HashAlgorithmInfo(algorithm: var_algorithm, name: var_name, digestSize: var_digestSize, blockSize: var_blockSize, isXof: var_isXof, isBroken: var_isBroken, supportsHmac: var_supportsHmac); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected JweDecrypted sse_decode_jwe_decrypted(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_headerJson = sse_decode_String(deserializer);
+var var_plaintext = sse_decode_list_prim_u_8_strict(deserializer);
+return JweDecrypted(headerJson: var_headerJson, plaintext: var_plaintext); }
+
+@protected JwsToken sse_decode_jws_token(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_headerJson = sse_decode_String(deserializer);
+var var_claimsJson = sse_decode_String(deserializer);
+return JwsToken(headerJson: var_headerJson, claimsJson: var_claimsJson); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HashAlgorithmInfo> sse_decode_list_hash_algorithm_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HashAlgorithmInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_hash_algorithm_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Uint8List> sse_decode_list_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Uint8List>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<U8Array28> sse_decode_list_u_8_array_28(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <U8Array28>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_u_8_array_28(deserializer)); }
+        return ans_;
+         }
+
+@protected List<U8Array32> sse_decode_list_u_8_array_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <U8Array32>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_u_8_array_32(deserializer)); }
+        return ans_;
+         }
+
+@protected List<U8Array48> sse_decode_list_u_8_array_48(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <U8Array48>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_u_8_array_48(deserializer)); }
+        return ans_;
+         }
+
+@protected List<U8Array64> sse_decode_list_u_8_array_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <U8Array64>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_u_8_array_64(deserializer)); }
+        return ans_;
+         }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (Uint8List,U8Array32) sse_decode_record_list_prim_u_8_strict_u_8_array_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+var var_field1 = sse_decode_u_8_array_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected RsaKeyPair sse_decode_rsa_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_privateKey = sse_decode_list_prim_u_8_strict(deserializer);
+var var_publicKey = sse_decode_list_prim_u_8_strict(deserializer);
+return RsaKeyPair(privateKey: var_privateKey, publicKey: var_publicKey); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected U8Array16 sse_decode_u_8_array_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array16(inner); }
+
+@protected U8Array20 sse_decode_u_8_array_20(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array20(inner); }
+
+@protected U8Array28 sse_decode_u_8_array_28(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array28(inner); }
+
+@protected U8Array32 sse_decode_u_8_array_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array32(inner); }
+
+@protected U8Array48 sse_decode_u_8_array_48(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array48(inner); }
+
+@protected U8Array64 sse_decode_u_8_array_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return U8Array64(inner); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected X25519KeyPair sse_decode_x_25519_key_pair(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_secretKey = sse_decode_u_8_array_32(deserializer);
+var var_publicKey = sse_decode_u_8_array_32(deserializer);
+return X25519KeyPair(secretKey: var_secretKey, publicKey: var_publicKey); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(Aes256GcmStreamDecryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamDecryptorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(Aes256GcmStreamEncryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamEncryptorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(Blake2BHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2BHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(Blake2SHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2SHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(Blake3Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake3HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(Keccak256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Keccak256HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(Md5Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(Md5HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HmacHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(Sha1Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(Sha1HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HmacHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(Sha224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(Sha224HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HmacHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(Sha256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(Sha256HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HmacHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(Sha384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(Sha384HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HmacHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(Sha3224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3224HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(Sha3256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3256HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(Sha3384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3384HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(Sha3512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3512HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(Sha512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(Sha512HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HmacHasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(Sha512224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512224HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(Sha512256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512256HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(Shake128Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake128HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(Shake256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake256HasherImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(Aes256GcmStreamDecryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamDecryptorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(Aes256GcmStreamEncryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamEncryptorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(Blake2BHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2BHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(Blake2SHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2SHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(Blake3Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake3HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(Keccak256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Keccak256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(Md5Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(Md5HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(Sha1Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(Sha1HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(Sha224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(Sha224HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(Sha256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(Sha256HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(Sha384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(Sha384HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(Sha3224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3224HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(Sha3256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(Sha3384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3384HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(Sha3512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3512HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(Sha512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(Sha512HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(Sha512224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512224HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(Sha512256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(Shake128Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake128HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(Shake256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(Aes256GcmStreamEncryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamEncryptorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(Blake2BHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2BHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(Blake2SHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2SHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(Blake3Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake3HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(Keccak256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Keccak256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(Md5Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(Md5HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(Sha1Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(Sha1HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(Sha224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(Sha224HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(Sha256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(Sha256HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(Sha384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(Sha384HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(Sha3224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3224HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(Sha3256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(Sha3384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3384HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(Sha3512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3512HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(Sha512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(Sha512HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HmacHasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(Sha512224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512224HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(Sha512256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(Shake128Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake128HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(Shake256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake256HasherImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamDecryptor(Aes256GcmStreamDecryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamDecryptorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAes256GcmStreamEncryptor(Aes256GcmStreamEncryptor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Aes256GcmStreamEncryptorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2bHasher(Blake2BHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2BHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake2sHasher(Blake2SHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake2SHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBlake3Hasher(Blake3Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Blake3HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHasher(Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerKeccak256Hasher(Keccak256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Keccak256HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5Hasher(Md5Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMd5HmacHasher(Md5HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Md5HmacHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1Hasher(Sha1Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha1HmacHasher(Sha1HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha1HmacHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224Hasher(Sha224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha224HmacHasher(Sha224HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha224HmacHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256Hasher(Sha256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha256HmacHasher(Sha256HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha256HmacHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384Hasher(Sha384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha384HmacHasher(Sha384HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha384HmacHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_224Hasher(Sha3224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3224HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_256Hasher(Sha3256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3256HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_384Hasher(Sha3384Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3384HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha3_512Hasher(Sha3512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha3512HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512Hasher(Sha512Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512HmacHasher(Sha512HmacHasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512HmacHasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_224Hasher(Sha512224Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512224HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSha512_256Hasher(Sha512256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Sha512256HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake128Hasher(Shake128Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake128HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerShake256Hasher(Shake256Hasher self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as Shake256HasherImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_cipher_error(CipherError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CipherError_InvalidKeyLength(expected: final expected,actual: final actual): sse_encode_i_32(0, serializer); sse_encode_usize(expected, serializer);
+sse_encode_usize(actual, serializer);
+case CipherError_InvalidKey(): sse_encode_i_32(1, serializer); case CipherError_CiphertextTooShort(minimum: final minimum,actual: final actual): sse_encode_i_32(2, serializer); sse_encode_usize(minimum, serializer);
+sse_encode_usize(actual, serializer);
+case CipherError_AuthenticationFailed(): sse_encode_i_32(3, serializer); case CipherError_EncryptionFailed(): sse_encode_i_32(4, serializer); case CipherError_StreamExhausted(): sse_encode_i_32(5, serializer); case CipherError_InvalidFormat(): sse_encode_i_32(6, serializer); case CipherError_InvalidHex(): sse_encode_i_32(7, serializer); case CipherError_Io(message: final message): sse_encode_i_32(8, serializer); sse_encode_String(message, serializer);
+case CipherError_InvalidParameter(message: final message): sse_encode_i_32(9, serializer); sse_encode_String(message, serializer);
+case CipherError_UnsupportedAlgorithm(algorithm: final algorithm): sse_encode_i_32(10, serializer); sse_encode_String(algorithm, serializer);
+case CipherError_TokenExpired(): sse_encode_i_32(11, serializer); case CipherError_TokenNotYetValid(): sse_encode_i_32(12, serializer);   } }
+
+@protected void sse_encode_ecdsa_key_pair(EcdsaKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.privateKey, serializer);
+sse_encode_list_prim_u_8_strict(self.publicKey, serializer);
+ }
+
+@protected void sse_encode_ed_25519_key_pair(Ed25519KeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8_array_32(self.seed, serializer);
+sse_encode_u_8_array_32(self.publicKey, serializer);
+ }
+
+@protected void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_hash_algorithm_info(HashAlgorithmInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_hash_algorithm(self.algorithm, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_usize(self.digestSize, serializer);
+sse_encode_usize(self.blockSize, serializer);
+sse_encode_bool(self.isXof, serializer);
+sse_encode_bool(self.isBroken, serializer);
+sse_encode_bool(self.supportsHmac, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_jwe_decrypted(JweDecrypted self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.headerJson, serializer);
+sse_encode_list_prim_u_8_strict(self.plaintext, serializer);
+ }
+
+@protected void sse_encode_jws_token(JwsToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.headerJson, serializer);
+sse_encode_String(self.claimsJson, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_hash_algorithm_info(List<HashAlgorithmInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_hash_algorithm_info(item, serializer); } }
+
+@protected void sse_encode_list_list_prim_u_8_strict(List<Uint8List> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_u_8_array_28(List<U8Array28> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_u_8_array_28(item, serializer); } }
+
+@protected void sse_encode_list_u_8_array_32(List<U8Array32> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_u_8_array_32(item, serializer); } }
+
+@protected void sse_encode_list_u_8_array_48(List<U8Array48> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_u_8_array_48(item, serializer); } }
+
+@protected void sse_encode_list_u_8_array_64(List<U8Array64> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_u_8_array_64(item, serializer); } }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_record_list_prim_u_8_strict_u_8_array_32((Uint8List,U8Array32) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.$1, serializer);
+sse_encode_u_8_array_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_rsa_key_pair(RsaKeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.privateKey, serializer);
+sse_encode_list_prim_u_8_strict(self.publicKey, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_u_8_array_16(U8Array16 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_u_8_array_20(U8Array20 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_u_8_array_28(U8Array28 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_u_8_array_32(U8Array32 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_u_8_array_48(U8Array48 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_u_8_array_64(U8Array64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.inner, serializer); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_x_25519_key_pair(X25519KeyPair self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8_array_32(self.secretKey, serializer);
+sse_encode_u_8_array_32(self.publicKey, serializer);
+ }
+                }
+                
+
+            @sealed class Aes256GcmStreamDecryptorImpl extends RustOpaque implements Aes256GcmStreamDecryptor {
+                // Not to be used by end users
+                Aes256GcmStreamDecryptorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Aes256GcmStreamDecryptorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Aes256GcmStreamDecryptor,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Aes256GcmStreamDecryptor,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Aes256GcmStreamDecryptorPtr,
+                );
+
+                /// Open an intermediate chunk. Fails on tampering, reordering, or if the
+/// chunk was actually sealed as the last one.
+ Uint8List  decryptChunk({required List<int> chunk })=>RustLib.instance.api.crateStreamAes256GcmStreamDecryptorDecryptChunk(that: this, chunk: chunk);
+
+
+/// Open the final chunk and consume the decryptor. Fails if the stream
+/// was truncated, since the chunk given here was not sealed as the last.
+ Uint8List  finalize({required List<int> chunk })=>RustLib.instance.api.crateStreamAes256GcmStreamDecryptorFinalize(that: this, chunk: chunk);
+
+
+            }
+            @sealed class Aes256GcmStreamEncryptorImpl extends RustOpaque implements Aes256GcmStreamEncryptor {
+                // Not to be used by end users
+                Aes256GcmStreamEncryptorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Aes256GcmStreamEncryptorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Aes256GcmStreamEncryptor,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Aes256GcmStreamEncryptor,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Aes256GcmStreamEncryptorPtr,
+                );
+
+                /// Seal an intermediate chunk. Fails once the chunk counter is exhausted.
+ Uint8List  encryptChunk({required List<int> chunk })=>RustLib.instance.api.crateStreamAes256GcmStreamEncryptorEncryptChunk(that: this, chunk: chunk);
+
+
+/// Seal the final chunk (may be empty) and consume the encryptor.
+ Uint8List  finalize({required List<int> chunk })=>RustLib.instance.api.crateStreamAes256GcmStreamEncryptorFinalize(that: this, chunk: chunk);
+
+
+/// Stream header that must be stored before the first chunk.
+ Uint8List  header()=>RustLib.instance.api.crateStreamAes256GcmStreamEncryptorHeader(that: this, );
+
+
+            }
+            @sealed class Blake2BHasherImpl extends RustOpaque implements Blake2BHasher {
+                // Not to be used by end users
+                Blake2BHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Blake2BHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Blake2BHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Blake2BHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Blake2BHasherPtr,
+                );
+
+                 Uint8List  finalize()=>RustLib.instance.api.crateBlake2BHasherFinalize(that: this, );
+
+
+ Blake2BHasher  fork()=>RustLib.instance.api.crateBlake2BHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateBlake2BHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateBlake2BHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Blake2SHasherImpl extends RustOpaque implements Blake2SHasher {
+                // Not to be used by end users
+                Blake2SHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Blake2SHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Blake2SHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Blake2SHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Blake2SHasherPtr,
+                );
+
+                 Uint8List  finalize()=>RustLib.instance.api.crateBlake2SHasherFinalize(that: this, );
+
+
+ Blake2SHasher  fork()=>RustLib.instance.api.crateBlake2SHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateBlake2SHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateBlake2SHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Blake3HasherImpl extends RustOpaque implements Blake3Hasher {
+                // Not to be used by end users
+                Blake3HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Blake3HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Blake3Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Blake3Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Blake3HasherPtr,
+                );
+
+                 U8Array32  finalize()=>RustLib.instance.api.crateBlake3HasherFinalize(that: this, );
+
+
+ Blake3Hasher  fork()=>RustLib.instance.api.crateBlake3HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateBlake3HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateBlake3HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class HasherImpl extends RustOpaque implements Hasher {
+                // Not to be used by end users
+                HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_HasherPtr,
+                );
+
+                 HashAlgorithm  algorithm()=>RustLib.instance.api.crateHashAlgorithmHasherAlgorithm(that: this, );
+
+
+/// Opaque versioned midstate for `import_state`; BLAKE2/BLAKE3 fail with
+/// `UnsupportedAlgorithm`.
+ Uint8List  exportState()=>RustLib.instance.api.crateHashAlgorithmHasherExportState(that: this, );
+
+
+/// Digest of everything fed so far; SHAKE yields `digest_size` bytes.
+ Uint8List  finalize()=>RustLib.instance.api.crateHashAlgorithmHasherFinalize(that: this, );
+
+
+ Hasher  fork()=>RustLib.instance.api.crateHashAlgorithmHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateHashAlgorithmHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateHashAlgorithmHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Keccak256HasherImpl extends RustOpaque implements Keccak256Hasher {
+                // Not to be used by end users
+                Keccak256HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Keccak256HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Keccak256Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Keccak256Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Keccak256HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateKeccak256HasherExportState(that: this, );
+
+
+ U8Array32  finalize()=>RustLib.instance.api.crateKeccak256HasherFinalize(that: this, );
+
+
+ Keccak256Hasher  fork()=>RustLib.instance.api.crateKeccak256HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateKeccak256HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateKeccak256HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Md5HasherImpl extends RustOpaque implements Md5Hasher {
+                // Not to be used by end users
+                Md5HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Md5HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Md5Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Md5Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Md5HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateMd5HasherExportState(that: this, );
+
+
+ U8Array16  finalize()=>RustLib.instance.api.crateMd5HasherFinalize(that: this, );
+
+
+ Md5Hasher  fork()=>RustLib.instance.api.crateMd5HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateMd5HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateMd5HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Md5HmacHasherImpl extends RustOpaque implements Md5HmacHasher {
+                // Not to be used by end users
+                Md5HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Md5HmacHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Md5HmacHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Md5HmacHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Md5HmacHasherPtr,
+                );
+
+                /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState()=>RustLib.instance.api.crateMd5HmacHasherExportState(that: this, );
+
+
+ U8Array16  finalize()=>RustLib.instance.api.crateMd5HmacHasherFinalize(that: this, );
+
+
+ Md5HmacHasher  fork()=>RustLib.instance.api.crateMd5HmacHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateMd5HmacHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateMd5HmacHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha1HasherImpl extends RustOpaque implements Sha1Hasher {
+                // Not to be used by end users
+                Sha1HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha1HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha1Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha1Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha1HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha1HasherExportState(that: this, );
+
+
+ U8Array20  finalize()=>RustLib.instance.api.crateSha1HasherFinalize(that: this, );
+
+
+ Sha1Hasher  fork()=>RustLib.instance.api.crateSha1HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha1HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha1HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha1HmacHasherImpl extends RustOpaque implements Sha1HmacHasher {
+                // Not to be used by end users
+                Sha1HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha1HmacHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha1HmacHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha1HmacHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha1HmacHasherPtr,
+                );
+
+                /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState()=>RustLib.instance.api.crateSha1HmacHasherExportState(that: this, );
+
+
+ U8Array20  finalize()=>RustLib.instance.api.crateSha1HmacHasherFinalize(that: this, );
+
+
+ Sha1HmacHasher  fork()=>RustLib.instance.api.crateSha1HmacHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha1HmacHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha1HmacHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha224HasherImpl extends RustOpaque implements Sha224Hasher {
+                // Not to be used by end users
+                Sha224HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha224HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha224Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha224Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha224HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha224HasherExportState(that: this, );
+
+
+ U8Array28  finalize()=>RustLib.instance.api.crateSha224HasherFinalize(that: this, );
+
+
+ Sha224Hasher  fork()=>RustLib.instance.api.crateSha224HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha224HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha224HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha224HmacHasherImpl extends RustOpaque implements Sha224HmacHasher {
+                // Not to be used by end users
+                Sha224HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha224HmacHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha224HmacHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha224HmacHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha224HmacHasherPtr,
+                );
+
+                /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState()=>RustLib.instance.api.crateSha224HmacHasherExportState(that: this, );
+
+
+ U8Array28  finalize()=>RustLib.instance.api.crateSha224HmacHasherFinalize(that: this, );
+
+
+ Sha224HmacHasher  fork()=>RustLib.instance.api.crateSha224HmacHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha224HmacHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha224HmacHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha256HasherImpl extends RustOpaque implements Sha256Hasher {
+                // Not to be used by end users
+                Sha256HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha256HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha256Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha256Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha256HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha256HasherExportState(that: this, );
+
+
+ U8Array32  finalize()=>RustLib.instance.api.crateSha256HasherFinalize(that: this, );
+
+
+ Sha256Hasher  fork()=>RustLib.instance.api.crateSha256HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha256HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha256HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha256HmacHasherImpl extends RustOpaque implements Sha256HmacHasher {
+                // Not to be used by end users
+                Sha256HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha256HmacHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha256HmacHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha256HmacHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha256HmacHasherPtr,
+                );
+
+                /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState()=>RustLib.instance.api.crateSha256HmacHasherExportState(that: this, );
+
+
+ U8Array32  finalize()=>RustLib.instance.api.crateSha256HmacHasherFinalize(that: this, );
+
+
+ Sha256HmacHasher  fork()=>RustLib.instance.api.crateSha256HmacHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha256HmacHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha256HmacHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha3224HasherImpl extends RustOpaque implements Sha3224Hasher {
+                // Not to be used by end users
+                Sha3224HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha3224HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha3224Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3224Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3224HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha3224HasherExportState(that: this, );
+
+
+ U8Array28  finalize()=>RustLib.instance.api.crateSha3224HasherFinalize(that: this, );
+
+
+ Sha3224Hasher  fork()=>RustLib.instance.api.crateSha3224HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha3224HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha3224HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha3256HasherImpl extends RustOpaque implements Sha3256Hasher {
+                // Not to be used by end users
+                Sha3256HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha3256HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha3256Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3256Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3256HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha3256HasherExportState(that: this, );
+
+
+ U8Array32  finalize()=>RustLib.instance.api.crateSha3256HasherFinalize(that: this, );
+
+
+ Sha3256Hasher  fork()=>RustLib.instance.api.crateSha3256HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha3256HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha3256HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha3384HasherImpl extends RustOpaque implements Sha3384Hasher {
+                // Not to be used by end users
+                Sha3384HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha3384HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha3384Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3384Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3384HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha3384HasherExportState(that: this, );
+
+
+ U8Array48  finalize()=>RustLib.instance.api.crateSha3384HasherFinalize(that: this, );
+
+
+ Sha3384Hasher  fork()=>RustLib.instance.api.crateSha3384HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha3384HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha3384HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha3512HasherImpl extends RustOpaque implements Sha3512Hasher {
+                // Not to be used by end users
+                Sha3512HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha3512HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha3512Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3512Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha3512HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha3512HasherExportState(that: this, );
+
+
+ U8Array64  finalize()=>RustLib.instance.api.crateSha3512HasherFinalize(that: this, );
+
+
+ Sha3512Hasher  fork()=>RustLib.instance.api.crateSha3512HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha3512HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha3512HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha384HasherImpl extends RustOpaque implements Sha384Hasher {
+                // Not to be used by end users
+                Sha384HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha384HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha384Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha384Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha384HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha384HasherExportState(that: this, );
+
+
+ U8Array48  finalize()=>RustLib.instance.api.crateSha384HasherFinalize(that: this, );
+
+
+ Sha384Hasher  fork()=>RustLib.instance.api.crateSha384HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha384HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha384HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha384HmacHasherImpl extends RustOpaque implements Sha384HmacHasher {
+                // Not to be used by end users
+                Sha384HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha384HmacHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha384HmacHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha384HmacHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha384HmacHasherPtr,
+                );
+
+                /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState()=>RustLib.instance.api.crateSha384HmacHasherExportState(that: this, );
+
+
+ U8Array48  finalize()=>RustLib.instance.api.crateSha384HmacHasherFinalize(that: this, );
+
+
+ Sha384HmacHasher  fork()=>RustLib.instance.api.crateSha384HmacHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha384HmacHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha384HmacHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha512224HasherImpl extends RustOpaque implements Sha512224Hasher {
+                // Not to be used by end users
+                Sha512224HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha512224HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha512224Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512224Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512224HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha512224HasherExportState(that: this, );
+
+
+ U8Array28  finalize()=>RustLib.instance.api.crateSha512224HasherFinalize(that: this, );
+
+
+ Sha512224Hasher  fork()=>RustLib.instance.api.crateSha512224HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha512224HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha512224HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha512256HasherImpl extends RustOpaque implements Sha512256Hasher {
+                // Not to be used by end users
+                Sha512256HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha512256HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha512256Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512256Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512256HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha512256HasherExportState(that: this, );
+
+
+ U8Array32  finalize()=>RustLib.instance.api.crateSha512256HasherFinalize(that: this, );
+
+
+ Sha512256Hasher  fork()=>RustLib.instance.api.crateSha512256HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha512256HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha512256HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha512HasherImpl extends RustOpaque implements Sha512Hasher {
+                // Not to be used by end users
+                Sha512HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha512HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha512Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateSha512HasherExportState(that: this, );
+
+
+ U8Array64  finalize()=>RustLib.instance.api.crateSha512HasherFinalize(that: this, );
+
+
+ Sha512Hasher  fork()=>RustLib.instance.api.crateSha512HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha512HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha512HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Sha512HmacHasherImpl extends RustOpaque implements Sha512HmacHasher {
+                // Not to be used by end users
+                Sha512HmacHasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Sha512HmacHasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Sha512HmacHasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512HmacHasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Sha512HmacHasherPtr,
+                );
+
+                /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState()=>RustLib.instance.api.crateSha512HmacHasherExportState(that: this, );
+
+
+ U8Array64  finalize()=>RustLib.instance.api.crateSha512HmacHasherFinalize(that: this, );
+
+
+ Sha512HmacHasher  fork()=>RustLib.instance.api.crateSha512HmacHasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateSha512HmacHasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateSha512HmacHasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Shake128HasherImpl extends RustOpaque implements Shake128Hasher {
+                // Not to be used by end users
+                Shake128HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Shake128HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Shake128Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Shake128Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Shake128HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateShake128HasherExportState(that: this, );
+
+
+ Uint8List  finalize({required BigInt outputLen })=>RustLib.instance.api.crateShake128HasherFinalize(that: this, outputLen: outputLen);
+
+
+ Shake128Hasher  fork()=>RustLib.instance.api.crateShake128HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateShake128HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateShake128HasherUpdate(that: this, data: data);
+
+
+            }
+            @sealed class Shake256HasherImpl extends RustOpaque implements Shake256Hasher {
+                // Not to be used by end users
+                Shake256HasherImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                Shake256HasherImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Shake256Hasher,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Shake256Hasher,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_Shake256HasherPtr,
+                );
+
+                 Uint8List  exportState()=>RustLib.instance.api.crateShake256HasherExportState(that: this, );
+
+
+ Uint8List  finalize({required BigInt outputLen })=>RustLib.instance.api.crateShake256HasherFinalize(that: this, outputLen: outputLen);
+
+
+ Shake256Hasher  fork()=>RustLib.instance.api.crateShake256HasherFork(that: this, );
+
+
+ void  reset()=>RustLib.instance.api.crateShake256HasherReset(that: this, );
+
+
+ void  update({required List<int> data })=>RustLib.instance.api.crateShake256HasherUpdate(that: this, data: data);
+
+
+            }
\ No newline at end of file
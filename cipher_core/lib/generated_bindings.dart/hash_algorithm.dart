@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `finalize`, `from_name`, `hash_internal`, `hmac_generic_internal`, `hmac_internal`, `info`, `is_xof`, `name`, `new`, `sizes`, `supports_hmac`, `unsupported_hmac`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HasherState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `hash`
+
+
+            Uint8List  hash({required HashAlgorithm algorithm , required List<int> data }) => RustLib.instance.api.crateHashAlgorithmHash(algorithm: algorithm, data: data);
+
+Future<Uint8List>  hashAsync({required HashAlgorithm algorithm , required List<int> data }) => RustLib.instance.api.crateHashAlgorithmHashAsync(algorithm: algorithm, data: data);
+
+/// HMAC with any algorithm whose `supports_hmac` is true; others fail with
+/// `UnsupportedAlgorithm`.
+Uint8List  hmac({required HashAlgorithm algorithm , required List<int> key , required List<int> data }) => RustLib.instance.api.crateHashAlgorithmHmac(algorithm: algorithm, key: key, data: data);
+
+Future<Uint8List>  hmacAsync({required HashAlgorithm algorithm , required List<int> key , required List<int> data }) => RustLib.instance.api.crateHashAlgorithmHmacAsync(algorithm: algorithm, key: key, data: data);
+
+Hasher  newHasher({required HashAlgorithm algorithm }) => RustLib.instance.api.crateHashAlgorithmNewHasher(algorithm: algorithm);
+
+HashAlgorithmInfo  hashAlgorithmInfo({required HashAlgorithm algorithm }) => RustLib.instance.api.crateHashAlgorithmHashAlgorithmInfo(algorithm: algorithm);
+
+/// Metadata for every supported algorithm, in `get_all_algorithms` order.
+List<HashAlgorithmInfo>  allHashAlgorithms() => RustLib.instance.api.crateHashAlgorithmAllHashAlgorithms();
+
+/// Map a name from `get_all_algorithms` back to its `HashAlgorithm`.
+HashAlgorithm  hashAlgorithmFromName({required String name }) => RustLib.instance.api.crateHashAlgorithmHashAlgorithmFromName(name: name);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Hasher>>
+                abstract class Hasher implements RustOpaqueInterface {
+                     HashAlgorithm  algorithm();
+
+
+/// Opaque versioned midstate for `import_state`; BLAKE2/BLAKE3 fail with
+/// `UnsupportedAlgorithm`.
+ Uint8List  exportState();
+
+
+/// Digest of everything fed so far; SHAKE yields `digest_size` bytes.
+ Uint8List  finalize();
+
+
+ Hasher  fork();
+
+
+/// Resume a hasher from `export_state` output; the algorithm travels in the blob.
+static Hasher  importState({required List<int> state })=>RustLib.instance.api.crateHashAlgorithmHasherImportState(state: state);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+enum HashAlgorithm {
+                    sha1,
+sha224,
+sha256,
+sha384,
+sha512,
+sha512224,
+sha512256,
+md5,
+sha3224,
+sha3256,
+sha3384,
+sha3512,
+keccak256,
+shake128,
+shake256,
+blake2B,
+blake2S,
+blake3,
+                    ;
+                    
+                }
+
+/// Static properties of a `HashAlgorithm`.
+class HashAlgorithmInfo  {
+                final HashAlgorithm algorithm;
+/// Name used by `hash_size` and `get_all_algorithms`
+final String name;
+/// Digest length in bytes (the default length for SHAKE)
+final BigInt digestSize;
+/// Internal block (or sponge rate) size in bytes
+final BigInt blockSize;
+/// Extendable output: any digest length can be requested
+final bool isXof;
+/// Practical collision attacks exist; do not use for new designs
+final bool isBroken;
+/// Usable with `hmac`. BLAKE2/BLAKE3 have their
+/// own keyed modes and SHAKE has no fixed output, so they are excluded
+final bool supportsHmac;
+
+                const HashAlgorithmInfo({required this.algorithm ,required this.name ,required this.digestSize ,required this.blockSize ,required this.isXof ,required this.isBroken ,required this.supportsHmac ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => algorithm.hashCode^name.hashCode^digestSize.hashCode^blockSize.hashCode^isXof.hashCode^isBroken.hashCode^supportsHmac.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HashAlgorithmInfo &&
+                runtimeType == other.runtimeType
+                && algorithm == other.algorithm&& name == other.name&& digestSize == other.digestSize&& blockSize == other.blockSize&& isXof == other.isXof&& isBroken == other.isBroken&& supportsHmac == other.supportsHmac;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `b64_internal`, `concat_kdf_internal`, `ecdh_es_recipient_internal`, `ecdh_es_sender_internal`, `header_bytes_internal`, `jwe_decrypt_internal`, `jwe_encrypt_internal`, `name`, `parse_header_internal`, `parse`, `unb64_internal`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `JweAlgorithm`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`
+
+
+            /// Encrypt `plaintext` as a compact JWE with `enc` = A256GCM. `header_json`
+/// may add fields such as `kid`, `cty`, or `apu`/`apv` for ECDH-ES; `alg`,
+/// `enc` and `epk` are always set here.
+String  jweEncrypt({required String algorithm , required List<int> key , required List<int> plaintext , String? headerJson }) => RustLib.instance.api.crateJweJweEncrypt(algorithm: algorithm, key: key, plaintext: plaintext, headerJson: headerJson);
+
+Future<String>  jweEncryptAsync({required String algorithm , required List<int> key , required List<int> plaintext , String? headerJson }) => RustLib.instance.api.crateJweJweEncryptAsync(algorithm: algorithm, key: key, plaintext: plaintext, headerJson: headerJson);
+
+/// Decrypt a compact JWE produced with `algorithm`. Fails with
+/// `AuthenticationFailed` on a wrong key, `alg` mismatch or tampering.
+JweDecrypted  jweDecrypt({required String token , required String algorithm , required List<int> key }) => RustLib.instance.api.crateJweJweDecrypt(token: token, algorithm: algorithm, key: key);
+
+Future<JweDecrypted>  jweDecryptAsync({required String token , required String algorithm , required List<int> key }) => RustLib.instance.api.crateJweJweDecryptAsync(token: token, algorithm: algorithm, key: key);
+
+/// Decode the protected header without decrypting, e.g. to read `kid`.
+/// The header is authenticated only once `jwe_decrypt` succeeds.
+String  jweDecodeHeader({required String token }) => RustLib.instance.api.crateJweJweDecodeHeader(token: token);
+
+            class JweDecrypted  {
+                /// Decoded protected header, as compact JSON
+final String headerJson;
+final Uint8List plaintext;
+
+                const JweDecrypted({required this.headerJson ,required this.plaintext ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => headerJson.hashCode^plaintext.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JweDecrypted &&
+                runtimeType == other.runtimeType
+                && headerJson == other.headerJson&& plaintext == other.plaintext;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_part_internal`, `hmac_sign_internal`, `hmac_verify_internal`, `into_token`, `json_object_internal`, `jws_sign_internal`, `jws_verify_internal`, `name`, `numeric_claim_internal`, `parse_internal`, `parse`, `sign`, `validate_times_internal`, `verify`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `JwsAlgorithm`, `ParsedToken`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`
+
+
+            /// Sign `claims_json` (a JSON object) as a compact JWS. `header_json` may add
+/// fields such as `kid`; `alg` is always set from `algorithm` and `typ`
+/// defaults to "JWT".
+String  jwsSign({required String algorithm , required List<int> key , required String claimsJson , String? headerJson }) => RustLib.instance.api.crateJwsJwsSign(algorithm: algorithm, key: key, claimsJson: claimsJson, headerJson: headerJson);
+
+Future<String>  jwsSignAsync({required String algorithm , required List<int> key , required String claimsJson , String? headerJson }) => RustLib.instance.api.crateJwsJwsSignAsync(algorithm: algorithm, key: key, claimsJson: claimsJson, headerJson: headerJson);
+
+/// Verify a compact JWS signed with `algorithm`, then check `exp`, `nbf`
+/// and `iat` (when present) allowing `leeway_seconds` of clock skew.
+/// Fails with `AuthenticationFailed` on a bad signature or `alg` mismatch,
+/// and `TokenExpired` / `TokenNotYetValid` on time claims.
+JwsToken  jwsVerify({required String token , required String algorithm , required List<int> key , required BigInt leewaySeconds }) => RustLib.instance.api.crateJwsJwsVerify(token: token, algorithm: algorithm, key: key, leewaySeconds: leewaySeconds);
+
+Future<JwsToken>  jwsVerifyAsync({required String token , required String algorithm , required List<int> key , required BigInt leewaySeconds }) => RustLib.instance.api.crateJwsJwsVerifyAsync(token: token, algorithm: algorithm, key: key, leewaySeconds: leewaySeconds);
+
+/// Decode header and claims WITHOUT checking the signature or time claims,
+/// e.g. to read `kid` and pick the verification key. Never trust the result.
+JwsToken  jwsDecodeUnverified({required String token }) => RustLib.instance.api.crateJwsJwsDecodeUnverified(token: token);
+
+            class JwsToken  {
+                /// Decoded protected header, as compact JSON
+final String headerJson;
+/// Decoded claims (payload), as compact JSON
+final String claimsJson;
+
+                const JwsToken({required this.headerJson ,required this.claimsJson ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => headerJson.hashCode^claimsJson.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JwsToken &&
+                runtimeType == other.runtimeType
+                && headerJson == other.headerJson&& claimsJson == other.claimsJson;
+        
+            }
+            
\ No newline at end of file
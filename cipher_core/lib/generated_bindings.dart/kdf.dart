@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `argon2_verify_password_internal`, `argon2id_context`, `argon2id_derive_key_internal`, `argon2id_hash_password_internal`, `bcrypt_hash_internal`, `bcrypt_verify_internal`, `check_argon2_params`, `check_pbkdf2_params`, `check_scrypt_params`, `hkdf_sha256_expand_internal`, `hkdf_sha256_extract_internal`, `hkdf_sha256_internal`, `hkdf_sha384_expand_internal`, `hkdf_sha384_extract_internal`, `hkdf_sha384_internal`, `hkdf_sha512_expand_internal`, `hkdf_sha512_extract_internal`, `hkdf_sha512_internal`, `pbkdf2_hmac_sha1_internal`, `pbkdf2_hmac_sha256_internal`, `pbkdf2_hmac_sha512_internal`, `scrypt_derive_key_internal`
+
+
+            Uint8List  pbkdf2HmacSha1({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen }) => RustLib.instance.api.crateKdfPbkdf2HmacSha1(password: password, salt: salt, iterations: iterations, outputLen: outputLen);
+
+Future<Uint8List>  pbkdf2HmacSha1Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen }) => RustLib.instance.api.crateKdfPbkdf2HmacSha1Async(password: password, salt: salt, iterations: iterations, outputLen: outputLen);
+
+Uint8List  pbkdf2HmacSha256({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen }) => RustLib.instance.api.crateKdfPbkdf2HmacSha256(password: password, salt: salt, iterations: iterations, outputLen: outputLen);
+
+Future<Uint8List>  pbkdf2HmacSha256Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen }) => RustLib.instance.api.crateKdfPbkdf2HmacSha256Async(password: password, salt: salt, iterations: iterations, outputLen: outputLen);
+
+Uint8List  pbkdf2HmacSha512({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen }) => RustLib.instance.api.crateKdfPbkdf2HmacSha512(password: password, salt: salt, iterations: iterations, outputLen: outputLen);
+
+Future<Uint8List>  pbkdf2HmacSha512Async({required List<int> password , required List<int> salt , required int iterations , required BigInt outputLen }) => RustLib.instance.api.crateKdfPbkdf2HmacSha512Async(password: password, salt: salt, iterations: iterations, outputLen: outputLen);
+
+U8Array32  hkdfSha256Extract({required List<int> salt , required List<int> ikm }) => RustLib.instance.api.crateKdfHkdfSha256Extract(salt: salt, ikm: ikm);
+
+Future<U8Array32>  hkdfSha256ExtractAsync({required List<int> salt , required List<int> ikm }) => RustLib.instance.api.crateKdfHkdfSha256ExtractAsync(salt: salt, ikm: ikm);
+
+Uint8List  hkdfSha256Expand({required List<int> prk , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha256Expand(prk: prk, info: info, outputLen: outputLen);
+
+Future<Uint8List>  hkdfSha256ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha256ExpandAsync(prk: prk, info: info, outputLen: outputLen);
+
+Uint8List  hkdfSha256({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha256(ikm: ikm, salt: salt, info: info, outputLen: outputLen);
+
+Future<Uint8List>  hkdfSha256Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha256Async(ikm: ikm, salt: salt, info: info, outputLen: outputLen);
+
+U8Array48  hkdfSha384Extract({required List<int> salt , required List<int> ikm }) => RustLib.instance.api.crateKdfHkdfSha384Extract(salt: salt, ikm: ikm);
+
+Future<U8Array48>  hkdfSha384ExtractAsync({required List<int> salt , required List<int> ikm }) => RustLib.instance.api.crateKdfHkdfSha384ExtractAsync(salt: salt, ikm: ikm);
+
+Uint8List  hkdfSha384Expand({required List<int> prk , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha384Expand(prk: prk, info: info, outputLen: outputLen);
+
+Future<Uint8List>  hkdfSha384ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha384ExpandAsync(prk: prk, info: info, outputLen: outputLen);
+
+Uint8List  hkdfSha384({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha384(ikm: ikm, salt: salt, info: info, outputLen: outputLen);
+
+Future<Uint8List>  hkdfSha384Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha384Async(ikm: ikm, salt: salt, info: info, outputLen: outputLen);
+
+U8Array64  hkdfSha512Extract({required List<int> salt , required List<int> ikm }) => RustLib.instance.api.crateKdfHkdfSha512Extract(salt: salt, ikm: ikm);
+
+Future<U8Array64>  hkdfSha512ExtractAsync({required List<int> salt , required List<int> ikm }) => RustLib.instance.api.crateKdfHkdfSha512ExtractAsync(salt: salt, ikm: ikm);
+
+Uint8List  hkdfSha512Expand({required List<int> prk , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha512Expand(prk: prk, info: info, outputLen: outputLen);
+
+Future<Uint8List>  hkdfSha512ExpandAsync({required List<int> prk , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha512ExpandAsync(prk: prk, info: info, outputLen: outputLen);
+
+Uint8List  hkdfSha512({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha512(ikm: ikm, salt: salt, info: info, outputLen: outputLen);
+
+Future<Uint8List>  hkdfSha512Async({required List<int> ikm , required List<int> salt , required List<int> info , required BigInt outputLen }) => RustLib.instance.api.crateKdfHkdfSha512Async(ikm: ikm, salt: salt, info: info, outputLen: outputLen);
+
+/// Hash a password with a random salt and return a PHC string
+/// (`$argon2id$v=19$m=...,t=...,p=...$salt$hash`) for storage.
+String  argon2IdHashPassword({required List<int> password , required int memoryKib , required int iterations , required int parallelism }) => RustLib.instance.api.crateKdfArgon2IdHashPassword(password: password, memoryKib: memoryKib, iterations: iterations, parallelism: parallelism);
+
+Future<String>  argon2IdHashPasswordAsync({required List<int> password , required int memoryKib , required int iterations , required int parallelism }) => RustLib.instance.api.crateKdfArgon2IdHashPasswordAsync(password: password, memoryKib: memoryKib, iterations: iterations, parallelism: parallelism);
+
+/// Check a password against a stored PHC string. Returns `Ok(false)` on a
+/// mismatch and an error only if the PHC string itself is malformed or asks
+/// for more than `ARGON2_MAX_MEMORY_KIB`.
+bool  argon2VerifyPassword({required List<int> password , required String phc }) => RustLib.instance.api.crateKdfArgon2VerifyPassword(password: password, phc: phc);
+
+Future<bool>  argon2VerifyPasswordAsync({required List<int> password , required String phc }) => RustLib.instance.api.crateKdfArgon2VerifyPasswordAsync(password: password, phc: phc);
+
+/// Derive raw key bytes with Argon2id (salt must be at least 8 bytes).
+Uint8List  argon2IdDeriveKey({required List<int> password , required List<int> salt , required int memoryKib , required int iterations , required int parallelism , required BigInt outputLen }) => RustLib.instance.api.crateKdfArgon2IdDeriveKey(password: password, salt: salt, memoryKib: memoryKib, iterations: iterations, parallelism: parallelism, outputLen: outputLen);
+
+Future<Uint8List>  argon2IdDeriveKeyAsync({required List<int> password , required List<int> salt , required int memoryKib , required int iterations , required int parallelism , required BigInt outputLen }) => RustLib.instance.api.crateKdfArgon2IdDeriveKeyAsync(password: password, salt: salt, memoryKib: memoryKib, iterations: iterations, parallelism: parallelism, outputLen: outputLen);
+
+Uint8List  scryptDeriveKey({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required BigInt outputLen }) => RustLib.instance.api.crateKdfScryptDeriveKey(password: password, salt: salt, logN: logN, r: r, p: p, outputLen: outputLen);
+
+Future<Uint8List>  scryptDeriveKeyAsync({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required BigInt outputLen }) => RustLib.instance.api.crateKdfScryptDeriveKeyAsync(password: password, salt: salt, logN: logN, r: r, p: p, outputLen: outputLen);
+
+/// Re-derive with the stored parameters and compare against `expected`
+/// in constant time. The output length is taken from `expected`.
+bool  scryptVerify({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required List<int> expected }) => RustLib.instance.api.crateKdfScryptVerify(password: password, salt: salt, logN: logN, r: r, p: p, expected: expected);
+
+Future<bool>  scryptVerifyAsync({required List<int> password , required List<int> salt , required int logN , required int r , required int p , required List<int> expected }) => RustLib.instance.api.crateKdfScryptVerifyAsync(password: password, salt: salt, logN: logN, r: r, p: p, expected: expected);
+
+/// Hash a password with bcrypt at the given cost (4..=31, 12 is typical).
+String  bcryptHash({required List<int> password , required int cost }) => RustLib.instance.api.crateKdfBcryptHash(password: password, cost: cost);
+
+Future<String>  bcryptHashAsync({required List<int> password , required int cost }) => RustLib.instance.api.crateKdfBcryptHashAsync(password: password, cost: cost);
+
+/// Check a password against a stored bcrypt hash. Returns `Ok(false)` on a
+/// mismatch and an error only if the hash string is malformed.
+bool  bcryptVerify({required List<int> password , required String hash }) => RustLib.instance.api.crateKdfBcryptVerify(password: password, hash: hash);
+
+Future<bool>  bcryptVerifyAsync({required List<int> password , required String hash }) => RustLib.instance.api.crateKdfBcryptVerifyAsync(password: password, hash: hash);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,31 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `aes_kw_unwrap_internal`, `aes_kw_wrap_internal`, `aes_kwp_unwrap_internal`, `aes_kwp_wrap_internal`, `unwrap_error`
+
+
+            Uint8List  aesKeyWrap({required List<int> key , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyWrap(key: key, kek: kek);
+
+Future<Uint8List>  aesKeyWrapAsync({required List<int> key , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyWrapAsync(key: key, kek: kek);
+
+Uint8List  aesKeyUnwrap({required List<int> wrappedKey , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyUnwrap(wrappedKey: wrappedKey, kek: kek);
+
+Future<Uint8List>  aesKeyUnwrapAsync({required List<int> wrappedKey , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyUnwrapAsync(wrappedKey: wrappedKey, kek: kek);
+
+Uint8List  aesKeyWrapWithPadding({required List<int> key , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyWrapWithPadding(key: key, kek: kek);
+
+Future<Uint8List>  aesKeyWrapWithPaddingAsync({required List<int> key , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyWrapWithPaddingAsync(key: key, kek: kek);
+
+Uint8List  aesKeyUnwrapWithPadding({required List<int> wrappedKey , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyUnwrapWithPadding(wrappedKey: wrappedKey, kek: kek);
+
+Future<Uint8List>  aesKeyUnwrapWithPaddingAsync({required List<int> wrappedKey , required List<int> kek }) => RustLib.instance.api.crateKeyWrapAesKeyUnwrapWithPaddingAsync(wrappedKey: wrappedKey, kek: kek);
+
+            
+            
\ No newline at end of file
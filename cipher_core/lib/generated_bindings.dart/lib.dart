@@ -6,338 +6,1128 @@
 import 'frb_generated.dart';
 import 'package:collection/collection.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'lib.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `hmac_md5_internal`, `hmac_sha1_internal`, `hmac_sha224_internal`, `hmac_sha256_internal`, `hmac_sha384_internal`, `hmac_sha512_internal`, `md5_internal`, `sha1_internal`, `sha224_internal`, `sha256_internal`, `sha384_internal`, `sha512_224_internal`, `sha512_256_internal`, `sha512_internal`
+            // These functions are ignored because they are not marked as `pub`: `aead_decrypt_internal`, `aead_encrypt_internal`, `blake2b_internal`, `blake2b_keyed_internal`, `blake2b_params_internal`, `blake2s_internal`, `blake2s_keyed_internal`, `blake2s_params_internal`, `blake3_derive_key_internal`, `blake3_internal`, `blake3_key_internal`, `blake3_keyed_internal`, `blake3_update_internal`, `check_key_len`, `check_shake_output_len`, `ct_eq_internal`, `etm_mac_internal`, `hmac_md5_internal`, `hmac_sha1_internal`, `hmac_sha224_internal`, `hmac_sha256_internal`, `hmac_sha384_internal`, `hmac_sha512_internal`, `keccak256_internal`, `md5_internal`, `run_blocking`, `sha1_internal`, `sha224_internal`, `sha256_internal`, `sha384_internal`, `sha3_224_internal`, `sha3_256_internal`, `sha3_384_internal`, `sha3_512_internal`, `sha512_224_internal`, `sha512_256_internal`, `sha512_internal`, `shake128_internal`, `shake256_internal`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `from`
 
-U8Array32 sha256({required List<int> data}) =>
-    RustLib.instance.api.crateSha256(data: data);
 
-Future<U8Array32> sha256Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha256Async(data: data);
+            U8Array32  sha256({required List<int> data }) => RustLib.instance.api.crateSha256(data: data);
 
-U8Array64 sha512({required List<int> data}) =>
-    RustLib.instance.api.crateSha512(data: data);
+Future<U8Array32>  sha256Async({required List<int> data }) => RustLib.instance.api.crateSha256Async(data: data);
 
-Future<U8Array64> sha512Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha512Async(data: data);
+U8Array64  sha512({required List<int> data }) => RustLib.instance.api.crateSha512(data: data);
 
-U8Array20 sha1({required List<int> data}) =>
-    RustLib.instance.api.crateSha1(data: data);
+Future<U8Array64>  sha512Async({required List<int> data }) => RustLib.instance.api.crateSha512Async(data: data);
 
-Future<U8Array20> sha1Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha1Async(data: data);
+U8Array20  sha1({required List<int> data }) => RustLib.instance.api.crateSha1(data: data);
 
-U8Array48 sha384({required List<int> data}) =>
-    RustLib.instance.api.crateSha384(data: data);
+Future<U8Array20>  sha1Async({required List<int> data }) => RustLib.instance.api.crateSha1Async(data: data);
 
-Future<U8Array48> sha384Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha384Async(data: data);
+U8Array48  sha384({required List<int> data }) => RustLib.instance.api.crateSha384(data: data);
 
-U8Array28 sha224({required List<int> data}) =>
-    RustLib.instance.api.crateSha224(data: data);
+Future<U8Array48>  sha384Async({required List<int> data }) => RustLib.instance.api.crateSha384Async(data: data);
 
-Future<U8Array28> sha224Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha224Async(data: data);
+U8Array28  sha224({required List<int> data }) => RustLib.instance.api.crateSha224(data: data);
 
-U8Array16 md5({required List<int> data}) =>
-    RustLib.instance.api.crateMd5(data: data);
+Future<U8Array28>  sha224Async({required List<int> data }) => RustLib.instance.api.crateSha224Async(data: data);
 
-Future<U8Array16> md5Async({required List<int> data}) =>
-    RustLib.instance.api.crateMd5Async(data: data);
+U8Array16  md5({required List<int> data }) => RustLib.instance.api.crateMd5(data: data);
 
-U8Array32 sha512256({required List<int> data}) =>
-    RustLib.instance.api.crateSha512256(data: data);
+Future<U8Array16>  md5Async({required List<int> data }) => RustLib.instance.api.crateMd5Async(data: data);
 
-Future<U8Array32> sha512256Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha512256Async(data: data);
+U8Array32  sha512256({required List<int> data }) => RustLib.instance.api.crateSha512256(data: data);
 
-U8Array28 sha512224({required List<int> data}) =>
-    RustLib.instance.api.crateSha512224(data: data);
+Future<U8Array32>  sha512256Async({required List<int> data }) => RustLib.instance.api.crateSha512256Async(data: data);
 
-Future<U8Array28> sha512224Async({required List<int> data}) =>
-    RustLib.instance.api.crateSha512224Async(data: data);
+U8Array28  sha512224({required List<int> data }) => RustLib.instance.api.crateSha512224(data: data);
 
-U8Array32 hmacSha256({required List<int> key, required List<int> data}) =>
-    RustLib.instance.api.crateHmacSha256(key: key, data: data);
+Future<U8Array28>  sha512224Async({required List<int> data }) => RustLib.instance.api.crateSha512224Async(data: data);
 
-Future<U8Array32> hmacSha256Async({
-  required List<int> key,
-  required List<int> data,
-}) => RustLib.instance.api.crateHmacSha256Async(key: key, data: data);
+U8Array28  sha3224({required List<int> data }) => RustLib.instance.api.crateSha3224(data: data);
 
-U8Array64 hmacSha512({required List<int> key, required List<int> data}) =>
-    RustLib.instance.api.crateHmacSha512(key: key, data: data);
+Future<U8Array28>  sha3224Async({required List<int> data }) => RustLib.instance.api.crateSha3224Async(data: data);
 
-Future<U8Array64> hmacSha512Async({
-  required List<int> key,
-  required List<int> data,
-}) => RustLib.instance.api.crateHmacSha512Async(key: key, data: data);
+U8Array32  sha3256({required List<int> data }) => RustLib.instance.api.crateSha3256(data: data);
 
-U8Array20 hmacSha1({required List<int> key, required List<int> data}) =>
-    RustLib.instance.api.crateHmacSha1(key: key, data: data);
+Future<U8Array32>  sha3256Async({required List<int> data }) => RustLib.instance.api.crateSha3256Async(data: data);
 
-Future<U8Array20> hmacSha1Async({
-  required List<int> key,
-  required List<int> data,
-}) => RustLib.instance.api.crateHmacSha1Async(key: key, data: data);
+U8Array48  sha3384({required List<int> data }) => RustLib.instance.api.crateSha3384(data: data);
 
-U8Array48 hmacSha384({required List<int> key, required List<int> data}) =>
-    RustLib.instance.api.crateHmacSha384(key: key, data: data);
+Future<U8Array48>  sha3384Async({required List<int> data }) => RustLib.instance.api.crateSha3384Async(data: data);
 
-Future<U8Array48> hmacSha384Async({
-  required List<int> key,
-  required List<int> data,
-}) => RustLib.instance.api.crateHmacSha384Async(key: key, data: data);
+U8Array64  sha3512({required List<int> data }) => RustLib.instance.api.crateSha3512(data: data);
 
-U8Array28 hmacSha224({required List<int> key, required List<int> data}) =>
-    RustLib.instance.api.crateHmacSha224(key: key, data: data);
-
-Future<U8Array28> hmacSha224Async({
-  required List<int> key,
-  required List<int> data,
-}) => RustLib.instance.api.crateHmacSha224Async(key: key, data: data);
-
-U8Array16 hmacMd5({required List<int> key, required List<int> data}) =>
-    RustLib.instance.api.crateHmacMd5(key: key, data: data);
-
-Future<U8Array16> hmacMd5Async({
-  required List<int> key,
-  required List<int> data,
-}) => RustLib.instance.api.crateHmacMd5Async(key: key, data: data);
-
-Uint8List? aes256Encrypt({
-  required List<int> plaintext,
-  required List<int> key,
-}) => RustLib.instance.api.crateAes256Encrypt(plaintext: plaintext, key: key);
-
-Future<Uint8List?> aes256EncryptAsync({
-  required List<int> plaintext,
-  required List<int> key,
-}) => RustLib.instance.api.crateAes256EncryptAsync(
-  plaintext: plaintext,
-  key: key,
-);
-
-Uint8List? aes256Decrypt({
-  required List<int> ciphertext,
-  required List<int> key,
-}) => RustLib.instance.api.crateAes256Decrypt(ciphertext: ciphertext, key: key);
-
-Future<Uint8List?> aes256DecryptAsync({
-  required List<int> ciphertext,
-  required List<int> key,
-}) => RustLib.instance.api.crateAes256DecryptAsync(
-  ciphertext: ciphertext,
-  key: key,
-);
-
-List<U8Array32> sha256Batch({required List<Uint8List> inputs}) =>
-    RustLib.instance.api.crateSha256Batch(inputs: inputs);
-
-Future<List<U8Array32>> sha256BatchAsync({required List<Uint8List> inputs}) =>
-    RustLib.instance.api.crateSha256BatchAsync(inputs: inputs);
-
-List<U8Array64> sha512Batch({required List<Uint8List> inputs}) =>
-    RustLib.instance.api.crateSha512Batch(inputs: inputs);
-
-Future<List<U8Array64>> sha512BatchAsync({required List<Uint8List> inputs}) =>
-    RustLib.instance.api.crateSha512BatchAsync(inputs: inputs);
-
-List<U8Array32> hmacSha256Batch({
-  required List<int> key,
-  required List<Uint8List> messages,
-}) => RustLib.instance.api.crateHmacSha256Batch(key: key, messages: messages);
-
-Future<List<U8Array32>> hmacSha256BatchAsync({
-  required List<int> key,
-  required List<Uint8List> messages,
-}) => RustLib.instance.api.crateHmacSha256BatchAsync(
-  key: key,
-  messages: messages,
-);
-
-List<U8Array64> hmacSha512Batch({
-  required List<int> key,
-  required List<Uint8List> messages,
-}) => RustLib.instance.api.crateHmacSha512Batch(key: key, messages: messages);
-
-Future<List<U8Array64>> hmacSha512BatchAsync({
-  required List<int> key,
-  required List<Uint8List> messages,
-}) => RustLib.instance.api.crateHmacSha512BatchAsync(
-  key: key,
-  messages: messages,
-);
-
-Uint8List? hashThenEncrypt({required List<int> data, required List<int> key}) =>
-    RustLib.instance.api.crateHashThenEncrypt(data: data, key: key);
-
-Future<Uint8List?> hashThenEncryptAsync({
-  required List<int> data,
-  required List<int> key,
-}) => RustLib.instance.api.crateHashThenEncryptAsync(data: data, key: key);
-
-(Uint8List, U8Array32)? encryptThenHmac({
-  required List<int> plaintext,
-  required List<int> encKey,
-  required List<int> macKey,
-}) => RustLib.instance.api.crateEncryptThenHmac(
-  plaintext: plaintext,
-  encKey: encKey,
-  macKey: macKey,
-);
-
-Future<(Uint8List, U8Array32)?> encryptThenHmacAsync({
-  required List<int> plaintext,
-  required List<int> encKey,
-  required List<int> macKey,
-}) => RustLib.instance.api.crateEncryptThenHmacAsync(
-  plaintext: plaintext,
-  encKey: encKey,
-  macKey: macKey,
-);
-
-Uint8List? verifyHmacThenDecrypt({
-  required List<int> ciphertext,
-  required List<int> mac,
-  required List<int> encKey,
-  required List<int> macKey,
-}) => RustLib.instance.api.crateVerifyHmacThenDecrypt(
-  ciphertext: ciphertext,
-  mac: mac,
-  encKey: encKey,
-  macKey: macKey,
-);
-
-Future<Uint8List?> verifyHmacThenDecryptAsync({
-  required List<int> ciphertext,
-  required List<int> mac,
-  required List<int> encKey,
-  required List<int> macKey,
-}) => RustLib.instance.api.crateVerifyHmacThenDecryptAsync(
-  ciphertext: ciphertext,
-  mac: mac,
-  encKey: encKey,
-  macKey: macKey,
-);
-
-String toHex({required List<int> bytes}) =>
-    RustLib.instance.api.crateToHex(bytes: bytes);
-
-Uint8List? fromHex({required String hexString}) =>
-    RustLib.instance.api.crateFromHex(hexString: hexString);
+Future<U8Array64>  sha3512Async({required List<int> data }) => RustLib.instance.api.crateSha3512Async(data: data);
 
-BigInt hashSize({required String algorithm}) =>
-    RustLib.instance.api.crateHashSize(algorithm: algorithm);
+U8Array32  keccak256({required List<int> data }) => RustLib.instance.api.crateKeccak256(data: data);
 
-List<String> getAllAlgorithms() => RustLib.instance.api.crateGetAllAlgorithms();
+Future<U8Array32>  keccak256Async({required List<int> data }) => RustLib.instance.api.crateKeccak256Async(data: data);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha1Hasher>>
-abstract class Sha1Hasher implements RustOpaqueInterface {
-  U8Array20 finalize();
+Uint8List  shake128({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateShake128(data: data, outputLen: outputLen);
 
-  factory Sha1Hasher() => RustLib.instance.api.crateSha1HasherNew();
+Future<Uint8List>  shake128Async({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateShake128Async(data: data, outputLen: outputLen);
 
-  void update({required List<int> data});
-}
+Uint8List  shake256({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateShake256(data: data, outputLen: outputLen);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha256Hasher>>
-abstract class Sha256Hasher implements RustOpaqueInterface {
-  U8Array32 finalize();
+Future<Uint8List>  shake256Async({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateShake256Async(data: data, outputLen: outputLen);
 
-  factory Sha256Hasher() => RustLib.instance.api.crateSha256HasherNew();
+U8Array64  blake2B({required List<int> data }) => RustLib.instance.api.crateBlake2B(data: data);
 
-  void update({required List<int> data});
-}
+Future<U8Array64>  blake2BAsync({required List<int> data }) => RustLib.instance.api.crateBlake2BAsync(data: data);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha256HmacHasher>>
-abstract class Sha256HmacHasher implements RustOpaqueInterface {
-  U8Array32 finalize();
+Uint8List  blake2BWithLen({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2BWithLen(data: data, outputLen: outputLen);
 
-  factory Sha256HmacHasher({required List<int> key}) =>
-      RustLib.instance.api.crateSha256HmacHasherNew(key: key) ??
-          (throw StateError('Failed to create Sha256HmacHasher'));
+Future<Uint8List>  blake2BWithLenAsync({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2BWithLenAsync(data: data, outputLen: outputLen);
 
-  void update({required List<int> data});
-}
+Uint8List  blake2BKeyed({required List<int> key , required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2BKeyed(key: key, data: data, outputLen: outputLen);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha512Hasher>>
-abstract class Sha512Hasher implements RustOpaqueInterface {
-  U8Array64 finalize();
+Future<Uint8List>  blake2BKeyedAsync({required List<int> key , required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2BKeyedAsync(key: key, data: data, outputLen: outputLen);
 
-  factory Sha512Hasher() => RustLib.instance.api.crateSha512HasherNew();
+U8Array32  blake2S({required List<int> data }) => RustLib.instance.api.crateBlake2S(data: data);
 
-  void update({required List<int> data});
-}
+Future<U8Array32>  blake2SAsync({required List<int> data }) => RustLib.instance.api.crateBlake2SAsync(data: data);
 
-class U8Array16 extends NonGrowableListView<int> {
-  static const arraySize = 16;
+Uint8List  blake2SWithLen({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2SWithLen(data: data, outputLen: outputLen);
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+Future<Uint8List>  blake2SWithLenAsync({required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2SWithLenAsync(data: data, outputLen: outputLen);
 
-  U8Array16(this._inner) : assert(_inner.length == arraySize), super(_inner);
+Uint8List  blake2SKeyed({required List<int> key , required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2SKeyed(key: key, data: data, outputLen: outputLen);
 
-  U8Array16.init() : this(Uint8List(arraySize));
-}
+Future<Uint8List>  blake2SKeyedAsync({required List<int> key , required List<int> data , required BigInt outputLen }) => RustLib.instance.api.crateBlake2SKeyedAsync(key: key, data: data, outputLen: outputLen);
 
-class U8Array20 extends NonGrowableListView<int> {
-  static const arraySize = 20;
+U8Array32  blake3({required List<int> data }) => RustLib.instance.api.crateBlake3(data: data);
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+Future<U8Array32>  blake3Async({required List<int> data }) => RustLib.instance.api.crateBlake3Async(data: data);
 
-  U8Array20(this._inner) : assert(_inner.length == arraySize), super(_inner);
+U8Array32  blake3Keyed({required List<int> key , required List<int> data }) => RustLib.instance.api.crateBlake3Keyed(key: key, data: data);
 
-  U8Array20.init() : this(Uint8List(arraySize));
-}
+Future<U8Array32>  blake3KeyedAsync({required List<int> key , required List<int> data }) => RustLib.instance.api.crateBlake3KeyedAsync(key: key, data: data);
 
-class U8Array28 extends NonGrowableListView<int> {
-  static const arraySize = 28;
+U8Array32  blake3DeriveKey({required String context , required List<int> keyMaterial }) => RustLib.instance.api.crateBlake3DeriveKey(context: context, keyMaterial: keyMaterial);
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+Future<U8Array32>  blake3DeriveKeyAsync({required String context , required List<int> keyMaterial }) => RustLib.instance.api.crateBlake3DeriveKeyAsync(context: context, keyMaterial: keyMaterial);
 
-  U8Array28(this._inner) : assert(_inner.length == arraySize), super(_inner);
+U8Array32  hmacSha256({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha256(key: key, data: data);
 
-  U8Array28.init() : this(Uint8List(arraySize));
-}
+Future<U8Array32>  hmacSha256Async({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha256Async(key: key, data: data);
 
-class U8Array32 extends NonGrowableListView<int> {
-  static const arraySize = 32;
+U8Array64  hmacSha512({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha512(key: key, data: data);
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+Future<U8Array64>  hmacSha512Async({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha512Async(key: key, data: data);
 
-  U8Array32(this._inner) : assert(_inner.length == arraySize), super(_inner);
+U8Array20  hmacSha1({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha1(key: key, data: data);
 
-  U8Array32.init() : this(Uint8List(arraySize));
-}
+Future<U8Array20>  hmacSha1Async({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha1Async(key: key, data: data);
 
-class U8Array48 extends NonGrowableListView<int> {
-  static const arraySize = 48;
+U8Array48  hmacSha384({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha384(key: key, data: data);
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+Future<U8Array48>  hmacSha384Async({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha384Async(key: key, data: data);
 
-  U8Array48(this._inner) : assert(_inner.length == arraySize), super(_inner);
+U8Array28  hmacSha224({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha224(key: key, data: data);
 
-  U8Array48.init() : this(Uint8List(arraySize));
-}
+Future<U8Array28>  hmacSha224Async({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacSha224Async(key: key, data: data);
 
-class U8Array64 extends NonGrowableListView<int> {
-  static const arraySize = 64;
+U8Array16  hmacMd5({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacMd5(key: key, data: data);
 
-  @internal
-  Uint8List get inner => _inner;
-  final Uint8List _inner;
+Future<U8Array16>  hmacMd5Async({required List<int> key , required List<int> data }) => RustLib.instance.api.crateHmacMd5Async(key: key, data: data);
 
-  U8Array64(this._inner) : assert(_inner.length == arraySize), super(_inner);
+bool  constantTimeEq({required List<int> a , required List<int> b }) => RustLib.instance.api.crateConstantTimeEq(a: a, b: b);
 
-  U8Array64.init() : this(Uint8List(arraySize));
-}
+bool  verifyHmacSha256({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha256(key: key, data: data, mac: mac);
+
+Future<bool>  verifyHmacSha256Async({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha256Async(key: key, data: data, mac: mac);
+
+bool  verifyHmacSha512({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha512(key: key, data: data, mac: mac);
+
+Future<bool>  verifyHmacSha512Async({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha512Async(key: key, data: data, mac: mac);
+
+bool  verifyHmacSha1({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha1(key: key, data: data, mac: mac);
+
+Future<bool>  verifyHmacSha1Async({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha1Async(key: key, data: data, mac: mac);
+
+bool  verifyHmacSha384({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha384(key: key, data: data, mac: mac);
+
+Future<bool>  verifyHmacSha384Async({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha384Async(key: key, data: data, mac: mac);
+
+bool  verifyHmacSha224({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha224(key: key, data: data, mac: mac);
+
+Future<bool>  verifyHmacSha224Async({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacSha224Async(key: key, data: data, mac: mac);
+
+bool  verifyHmacMd5({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacMd5(key: key, data: data, mac: mac);
+
+Future<bool>  verifyHmacMd5Async({required List<int> key , required List<int> data , required List<int> mac }) => RustLib.instance.api.crateVerifyHmacMd5Async(key: key, data: data, mac: mac);
+
+Uint8List  aes256Encrypt({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateAes256Encrypt(plaintext: plaintext, key: key);
+
+Future<Uint8List>  aes256EncryptAsync({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateAes256EncryptAsync(plaintext: plaintext, key: key);
+
+Uint8List  aes256Decrypt({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateAes256Decrypt(ciphertext: ciphertext, key: key);
+
+Future<Uint8List>  aes256DecryptAsync({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateAes256DecryptAsync(ciphertext: ciphertext, key: key);
+
+Uint8List  aes256EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateAes256EncryptWithAad(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  aes256EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateAes256EncryptWithAadAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  aes256DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateAes256DecryptWithAad(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  aes256DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateAes256DecryptWithAadAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+Uint8List  chacha20Poly1305Encrypt({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateChacha20Poly1305Encrypt(plaintext: plaintext, key: key);
+
+Future<Uint8List>  chacha20Poly1305EncryptAsync({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateChacha20Poly1305EncryptAsync(plaintext: plaintext, key: key);
+
+Uint8List  chacha20Poly1305Decrypt({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateChacha20Poly1305Decrypt(ciphertext: ciphertext, key: key);
+
+Future<Uint8List>  chacha20Poly1305DecryptAsync({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateChacha20Poly1305DecryptAsync(ciphertext: ciphertext, key: key);
+
+Uint8List  chacha20Poly1305EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateChacha20Poly1305EncryptWithAad(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  chacha20Poly1305EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateChacha20Poly1305EncryptWithAadAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  chacha20Poly1305DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateChacha20Poly1305DecryptWithAad(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  chacha20Poly1305DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateChacha20Poly1305DecryptWithAadAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+Uint8List  xchacha20Poly1305Encrypt({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateXchacha20Poly1305Encrypt(plaintext: plaintext, key: key);
+
+Future<Uint8List>  xchacha20Poly1305EncryptAsync({required List<int> plaintext , required List<int> key }) => RustLib.instance.api.crateXchacha20Poly1305EncryptAsync(plaintext: plaintext, key: key);
+
+Uint8List  xchacha20Poly1305Decrypt({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateXchacha20Poly1305Decrypt(ciphertext: ciphertext, key: key);
+
+Future<Uint8List>  xchacha20Poly1305DecryptAsync({required List<int> ciphertext , required List<int> key }) => RustLib.instance.api.crateXchacha20Poly1305DecryptAsync(ciphertext: ciphertext, key: key);
+
+Uint8List  xchacha20Poly1305EncryptWithAad({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateXchacha20Poly1305EncryptWithAad(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  xchacha20Poly1305EncryptWithAadAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateXchacha20Poly1305EncryptWithAadAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  xchacha20Poly1305DecryptWithAad({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateXchacha20Poly1305DecryptWithAad(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  xchacha20Poly1305DecryptWithAadAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateXchacha20Poly1305DecryptWithAadAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+List<U8Array32>  sha256Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha256Batch(inputs: inputs);
+
+Future<List<U8Array32>>  sha256BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha256BatchAsync(inputs: inputs);
+
+List<U8Array64>  sha512Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha512Batch(inputs: inputs);
+
+Future<List<U8Array64>>  sha512BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha512BatchAsync(inputs: inputs);
+
+List<U8Array28>  sha3224Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3224Batch(inputs: inputs);
+
+Future<List<U8Array28>>  sha3224BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3224BatchAsync(inputs: inputs);
+
+List<U8Array32>  sha3256Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3256Batch(inputs: inputs);
+
+Future<List<U8Array32>>  sha3256BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3256BatchAsync(inputs: inputs);
+
+List<U8Array48>  sha3384Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3384Batch(inputs: inputs);
+
+Future<List<U8Array48>>  sha3384BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3384BatchAsync(inputs: inputs);
+
+List<U8Array64>  sha3512Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3512Batch(inputs: inputs);
+
+Future<List<U8Array64>>  sha3512BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateSha3512BatchAsync(inputs: inputs);
+
+List<U8Array32>  keccak256Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateKeccak256Batch(inputs: inputs);
+
+Future<List<U8Array32>>  keccak256BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateKeccak256BatchAsync(inputs: inputs);
+
+List<Uint8List>  shake128Batch({required List<Uint8List> inputs , required BigInt outputLen }) => RustLib.instance.api.crateShake128Batch(inputs: inputs, outputLen: outputLen);
+
+Future<List<Uint8List>>  shake128BatchAsync({required List<Uint8List> inputs , required BigInt outputLen }) => RustLib.instance.api.crateShake128BatchAsync(inputs: inputs, outputLen: outputLen);
+
+List<Uint8List>  shake256Batch({required List<Uint8List> inputs , required BigInt outputLen }) => RustLib.instance.api.crateShake256Batch(inputs: inputs, outputLen: outputLen);
+
+Future<List<Uint8List>>  shake256BatchAsync({required List<Uint8List> inputs , required BigInt outputLen }) => RustLib.instance.api.crateShake256BatchAsync(inputs: inputs, outputLen: outputLen);
+
+List<U8Array64>  blake2BBatch({required List<Uint8List> inputs }) => RustLib.instance.api.crateBlake2BBatch(inputs: inputs);
+
+Future<List<U8Array64>>  blake2BBatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateBlake2BBatchAsync(inputs: inputs);
+
+List<U8Array32>  blake2SBatch({required List<Uint8List> inputs }) => RustLib.instance.api.crateBlake2SBatch(inputs: inputs);
+
+Future<List<U8Array32>>  blake2SBatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateBlake2SBatchAsync(inputs: inputs);
+
+List<U8Array32>  blake3Batch({required List<Uint8List> inputs }) => RustLib.instance.api.crateBlake3Batch(inputs: inputs);
+
+Future<List<U8Array32>>  blake3BatchAsync({required List<Uint8List> inputs }) => RustLib.instance.api.crateBlake3BatchAsync(inputs: inputs);
+
+List<U8Array32>  hmacSha256Batch({required List<int> key , required List<Uint8List> messages }) => RustLib.instance.api.crateHmacSha256Batch(key: key, messages: messages);
+
+Future<List<U8Array32>>  hmacSha256BatchAsync({required List<int> key , required List<Uint8List> messages }) => RustLib.instance.api.crateHmacSha256BatchAsync(key: key, messages: messages);
+
+List<U8Array64>  hmacSha512Batch({required List<int> key , required List<Uint8List> messages }) => RustLib.instance.api.crateHmacSha512Batch(key: key, messages: messages);
+
+Future<List<U8Array64>>  hmacSha512BatchAsync({required List<int> key , required List<Uint8List> messages }) => RustLib.instance.api.crateHmacSha512BatchAsync(key: key, messages: messages);
+
+Uint8List  hashThenEncrypt({required List<int> data , required List<int> key }) => RustLib.instance.api.crateHashThenEncrypt(data: data, key: key);
+
+Future<Uint8List>  hashThenEncryptAsync({required List<int> data , required List<int> key }) => RustLib.instance.api.crateHashThenEncryptAsync(data: data, key: key);
+
+(Uint8List,U8Array32)  encryptThenHmac({required List<int> plaintext , required List<int> encKey , required List<int> macKey }) => RustLib.instance.api.crateEncryptThenHmac(plaintext: plaintext, encKey: encKey, macKey: macKey);
+
+Future<(Uint8List,U8Array32)>  encryptThenHmacAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey }) => RustLib.instance.api.crateEncryptThenHmacAsync(plaintext: plaintext, encKey: encKey, macKey: macKey);
+
+Uint8List  verifyHmacThenDecrypt({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey }) => RustLib.instance.api.crateVerifyHmacThenDecrypt(ciphertext: ciphertext, mac: mac, encKey: encKey, macKey: macKey);
+
+Future<Uint8List>  verifyHmacThenDecryptAsync({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey }) => RustLib.instance.api.crateVerifyHmacThenDecryptAsync(ciphertext: ciphertext, mac: mac, encKey: encKey, macKey: macKey);
+
+Uint8List  hashThenEncryptWithAad({required List<int> data , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateHashThenEncryptWithAad(data: data, key: key, aad: aad);
+
+Future<Uint8List>  hashThenEncryptWithAadAsync({required List<int> data , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateHashThenEncryptWithAadAsync(data: data, key: key, aad: aad);
+
+(Uint8List,U8Array32)  encryptThenHmacWithAad({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateEncryptThenHmacWithAad(plaintext: plaintext, encKey: encKey, macKey: macKey, aad: aad);
+
+Future<(Uint8List,U8Array32)>  encryptThenHmacWithAadAsync({required List<int> plaintext , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateEncryptThenHmacWithAadAsync(plaintext: plaintext, encKey: encKey, macKey: macKey, aad: aad);
+
+Uint8List  verifyHmacThenDecryptWithAad({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateVerifyHmacThenDecryptWithAad(ciphertext: ciphertext, mac: mac, encKey: encKey, macKey: macKey, aad: aad);
+
+Future<Uint8List>  verifyHmacThenDecryptWithAadAsync({required List<int> ciphertext , required List<int> mac , required List<int> encKey , required List<int> macKey , required List<int> aad }) => RustLib.instance.api.crateVerifyHmacThenDecryptWithAadAsync(ciphertext: ciphertext, mac: mac, encKey: encKey, macKey: macKey, aad: aad);
+
+String  toHex({required List<int> bytes }) => RustLib.instance.api.crateToHex(bytes: bytes);
+
+Uint8List  fromHex({required String hexString }) => RustLib.instance.api.crateFromHex(hexString: hexString);
+
+BigInt  hashSize({required String algorithm }) => RustLib.instance.api.crateHashSize(algorithm: algorithm);
+
+List<String>  getAllAlgorithms() => RustLib.instance.api.crateGetAllAlgorithms();
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Blake2bHasher>>
+                abstract class Blake2BHasher implements RustOpaqueInterface {
+                     Uint8List  finalize();
+
+
+ Blake2BHasher  fork();
+
+
+factory Blake2BHasher()=>RustLib.instance.api.crateBlake2BHasherNew();
+
+
+/// Keyed and/or shortened variant; an empty key means unkeyed.
+static Blake2BHasher  newKeyed({required List<int> key , required BigInt outputLen })=>RustLib.instance.api.crateBlake2BHasherNewKeyed(key: key, outputLen: outputLen);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Blake2sHasher>>
+                abstract class Blake2SHasher implements RustOpaqueInterface {
+                     Uint8List  finalize();
+
+
+ Blake2SHasher  fork();
+
+
+factory Blake2SHasher()=>RustLib.instance.api.crateBlake2SHasherNew();
+
+
+/// Keyed and/or shortened variant; an empty key means unkeyed.
+static Blake2SHasher  newKeyed({required List<int> key , required BigInt outputLen })=>RustLib.instance.api.crateBlake2SHasherNewKeyed(key: key, outputLen: outputLen);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Blake3Hasher>>
+                abstract class Blake3Hasher implements RustOpaqueInterface {
+                     U8Array32  finalize();
+
+
+ Blake3Hasher  fork();
+
+
+factory Blake3Hasher()=>RustLib.instance.api.crateBlake3HasherNew();
+
+
+static Blake3Hasher  newDeriveKey({required String context })=>RustLib.instance.api.crateBlake3HasherNewDeriveKey(context: context);
+
+
+static Blake3Hasher  newKeyed({required List<int> key })=>RustLib.instance.api.crateBlake3HasherNewKeyed(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Keccak256Hasher>>
+                abstract class Keccak256Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array32  finalize();
+
+
+ Keccak256Hasher  fork();
+
+
+static Keccak256Hasher  importState({required List<int> state })=>RustLib.instance.api.crateKeccak256HasherImportState(state: state);
+
+
+factory Keccak256Hasher()=>RustLib.instance.api.crateKeccak256HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Md5Hasher>>
+                abstract class Md5Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array16  finalize();
+
+
+ Md5Hasher  fork();
+
+
+static Md5Hasher  importState({required List<int> state })=>RustLib.instance.api.crateMd5HasherImportState(state: state);
+
+
+factory Md5Hasher()=>RustLib.instance.api.crateMd5HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Md5HmacHasher>>
+                abstract class Md5HmacHasher implements RustOpaqueInterface {
+                    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState();
+
+
+ U8Array16  finalize();
+
+
+ Md5HmacHasher  fork();
+
+
+static Md5HmacHasher  importState({required List<int> state })=>RustLib.instance.api.crateMd5HmacHasherImportState(state: state);
+
+
+factory Md5HmacHasher({required List<int> key })=>RustLib.instance.api.crateMd5HmacHasherNew(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha1Hasher>>
+                abstract class Sha1Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array20  finalize();
+
+
+ Sha1Hasher  fork();
+
+
+static Sha1Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha1HasherImportState(state: state);
+
+
+factory Sha1Hasher()=>RustLib.instance.api.crateSha1HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha1HmacHasher>>
+                abstract class Sha1HmacHasher implements RustOpaqueInterface {
+                    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState();
+
+
+ U8Array20  finalize();
+
+
+ Sha1HmacHasher  fork();
+
+
+static Sha1HmacHasher  importState({required List<int> state })=>RustLib.instance.api.crateSha1HmacHasherImportState(state: state);
+
+
+factory Sha1HmacHasher({required List<int> key })=>RustLib.instance.api.crateSha1HmacHasherNew(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha224Hasher>>
+                abstract class Sha224Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array28  finalize();
+
+
+ Sha224Hasher  fork();
+
+
+static Sha224Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha224HasherImportState(state: state);
+
+
+factory Sha224Hasher()=>RustLib.instance.api.crateSha224HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha224HmacHasher>>
+                abstract class Sha224HmacHasher implements RustOpaqueInterface {
+                    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState();
+
+
+ U8Array28  finalize();
+
+
+ Sha224HmacHasher  fork();
+
+
+static Sha224HmacHasher  importState({required List<int> state })=>RustLib.instance.api.crateSha224HmacHasherImportState(state: state);
+
+
+factory Sha224HmacHasher({required List<int> key })=>RustLib.instance.api.crateSha224HmacHasherNew(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha256Hasher>>
+                abstract class Sha256Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array32  finalize();
+
+
+ Sha256Hasher  fork();
+
+
+static Sha256Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha256HasherImportState(state: state);
+
+
+factory Sha256Hasher()=>RustLib.instance.api.crateSha256HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha256HmacHasher>>
+                abstract class Sha256HmacHasher implements RustOpaqueInterface {
+                    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState();
+
+
+ U8Array32  finalize();
+
+
+ Sha256HmacHasher  fork();
+
+
+static Sha256HmacHasher  importState({required List<int> state })=>RustLib.instance.api.crateSha256HmacHasherImportState(state: state);
+
+
+factory Sha256HmacHasher({required List<int> key })=>RustLib.instance.api.crateSha256HmacHasherNew(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha384Hasher>>
+                abstract class Sha384Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array48  finalize();
+
+
+ Sha384Hasher  fork();
+
+
+static Sha384Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha384HasherImportState(state: state);
+
+
+factory Sha384Hasher()=>RustLib.instance.api.crateSha384HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha384HmacHasher>>
+                abstract class Sha384HmacHasher implements RustOpaqueInterface {
+                    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState();
+
+
+ U8Array48  finalize();
+
+
+ Sha384HmacHasher  fork();
+
+
+static Sha384HmacHasher  importState({required List<int> state })=>RustLib.instance.api.crateSha384HmacHasherImportState(state: state);
+
+
+factory Sha384HmacHasher({required List<int> key })=>RustLib.instance.api.crateSha384HmacHasherNew(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha3_224Hasher>>
+                abstract class Sha3224Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array28  finalize();
+
+
+ Sha3224Hasher  fork();
+
+
+static Sha3224Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha3224HasherImportState(state: state);
+
+
+factory Sha3224Hasher()=>RustLib.instance.api.crateSha3224HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha3_256Hasher>>
+                abstract class Sha3256Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array32  finalize();
+
+
+ Sha3256Hasher  fork();
+
+
+static Sha3256Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha3256HasherImportState(state: state);
+
+
+factory Sha3256Hasher()=>RustLib.instance.api.crateSha3256HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha3_384Hasher>>
+                abstract class Sha3384Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array48  finalize();
+
+
+ Sha3384Hasher  fork();
+
+
+static Sha3384Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha3384HasherImportState(state: state);
+
+
+factory Sha3384Hasher()=>RustLib.instance.api.crateSha3384HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha3_512Hasher>>
+                abstract class Sha3512Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array64  finalize();
+
+
+ Sha3512Hasher  fork();
+
+
+static Sha3512Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha3512HasherImportState(state: state);
+
+
+factory Sha3512Hasher()=>RustLib.instance.api.crateSha3512HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha512Hasher>>
+                abstract class Sha512Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array64  finalize();
+
+
+ Sha512Hasher  fork();
+
+
+static Sha512Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha512HasherImportState(state: state);
+
+
+factory Sha512Hasher()=>RustLib.instance.api.crateSha512HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha512HmacHasher>>
+                abstract class Sha512HmacHasher implements RustOpaqueInterface {
+                    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+/// keep computing MACs under this key, so protect it like the key itself
+ Uint8List  exportState();
+
+
+ U8Array64  finalize();
+
+
+ Sha512HmacHasher  fork();
+
+
+static Sha512HmacHasher  importState({required List<int> state })=>RustLib.instance.api.crateSha512HmacHasherImportState(state: state);
+
+
+factory Sha512HmacHasher({required List<int> key })=>RustLib.instance.api.crateSha512HmacHasherNew(key: key);
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha512_224Hasher>>
+                abstract class Sha512224Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array28  finalize();
+
+
+ Sha512224Hasher  fork();
+
+
+static Sha512224Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha512224HasherImportState(state: state);
+
+
+factory Sha512224Hasher()=>RustLib.instance.api.crateSha512224HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Sha512_256Hasher>>
+                abstract class Sha512256Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ U8Array32  finalize();
+
+
+ Sha512256Hasher  fork();
+
+
+static Sha512256Hasher  importState({required List<int> state })=>RustLib.instance.api.crateSha512256HasherImportState(state: state);
+
+
+factory Sha512256Hasher()=>RustLib.instance.api.crateSha512256HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Shake128Hasher>>
+                abstract class Shake128Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ Uint8List  finalize({required BigInt outputLen });
+
+
+ Shake128Hasher  fork();
+
+
+static Shake128Hasher  importState({required List<int> state })=>RustLib.instance.api.crateShake128HasherImportState(state: state);
+
+
+factory Shake128Hasher()=>RustLib.instance.api.crateShake128HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Shake256Hasher>>
+                abstract class Shake256Hasher implements RustOpaqueInterface {
+                     Uint8List  exportState();
+
+
+ Uint8List  finalize({required BigInt outputLen });
+
+
+ Shake256Hasher  fork();
+
+
+static Shake256Hasher  importState({required List<int> state })=>RustLib.instance.api.crateShake256HasherImportState(state: state);
+
+
+factory Shake256Hasher()=>RustLib.instance.api.crateShake256HasherNew();
+
+
+ void  reset();
+
+
+ void  update({required List<int> data });
+
+
+
+                    
+                }
+                
+
+@freezed
+                sealed class CipherError with _$CipherError implements FrbException {
+                    const CipherError._();
+
+                     /// The key does not have the length the algorithm requires
+const factory CipherError.invalidKeyLength({   required BigInt expected ,  required BigInt actual , }) = CipherError_InvalidKeyLength;
+ /// The key was rejected by the algorithm (malformed or unsupported)
+const factory CipherError.invalidKey() = CipherError_InvalidKey;
+ /// The ciphertext is shorter than the nonce, header or tag it must contain
+const factory CipherError.ciphertextTooShort({   required BigInt minimum ,  required BigInt actual , }) = CipherError_CiphertextTooShort;
+ /// Authentication tag or MAC did not verify: wrong key, wrong AAD or tampering
+const factory CipherError.authenticationFailed() = CipherError_AuthenticationFailed;
+ /// The cipher refused to encrypt (e.g. message too long for the nonce scheme)
+const factory CipherError.encryptionFailed() = CipherError_EncryptionFailed;
+ /// A STREAM encryptor or decryptor ran out of chunk counters
+const factory CipherError.streamExhausted() = CipherError_StreamExhausted;
+ /// Encrypted data has an unknown magic, version or inconsistent layout
+const factory CipherError.invalidFormat() = CipherError_InvalidFormat;
+ /// The input string is not valid hex
+const factory CipherError.invalidHex() = CipherError_InvalidHex;
+ /// Reading or writing a file failed
+const factory CipherError.io({   required String message , }) = CipherError_Io;
+ /// A parameter is out of range (iterations, output length, cost...)
+const factory CipherError.invalidParameter({   required String message , }) = CipherError_InvalidParameter;
+ /// The algorithm name is unknown or not allowed here
+const factory CipherError.unsupportedAlgorithm({   required String algorithm , }) = CipherError_UnsupportedAlgorithm;
+ /// The token's `exp` claim is in the past (beyond the allowed leeway)
+const factory CipherError.tokenExpired() = CipherError_TokenExpired;
+ /// The token's `nbf` or `iat` claim is in the future (beyond the allowed leeway)
+const factory CipherError.tokenNotYetValid() = CipherError_TokenNotYetValid;
+
+                    
+
+                    
+                }
+
+
+            class U8Array16 extends NonGrowableListView<int> {
+                static const arraySize = 16;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array16(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array16.init(): this(Uint8List(arraySize));
+              }
+            
+
+
+            class U8Array20 extends NonGrowableListView<int> {
+                static const arraySize = 20;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array20(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array20.init(): this(Uint8List(arraySize));
+              }
+            
+
+
+            class U8Array28 extends NonGrowableListView<int> {
+                static const arraySize = 28;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array28(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array28.init(): this(Uint8List(arraySize));
+              }
+            
+
+
+            class U8Array32 extends NonGrowableListView<int> {
+                static const arraySize = 32;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array32(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array32.init(): this(Uint8List(arraySize));
+              }
+            
+
+
+            class U8Array48 extends NonGrowableListView<int> {
+                static const arraySize = 48;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array48(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array48.init(): this(Uint8List(arraySize));
+              }
+            
+
+
+            class U8Array64 extends NonGrowableListView<int> {
+                static const arraySize = 64;
+
+                @internal
+                Uint8List get inner => _inner;
+                final Uint8List _inner;
+
+                U8Array64(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                U8Array64.init(): this(Uint8List(arraySize));
+              }
+            
+            
\ No newline at end of file
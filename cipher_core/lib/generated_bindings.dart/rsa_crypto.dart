@@ -0,0 +1,123 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `allowed_size_internal`, `pem_internal`, `private_key_der_internal`, `private_key_internal`, `public_key_der_internal`, `public_key_internal`, `rsa_generate_keypair_internal`, `rsa_oaep_decrypt_internal`, `rsa_oaep_encrypt_internal`, `rsa_pkcs1v15_sign_internal`, `rsa_pkcs1v15_verify_internal`, `rsa_pss_sign_internal`, `rsa_pss_verify_internal`, `rsa_sign_internal`, `rsa_verify_internal`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SignatureScheme`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+
+
+            /// Generate a keypair with a `bits`-bit modulus and e = 65537. This takes
+/// from tens of milliseconds to several seconds; prefer the async variant.
+RsaKeyPair  rsaGenerateKeypair({required int bits }) => RustLib.instance.api.crateRsaCryptoRsaGenerateKeypair(bits: bits);
+
+Future<RsaKeyPair>  rsaGenerateKeypairAsync({required int bits }) => RustLib.instance.api.crateRsaCryptoRsaGenerateKeypairAsync(bits: bits);
+
+/// Parse a private key in any supported encoding and return it as PKCS#8 DER.
+Uint8List  rsaImportPrivateKey({required List<int> key }) => RustLib.instance.api.crateRsaCryptoRsaImportPrivateKey(key: key);
+
+/// Parse a public key in any supported encoding and return it as SPKI DER.
+Uint8List  rsaImportPublicKey({required List<int> key }) => RustLib.instance.api.crateRsaCryptoRsaImportPublicKey(key: key);
+
+/// SPKI DER public key matching a private key.
+Uint8List  rsaPublicKeyFromPrivateKey({required List<int> privateKey }) => RustLib.instance.api.crateRsaCryptoRsaPublicKeyFromPrivateKey(privateKey: privateKey);
+
+/// Export a private key as a PKCS#8 PEM string ("BEGIN PRIVATE KEY").
+String  rsaPrivateKeyToPem({required List<int> privateKey }) => RustLib.instance.api.crateRsaCryptoRsaPrivateKeyToPem(privateKey: privateKey);
+
+/// Export a public key as an SPKI PEM string ("BEGIN PUBLIC KEY").
+String  rsaPublicKeyToPem({required List<int> publicKey }) => RustLib.instance.api.crateRsaCryptoRsaPublicKeyToPem(publicKey: publicKey);
+
+/// Modulus size in bits, e.g. to check a partner key meets policy.
+int  rsaKeySizeBits({required List<int> publicKey }) => RustLib.instance.api.crateRsaCryptoRsaKeySizeBits(publicKey: publicKey);
+
+/// Encrypt with RSA-OAEP-SHA256. The plaintext can be at most
+/// `key_bytes - 66` long; encrypt a symmetric key for anything larger.
+Uint8List  rsaOaepSha256Encrypt({required List<int> publicKey , required List<int> plaintext }) => RustLib.instance.api.crateRsaCryptoRsaOaepSha256Encrypt(publicKey: publicKey, plaintext: plaintext);
+
+Future<Uint8List>  rsaOaepSha256EncryptAsync({required List<int> publicKey , required List<int> plaintext }) => RustLib.instance.api.crateRsaCryptoRsaOaepSha256EncryptAsync(publicKey: publicKey, plaintext: plaintext);
+
+Uint8List  rsaOaepSha256Decrypt({required List<int> privateKey , required List<int> ciphertext }) => RustLib.instance.api.crateRsaCryptoRsaOaepSha256Decrypt(privateKey: privateKey, ciphertext: ciphertext);
+
+Future<Uint8List>  rsaOaepSha256DecryptAsync({required List<int> privateKey , required List<int> ciphertext }) => RustLib.instance.api.crateRsaCryptoRsaOaepSha256DecryptAsync(privateKey: privateKey, ciphertext: ciphertext);
+
+Uint8List  rsaPkcs1V15Sha256Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha256Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  rsaPkcs1V15Sha256SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha256SignAsync(privateKey: privateKey, message: message);
+
+bool  rsaPkcs1V15Sha256Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha256Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  rsaPkcs1V15Sha256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha256VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  rsaPkcs1V15Sha384Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha384Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  rsaPkcs1V15Sha384SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha384SignAsync(privateKey: privateKey, message: message);
+
+bool  rsaPkcs1V15Sha384Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha384Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  rsaPkcs1V15Sha384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha384VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  rsaPkcs1V15Sha512Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha512Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  rsaPkcs1V15Sha512SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha512SignAsync(privateKey: privateKey, message: message);
+
+bool  rsaPkcs1V15Sha512Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha512Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  rsaPkcs1V15Sha512VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPkcs1V15Sha512VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  rsaPssSha256Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPssSha256Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  rsaPssSha256SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPssSha256SignAsync(privateKey: privateKey, message: message);
+
+bool  rsaPssSha256Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPssSha256Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  rsaPssSha256VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPssSha256VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  rsaPssSha384Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPssSha384Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  rsaPssSha384SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPssSha384SignAsync(privateKey: privateKey, message: message);
+
+bool  rsaPssSha384Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPssSha384Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  rsaPssSha384VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPssSha384VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+Uint8List  rsaPssSha512Sign({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPssSha512Sign(privateKey: privateKey, message: message);
+
+Future<Uint8List>  rsaPssSha512SignAsync({required List<int> privateKey , required List<int> message }) => RustLib.instance.api.crateRsaCryptoRsaPssSha512SignAsync(privateKey: privateKey, message: message);
+
+bool  rsaPssSha512Verify({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPssSha512Verify(publicKey: publicKey, message: message, signature: signature);
+
+Future<bool>  rsaPssSha512VerifyAsync({required List<int> publicKey , required List<int> message , required List<int> signature }) => RustLib.instance.api.crateRsaCryptoRsaPssSha512VerifyAsync(publicKey: publicKey, message: message, signature: signature);
+
+            class RsaKeyPair  {
+                /// PKCS#8 DER
+final Uint8List privateKey;
+/// SPKI DER
+final Uint8List publicKey;
+
+                const RsaKeyPair({required this.privateKey ,required this.publicKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => privateKey.hashCode^publicKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RsaKeyPair &&
+                runtimeType == other.runtimeType
+                && privateKey == other.privateKey&& publicKey == other.publicKey;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_siv_key`, `cmac`, `dbl`, `gcm_siv_deterministic_decrypt_internal`, `gcm_siv_deterministic_encrypt_internal`, `s2v`, `siv_ctr`, `siv_decrypt_internal`, `siv_encrypt_internal`, `siv_nonce_decrypt_internal`, `siv_nonce_encrypt_internal`, `siv_open`, `siv_seal`, `xor_block`
+
+
+            Uint8List  aes256GcmSivEncrypt({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivEncrypt(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  aes256GcmSivEncryptAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivEncryptAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  aes256GcmSivDecrypt({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivDecrypt(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  aes256GcmSivDecryptAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivDecryptAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+Uint8List  aes256GcmSivEncryptDeterministic({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivEncryptDeterministic(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  aes256GcmSivEncryptDeterministicAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivEncryptDeterministicAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  aes256GcmSivDecryptDeterministic({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivDecryptDeterministic(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  aes256GcmSivDecryptDeterministicAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAes256GcmSivDecryptDeterministicAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+Uint8List  aesSivEncrypt({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivEncrypt(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  aesSivEncryptAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivEncryptAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  aesSivDecrypt({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivDecrypt(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  aesSivDecryptAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivDecryptAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+Uint8List  aesSivEncryptDeterministic({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivEncryptDeterministic(plaintext: plaintext, key: key, aad: aad);
+
+Future<Uint8List>  aesSivEncryptDeterministicAsync({required List<int> plaintext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivEncryptDeterministicAsync(plaintext: plaintext, key: key, aad: aad);
+
+Uint8List  aesSivDecryptDeterministic({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivDecryptDeterministic(ciphertext: ciphertext, key: key, aad: aad);
+
+Future<Uint8List>  aesSivDecryptDeterministicAsync({required List<int> ciphertext , required List<int> key , required List<int> aad }) => RustLib.instance.api.crateSivAesSivDecryptDeterministicAsync(ciphertext: ciphertext, key: key, aad: aad);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Aes256GcmStreamDecryptor>>
+                abstract class Aes256GcmStreamDecryptor implements RustOpaqueInterface {
+                    /// Open an intermediate chunk. Fails on tampering, reordering, or if the
+/// chunk was actually sealed as the last one.
+ Uint8List  decryptChunk({required List<int> chunk });
+
+
+/// Open the final chunk and consume the decryptor. Fails if the stream
+/// was truncated, since the chunk given here was not sealed as the last.
+ Uint8List  finalize({required List<int> chunk });
+
+
+/// Create a decryptor from the key and the header written by the encryptor.
+factory Aes256GcmStreamDecryptor({required List<int> key , required List<int> header })=>RustLib.instance.api.crateStreamAes256GcmStreamDecryptorNew(key: key, header: header);
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Aes256GcmStreamEncryptor>>
+                abstract class Aes256GcmStreamEncryptor implements RustOpaqueInterface {
+                    /// Seal an intermediate chunk. Fails once the chunk counter is exhausted.
+ Uint8List  encryptChunk({required List<int> chunk });
+
+
+/// Seal the final chunk (may be empty) and consume the encryptor.
+ Uint8List  finalize({required List<int> chunk });
+
+
+/// Stream header that must be stored before the first chunk.
+ Uint8List  header();
+
+
+/// Create an encryptor with a fresh random nonce prefix.
+factory Aes256GcmStreamEncryptor({required List<int> key })=>RustLib.instance.api.crateStreamAes256GcmStreamEncryptorNew(key: key);
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `open_internal`, `public_internal`, `seal_internal`, `sealed_box_key_internal`, `secret_internal`, `shared_secret_internal`
+
+
+            X25519KeyPair  x25519GenerateKeypair() => RustLib.instance.api.crateX25519X25519GenerateKeypair();
+
+/// Rebuild a keypair from a previously exported 32-byte secret key.
+X25519KeyPair  x25519KeypairFromSecret({required List<int> secretKey }) => RustLib.instance.api.crateX25519X25519KeypairFromSecret(secretKey: secretKey);
+
+/// Raw X25519 shared secret. Run it through HKDF before using it as a key.
+U8Array32  x25519DiffieHellman({required List<int> secretKey , required List<int> publicKey }) => RustLib.instance.api.crateX25519X25519DiffieHellman(secretKey: secretKey, publicKey: publicKey);
+
+Future<U8Array32>  x25519DiffieHellmanAsync({required List<int> secretKey , required List<int> publicKey }) => RustLib.instance.api.crateX25519X25519DiffieHellmanAsync(secretKey: secretKey, publicKey: publicKey);
+
+/// Encrypt to `recipient_pk` with a fresh ephemeral key. Only the holder of
+/// the matching secret key can open it; the sender stays anonymous.
+Uint8List  seal({required List<int> recipientPk , required List<int> plaintext }) => RustLib.instance.api.crateX25519Seal(recipientPk: recipientPk, plaintext: plaintext);
+
+Future<Uint8List>  sealAsync({required List<int> recipientPk , required List<int> plaintext }) => RustLib.instance.api.crateX25519SealAsync(recipientPk: recipientPk, plaintext: plaintext);
+
+Uint8List  open({required List<int> secretKey , required List<int> ciphertext }) => RustLib.instance.api.crateX25519Open(secretKey: secretKey, ciphertext: ciphertext);
+
+Future<Uint8List>  openAsync({required List<int> secretKey , required List<int> ciphertext }) => RustLib.instance.api.crateX25519OpenAsync(secretKey: secretKey, ciphertext: ciphertext);
+
+            class X25519KeyPair  {
+                final U8Array32 secretKey;
+final U8Array32 publicKey;
+
+                const X25519KeyPair({required this.secretKey ,required this.publicKey ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => secretKey.hashCode^publicKey.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is X25519KeyPair &&
+                runtimeType == other.runtimeType
+                && secretKey == other.secretKey&& publicKey == other.publicKey;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,441 @@
+// ============================================================================
+// LEGACY AES MODES: CBC (PKCS#7) & CTR
+// For reading and writing data produced by older clients. CBC and CTR give
+// no integrity on their own: prefer AES-GCM, or at least the composite
+// AES-CBC-HMAC-SHA256 mode at the end of this file, for anything new.
+// The key length selects AES-128, AES-192 or AES-256 (16, 24 or 32 bytes).
+// ============================================================================
+
+use aes::{Aes128, Aes192, Aes256};
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::{BlockCipher, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut, KeyInit, KeyIvInit};
+use ctr::cipher::StreamCipher;
+use rand::Rng;
+
+use crate::{ct_eq_internal, etm_mac_internal, CipherError};
+
+const BLOCK_LEN: usize = 16;
+const IV_LEN: usize = 16;
+const TAG_LEN: usize = 32;
+
+// ============================================================================
+// INTERNAL
+// ============================================================================
+
+fn check_aes_key(key: &[u8]) -> Result<(), CipherError> {
+    match key.len() {
+        16 | 24 | 32 => Ok(()),
+        _ => Err(CipherError::InvalidParameter { message: "AES key must be 16, 24 or 32 bytes".to_string() }),
+    }
+}
+
+fn check_iv(iv: &[u8]) -> Result<(), CipherError> {
+    if iv.len() != IV_LEN {
+        return Err(CipherError::InvalidParameter { message: format!("IV must be {IV_LEN} bytes") });
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn random_iv() -> [u8; IV_LEN] {
+    let mut iv = [0u8; IV_LEN];
+    rand::rng().fill_bytes(&mut iv);
+    iv
+}
+
+fn cbc_encrypt_with<C>(key: &[u8], iv: &[u8], plaintext: &[u8]) -> Vec<u8>
+where
+    C: BlockCipher + BlockEncrypt + KeyInit,
+    cbc::Encryptor<C>: KeyIvInit + BlockEncryptMut,
+{
+    cbc::Encryptor::<C>::new_from_slices(key, iv)
+        .expect("key and IV lengths checked by caller")
+        .encrypt_padded_vec_mut::<Pkcs7>(plaintext)
+}
+
+fn cbc_decrypt_with<C>(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError>
+where
+    C: BlockCipher + BlockDecrypt + KeyInit,
+    cbc::Decryptor<C>: KeyIvInit + BlockDecryptMut,
+{
+    cbc::Decryptor::<C>::new_from_slices(key, iv)
+        .expect("key and IV lengths checked by caller")
+        .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+        .map_err(|_| CipherError::InvalidFormat)
+}
+
+fn cbc_encrypt_internal(key: &[u8], iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_aes_key(key)?;
+    check_iv(iv)?;
+    Ok(match key.len() {
+        16 => cbc_encrypt_with::<Aes128>(key, iv, plaintext),
+        24 => cbc_encrypt_with::<Aes192>(key, iv, plaintext),
+        _ => cbc_encrypt_with::<Aes256>(key, iv, plaintext),
+    })
+}
+
+/// Decrypt and strip PKCS#7 padding; bad padding is `InvalidFormat`
+fn cbc_decrypt_internal(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_aes_key(key)?;
+    check_iv(iv)?;
+    if ciphertext.is_empty() || !ciphertext.len().is_multiple_of(BLOCK_LEN) {
+        return Err(CipherError::InvalidFormat);
+    }
+    match key.len() {
+        16 => cbc_decrypt_with::<Aes128>(key, iv, ciphertext),
+        24 => cbc_decrypt_with::<Aes192>(key, iv, ciphertext),
+        _ => cbc_decrypt_with::<Aes256>(key, iv, ciphertext),
+    }
+}
+
+/// AES-CTR with a 128-bit big-endian counter starting at `iv`; encryption
+/// and decryption are the same operation
+fn ctr_apply_internal(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_aes_key(key)?;
+    check_iv(iv)?;
+    let mut output = data.to_vec();
+    match key.len() {
+        16 => ctr::Ctr128BE::<Aes128>::new_from_slices(key, iv).expect("lengths checked").apply_keystream(&mut output),
+        24 => ctr::Ctr128BE::<Aes192>::new_from_slices(key, iv).expect("lengths checked").apply_keystream(&mut output),
+        _ => ctr::Ctr128BE::<Aes256>::new_from_slices(key, iv).expect("lengths checked").apply_keystream(&mut output),
+    }
+    Ok(output)
+}
+
+/// Split an IV-prepended ciphertext
+#[inline(always)]
+fn split_iv(ciphertext: &[u8]) -> Result<(&[u8], &[u8]), CipherError> {
+    if ciphertext.len() < IV_LEN {
+        return Err(CipherError::CiphertextTooShort { minimum: IV_LEN, actual: ciphertext.len() });
+    }
+    Ok(ciphertext.split_at(IV_LEN))
+}
+
+fn cbc_encrypt_random_iv_internal(key: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let iv = random_iv();
+    let ciphertext = cbc_encrypt_internal(key, &iv, plaintext)?;
+    let mut result = iv.to_vec();
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+fn cbc_decrypt_prefixed_internal(key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let (iv, body) = split_iv(ciphertext)?;
+    cbc_decrypt_internal(key, iv, body)
+}
+
+fn ctr_encrypt_random_iv_internal(key: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let iv = random_iv();
+    let ciphertext = ctr_apply_internal(key, &iv, plaintext)?;
+    let mut result = iv.to_vec();
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+fn ctr_decrypt_prefixed_internal(key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let (iv, body) = split_iv(ciphertext)?;
+    ctr_apply_internal(key, iv, body)
+}
+
+/// Encrypt-then-MAC: IV || CBC ciphertext || HMAC-SHA256 tag, where the tag
+/// covers aad || IV || ciphertext || aad length in bits (RFC 7518 5.2.2.1)
+fn cbc_hmac_encrypt_internal(enc_key: &[u8], mac_key: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let mut result = cbc_encrypt_random_iv_internal(enc_key, plaintext)?;
+    let tag = etm_mac_internal(mac_key, aad, &result);
+    result.extend_from_slice(&tag);
+    Ok(result)
+}
+
+/// The tag is checked in constant time before any block is decrypted, so
+/// tampered input never reaches the padding check
+fn cbc_hmac_decrypt_internal(enc_key: &[u8], mac_key: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_aes_key(enc_key)?;
+    let minimum = IV_LEN + BLOCK_LEN + TAG_LEN;
+    if ciphertext.len() < minimum {
+        return Err(CipherError::CiphertextTooShort { minimum, actual: ciphertext.len() });
+    }
+    let (body, tag) = ciphertext.split_at(ciphertext.len() - TAG_LEN);
+    if !ct_eq_internal(&etm_mac_internal(mac_key, aad, body), tag) {
+        return Err(CipherError::AuthenticationFailed);
+    }
+    cbc_decrypt_prefixed_internal(enc_key, body)
+}
+
+// ============================================================================
+// AES-CBC (SYNC & ASYNC)
+// Random 16-byte IV is generated and prepended to the ciphertext
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_cbc_encrypt(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_encrypt_random_iv_internal(&key, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_cbc_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_encrypt_random_iv_internal(&key, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_cbc_decrypt(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_decrypt_prefixed_internal(&key, &ciphertext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_cbc_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_decrypt_prefixed_internal(&key, &ciphertext)
+}
+
+// ============================================================================
+// AES-CBC WITH EXPLICIT IV (SYNC & ASYNC)
+// For formats that store the IV separately; never reuse an IV with a key
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_cbc_encrypt_with_iv(plaintext: Vec<u8>, key: Vec<u8>, iv: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_encrypt_internal(&key, &iv, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_cbc_encrypt_with_iv_async(plaintext: Vec<u8>, key: Vec<u8>, iv: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_encrypt_internal(&key, &iv, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_cbc_decrypt_with_iv(ciphertext: Vec<u8>, key: Vec<u8>, iv: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_decrypt_internal(&key, &iv, &ciphertext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_cbc_decrypt_with_iv_async(ciphertext: Vec<u8>, key: Vec<u8>, iv: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    cbc_decrypt_internal(&key, &iv, &ciphertext)
+}
+
+// ============================================================================
+// AES-CTR (SYNC & ASYNC)
+// Random 16-byte initial counter block is generated and prepended
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_ctr_encrypt(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    ctr_encrypt_random_iv_internal(&key, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_ctr_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    ctr_encrypt_random_iv_internal(&key, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_ctr_decrypt(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    ctr_decrypt_prefixed_internal(&key, &ciphertext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_ctr_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    ctr_decrypt_prefixed_internal(&key, &ciphertext)
+}
+
+// ============================================================================
+// AES-CTR WITH EXPLICIT IV (SYNC & ASYNC)
+// 128-bit big-endian counter starting at `iv`; the same call encrypts and
+// decrypts. A repeated (key, iv) pair leaks the XOR of both plaintexts
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_ctr_apply_keystream(data: Vec<u8>, key: Vec<u8>, iv: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    ctr_apply_internal(&key, &iv, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_ctr_apply_keystream_async(data: Vec<u8>, key: Vec<u8>, iv: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    ctr_apply_internal(&key, &iv, &data)
+}
+
+// ============================================================================
+// AES-CBC-HMAC-SHA256 (SYNC & ASYNC)
+// Composite AEAD in the style of RFC 7518 A*CBC-HS*, with independent keys:
+// IV (16) || ciphertext || full 32-byte HMAC-SHA256 tag
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_cbc_hmac_sha256_encrypt(
+    plaintext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    cbc_hmac_encrypt_internal(&enc_key, &mac_key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_cbc_hmac_sha256_encrypt_async(
+    plaintext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    cbc_hmac_encrypt_internal(&enc_key, &mac_key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_cbc_hmac_sha256_decrypt(
+    ciphertext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    cbc_hmac_decrypt_internal(&enc_key, &mac_key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_cbc_hmac_sha256_decrypt_async(
+    ciphertext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    cbc_hmac_decrypt_internal(&enc_key, &mac_key, &ciphertext, &aad)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    // NIST SP 800-38A F.2 / F.5 plaintext, shared by every mode and key size
+    const NIST_PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                                  30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const NIST_CBC_IV: &str = "000102030405060708090a0b0c0d0e0f";
+    const NIST_CTR_IV: &str = "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
+
+    // (key, CBC ciphertext, CTR ciphertext) for AES-128, AES-192 and AES-256
+    const NIST_VECTORS: [(&str, &str, &str); 3] = [
+        (
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+             73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7",
+            "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff\
+             5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee",
+        ),
+        (
+            "8e73b0f7da0e6452c810f32b809079e562f8ead2522c6b7b",
+            "4f021db243bc633d7178183a9fa071e8b4d9ada9ad7dedf4e5e738763f69145a\
+             571b242012fb7ae07fa9baac3df102e008b0e27988598881d920a9e64f5615cd",
+            "1abc932417521ca24f2b0459fe7e6e0b090339ec0aa6faefd5ccc2c6f4ce8e94\
+             1e36b26bd1ebc670d1bd1d665620abf74f78a7f6d29809585a97daec58c6b050",
+        ),
+        (
+            "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+            "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d\
+             39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b",
+            "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5\
+             2b0930daa23de94ce87017ba2d84988ddfc9c58db67aada613c2dd08457941a6",
+        ),
+    ];
+
+    // RFC 7518 B.1 (AES_128_CBC_HMAC_SHA_256): MAC key 00..0f, encryption key 10..1f
+    const RFC7518_PLAINTEXT: &[u8] = b"A cipher system must not be required to be secret, and it must be able to fall into the hands of the enemy without inconvenience";
+    const RFC7518_AAD: &[u8] = b"The second principle of Auguste Kerckhoffs";
+    const RFC7518_IV: &str = "1af38c2dc2b96ffdd86694092341bc04";
+    const RFC7518_CIPHERTEXT: &str = "c80edfa32ddf39d5ef00c0b468834279a2e46a1b8049f792f76bfe54b903a9c9\
+                                      a94ac9b47ad2655c5f10f9aef71427e2fc6f9b3f399a221489f16362c7032336\
+                                      09d45ac69864e3321cf82935ac4096c86e133314c54019e8ca7980dfa4b9cf1b\
+                                      384c486f3a54c51078158ee5d79de59fbd34d848b3d69550a67646344427ade5\
+                                      4b8851ffb598f7f80074b9473c82e2db";
+    const RFC7518_TAG: &str = "652c3fa36b0a7c5b3219fab3a30bc1c4";
+
+    fn keys() -> (Vec<u8>, Vec<u8>) {
+        ((16u8..32).collect(), (0u8..16).collect())
+    }
+
+    #[test]
+    fn cbc_nist_sp800_38a() {
+        let plaintext = hex::decode(NIST_PLAINTEXT).unwrap();
+        let iv = hex::decode(NIST_CBC_IV).unwrap();
+        for (key, cbc, _) in NIST_VECTORS {
+            let key = hex::decode(key).unwrap();
+            let expected = hex::decode(cbc).unwrap();
+            let ciphertext = aes_cbc_encrypt_with_iv(plaintext.clone(), key.clone(), iv.clone()).unwrap();
+            // Block-aligned input gains one full PKCS#7 padding block
+            assert_eq!(ciphertext.len(), expected.len() + BLOCK_LEN);
+            assert_eq!(&ciphertext[..expected.len()], &expected[..]);
+            assert_eq!(aes_cbc_decrypt_with_iv(ciphertext, key, iv.clone()).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn ctr_nist_sp800_38a() {
+        let plaintext = hex::decode(NIST_PLAINTEXT).unwrap();
+        let iv = hex::decode(NIST_CTR_IV).unwrap();
+        for (key, _, ctr) in NIST_VECTORS {
+            let key = hex::decode(key).unwrap();
+            let expected = hex::decode(ctr).unwrap();
+            assert_eq!(aes_ctr_apply_keystream(plaintext.clone(), key.clone(), iv.clone()).unwrap(), expected);
+            assert_eq!(aes_ctr_apply_keystream(expected, key.clone(), iv.clone()).unwrap(), plaintext);
+
+            let mut prefixed = iv.clone();
+            prefixed.extend(aes_ctr_apply_keystream(plaintext.clone(), key.clone(), iv.clone()).unwrap());
+            assert_eq!(aes_ctr_decrypt(prefixed, key).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn cbc_hmac_rfc7518_vector() {
+        let (enc_key, mac_key) = keys();
+        let iv = hex::decode(RFC7518_IV).unwrap();
+        let expected = hex::decode(RFC7518_CIPHERTEXT).unwrap();
+        assert_eq!(cbc_encrypt_internal(&enc_key, &iv, RFC7518_PLAINTEXT).unwrap(), expected);
+
+        let mut body = iv;
+        body.extend_from_slice(&expected);
+        let tag = etm_mac_internal(&mac_key, RFC7518_AAD, &body);
+        // RFC 7518 truncates to the first half; this layout keeps the full tag
+        assert_eq!(hex::encode(&tag[..16]), RFC7518_TAG);
+
+        body.extend_from_slice(&tag);
+        let plaintext = aes_cbc_hmac_sha256_decrypt(body, enc_key, mac_key, RFC7518_AAD.to_vec()).unwrap();
+        assert_eq!(plaintext, RFC7518_PLAINTEXT);
+    }
+
+    #[test]
+    fn cbc_hmac_tag_layout() {
+        let (enc_key, mac_key) = keys();
+        for len in [0usize, 1, 15, 16, 17, 100] {
+            let plaintext = vec![0x5a; len];
+            let sealed = aes_cbc_hmac_sha256_encrypt(plaintext.clone(), enc_key.clone(), mac_key.clone(), RFC7518_AAD.to_vec()).unwrap();
+            assert_eq!(sealed.len(), IV_LEN + (len / BLOCK_LEN + 1) * BLOCK_LEN + TAG_LEN);
+
+            let (body, tag) = sealed.split_at(sealed.len() - TAG_LEN);
+            let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&mac_key).unwrap();
+            mac.update(RFC7518_AAD);
+            mac.update(body);
+            mac.update(&((RFC7518_AAD.len() as u64) * 8).to_be_bytes());
+            assert_eq!(&mac.finalize().into_bytes()[..], tag);
+
+            let (iv, ciphertext) = body.split_at(IV_LEN);
+            assert_eq!(aes_cbc_decrypt_with_iv(ciphertext.to_vec(), enc_key.clone(), iv.to_vec()).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn cbc_hmac_tampering_is_authentication_failure() {
+        let (enc_key, mac_key) = keys();
+        let sealed = aes_cbc_hmac_sha256_encrypt(RFC7518_PLAINTEXT.to_vec(), enc_key.clone(), mac_key.clone(), RFC7518_AAD.to_vec()).unwrap();
+        // Every byte, including the last ciphertext block that carries the padding
+        for i in 0..sealed.len() {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 0x01;
+            assert!(matches!(
+                aes_cbc_hmac_sha256_decrypt(tampered, enc_key.clone(), mac_key.clone(), RFC7518_AAD.to_vec()),
+                Err(CipherError::AuthenticationFailed)
+            ));
+        }
+        assert!(matches!(
+            aes_cbc_hmac_sha256_decrypt(sealed.clone(), enc_key.clone(), mac_key.clone(), b"other".to_vec()),
+            Err(CipherError::AuthenticationFailed)
+        ));
+        assert_eq!(aes_cbc_hmac_sha256_decrypt(sealed, enc_key, mac_key, RFC7518_AAD.to_vec()).unwrap(), RFC7518_PLAINTEXT);
+    }
+}
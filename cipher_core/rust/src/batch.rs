@@ -0,0 +1,187 @@
+// ============================================================================
+// PARALLEL BATCH HASHING
+// Batches fan out over a dedicated worker pool so a few thousand thumbnails
+// use every core instead of one. The pool size is capped by
+// `set_batch_parallelism`; tiny batches stay on the calling thread, where
+// the hand-off would cost more than the hashing.
+// ============================================================================
+
+use std::sync::{Arc, RwLock};
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use hmac::digest::{KeyInit, Output};
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::{Keccak256, Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+use crate::hash_algorithm::{hash_internal, unsupported_hmac, HashAlgorithm};
+use crate::{run_blocking, CipherError};
+
+/// Below this many input bytes a batch is hashed on the calling thread
+const PARALLEL_MIN_BYTES: usize = 16 * 1024;
+
+static BATCH_POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+fn build_pool(max_threads: usize) -> Result<ThreadPool, CipherError> {
+    ThreadPoolBuilder::new()
+        .num_threads(max_threads)
+        .thread_name(|index| format!("cipher_core-batch-{index}"))
+        .build()
+        .map_err(|e| CipherError::Io { message: e.to_string() })
+}
+
+/// The shared pool, started on first use; `None` if no worker threads could
+/// be spawned, in which case batches run on the calling thread
+fn batch_pool() -> Option<Arc<ThreadPool>> {
+    if let Some(pool) = BATCH_POOL.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        return Some(pool.clone());
+    }
+    let mut slot = BATCH_POOL.write().unwrap_or_else(|e| e.into_inner());
+    if slot.is_none() {
+        *slot = Some(Arc::new(build_pool(0).ok()?));
+    }
+    slot.clone()
+}
+
+/// Map `f` over `inputs` on the batch pool, keeping input order
+pub(crate) fn par_map_internal<R, F>(inputs: &[Vec<u8>], f: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(&[u8]) -> R + Sync + Send,
+{
+    let total: usize = inputs.iter().map(Vec::len).sum();
+    match batch_pool() {
+        Some(pool) if inputs.len() >= 2 && total >= PARALLEL_MIN_BYTES => {
+            pool.install(|| inputs.par_iter().map(|input| f(input)).collect())
+        }
+        _ => inputs.iter().map(|input| f(input)).collect(),
+    }
+}
+
+/// HMAC every message under one key; the key is absorbed once and the keyed
+/// MAC is cloned per message
+pub(crate) fn hmac_map_internal<M, R>(key: &[u8], messages: &[Vec<u8>], finish: fn(Output<M>) -> R) -> Vec<R>
+where
+    M: Mac + KeyInit + Clone + Sync,
+    R: Send,
+{
+    let keyed = <M as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    par_map_internal(messages, |message| {
+        let mut mac = keyed.clone();
+        mac.update(message);
+        finish(mac.finalize().into_bytes())
+    })
+}
+
+fn hmac_batch_internal(algorithm: HashAlgorithm, key: &[u8], messages: &[Vec<u8>]) -> Result<Vec<Vec<u8>>, CipherError> {
+    fn tags<M: Mac + KeyInit + Clone + Sync>(key: &[u8], messages: &[Vec<u8>]) -> Vec<Vec<u8>> {
+        hmac_map_internal::<M, _>(key, messages, |tag| tag.to_vec())
+    }
+
+    Ok(match algorithm {
+        HashAlgorithm::Sha1 => tags::<Hmac<Sha1>>(key, messages),
+        HashAlgorithm::Sha224 => tags::<Hmac<Sha224>>(key, messages),
+        HashAlgorithm::Sha256 => tags::<Hmac<Sha256>>(key, messages),
+        HashAlgorithm::Sha384 => tags::<Hmac<Sha384>>(key, messages),
+        HashAlgorithm::Sha512 => tags::<Hmac<Sha512>>(key, messages),
+        HashAlgorithm::Sha512_224 => tags::<Hmac<Sha512_224>>(key, messages),
+        HashAlgorithm::Sha512_256 => tags::<Hmac<Sha512_256>>(key, messages),
+        HashAlgorithm::Md5 => tags::<Hmac<Md5>>(key, messages),
+        HashAlgorithm::Sha3_224 => tags::<Hmac<Sha3_224>>(key, messages),
+        HashAlgorithm::Sha3_256 => tags::<Hmac<Sha3_256>>(key, messages),
+        HashAlgorithm::Sha3_384 => tags::<Hmac<Sha3_384>>(key, messages),
+        HashAlgorithm::Sha3_512 => tags::<Hmac<Sha3_512>>(key, messages),
+        HashAlgorithm::Keccak256 => tags::<Hmac<Keccak256>>(key, messages),
+        _ => return Err(unsupported_hmac(algorithm)),
+    })
+}
+
+// ============================================================================
+// POOL CONFIGURATION (SYNC)
+// ============================================================================
+
+/// Cap the number of worker threads used by batch functions; 0 means one per
+/// core. Batches already running finish on the previous pool.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_batch_parallelism(max_threads: usize) -> Result<(), CipherError> {
+    let pool = build_pool(max_threads)?;
+    *BATCH_POOL.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(pool));
+    Ok(())
+}
+
+/// Number of worker threads batch functions currently fan out to
+#[flutter_rust_bridge::frb(sync)]
+pub fn batch_parallelism() -> usize {
+    batch_pool().map_or(1, |pool| pool.current_num_threads())
+}
+
+// ============================================================================
+// GENERIC BATCH HASH / HMAC (SYNC & ASYNC)
+// ============================================================================
+
+/// Hash every input with `algorithm`; results keep input order and SHAKE
+/// yields `digest_size` bytes, as in `hash`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn hash_batch(algorithm: HashAlgorithm, inputs: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    par_map_internal(&inputs, |input| hash_internal(algorithm, input))
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hash_batch_async(algorithm: HashAlgorithm, inputs: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    run_blocking(move || par_map_internal(&inputs, |input| hash_internal(algorithm, input))).await
+}
+
+/// HMAC every message under one key with any algorithm whose
+/// `supports_hmac` is true; others fail with `UnsupportedAlgorithm`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn hmac_batch(algorithm: HashAlgorithm, key: Vec<u8>, messages: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, CipherError> {
+    hmac_batch_internal(algorithm, &key, &messages)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_batch_async(
+    algorithm: HashAlgorithm,
+    key: Vec<u8>,
+    messages: Vec<Vec<u8>>,
+) -> Result<Vec<Vec<u8>>, CipherError> {
+    run_blocking(move || hmac_batch_internal(algorithm, &key, &messages)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_algorithm::hmac_internal;
+
+    /// Uneven sizes totalling well over `PARALLEL_MIN_BYTES`
+    fn inputs() -> Vec<Vec<u8>> {
+        (0..64u32).map(|i| vec![i as u8; (i as usize * 997) % 4096]).collect()
+    }
+
+    #[test]
+    fn parallel_hash_matches_sequential_in_order() {
+        let inputs = inputs();
+        assert!(inputs.iter().map(Vec::len).sum::<usize>() >= PARALLEL_MIN_BYTES);
+        for algorithm in HashAlgorithm::ALL {
+            let sequential: Vec<Vec<u8>> = inputs.iter().map(|input| hash_internal(algorithm, input)).collect();
+            assert_eq!(hash_batch(algorithm, inputs.clone()), sequential, "{}", algorithm.name());
+        }
+    }
+
+    #[test]
+    fn parallel_hmac_matches_sequential_in_order() {
+        let inputs = inputs();
+        for algorithm in HashAlgorithm::ALL.into_iter().filter(|algorithm| algorithm.info().supports_hmac) {
+            let sequential: Vec<Vec<u8>> =
+                inputs.iter().map(|input| hmac_internal(algorithm, b"batch key", input).unwrap()).collect();
+            assert_eq!(hmac_batch(algorithm, b"batch key".to_vec(), inputs.clone()).unwrap(), sequential);
+        }
+        assert!(matches!(
+            hmac_batch(HashAlgorithm::Blake3, b"batch key".to_vec(), inputs),
+            Err(CipherError::UnsupportedAlgorithm { .. })
+        ));
+    }
+}
@@ -0,0 +1,361 @@
+// ============================================================================
+// ECDSA P-256 / P-384 SIGNATURES (SYNC & ASYNC)
+// Private keys are raw big-endian scalars (32 / 48 bytes). Public keys are
+// SEC1 points: generated keys are uncompressed (65 / 97 bytes), and both
+// compressed and uncompressed points are accepted on import.
+// P-256 hashes with SHA-256 (ES256), P-384 with SHA-384 (ES384). Signing is
+// deterministic (RFC 6979). Signatures come in two encodings: fixed-length
+// r || s (64 / 96 bytes, as used by JWS) and ASN.1 DER (as used by X.509,
+// TLS and most platform keystores).
+// ============================================================================
+
+use rand::Rng;
+
+use crate::{check_key_len, CipherError};
+
+pub struct EcdsaKeyPair {
+    pub private_key: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+/// Per-curve helpers. The curve crates share the same `ecdsa` types, so each
+/// expansion only differs in the crate path and the scalar length.
+macro_rules! ecdsa_internal {
+    ($module:ident, $curve:ident, $scalar_len:expr) => {
+        pub(crate) mod $module {
+            use $curve::ecdsa::signature::{Signer, Verifier};
+            use $curve::ecdsa::{Signature, SigningKey, VerifyingKey};
+
+            use super::*;
+
+            pub(crate) const SCALAR_LEN: usize = $scalar_len;
+
+            pub(crate) fn signing_key(private_key: &[u8]) -> Result<SigningKey, CipherError> {
+                check_key_len(private_key, SCALAR_LEN)?;
+                SigningKey::from_slice(private_key).map_err(|_| CipherError::InvalidKey)
+            }
+
+            pub(crate) fn verifying_key(public_key: &[u8]) -> Result<VerifyingKey, CipherError> {
+                VerifyingKey::from_sec1_bytes(public_key).map_err(|_| CipherError::InvalidKey)
+            }
+
+            fn keypair(signing_key: &SigningKey) -> EcdsaKeyPair {
+                EcdsaKeyPair {
+                    private_key: signing_key.to_bytes().to_vec(),
+                    public_key: signing_key.verifying_key().to_encoded_point(false).as_bytes().to_vec(),
+                }
+            }
+
+            pub(crate) fn generate() -> EcdsaKeyPair {
+                // Rejection sampling: zero or >= n is astronomically unlikely
+                let mut bytes = [0u8; SCALAR_LEN];
+                loop {
+                    rand::rng().fill_bytes(&mut bytes);
+                    if let Ok(signing_key) = SigningKey::from_slice(&bytes) { return keypair(&signing_key); }
+                }
+            }
+
+            pub(crate) fn from_private_key(private_key: &[u8]) -> Result<EcdsaKeyPair, CipherError> {
+                Ok(keypair(&signing_key(private_key)?))
+            }
+
+            pub(crate) fn sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, CipherError> {
+                let signature: Signature = signing_key(private_key)?.sign(message);
+                Ok(signature.to_bytes().to_vec())
+            }
+
+            pub(crate) fn sign_der(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, CipherError> {
+                let signature: Signature = signing_key(private_key)?.sign(message);
+                Ok(signature.to_der().as_bytes().to_vec())
+            }
+
+            pub(crate) fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, CipherError> {
+                let key = verifying_key(public_key)?;
+                let Ok(signature) = Signature::from_slice(signature) else { return Ok(false) };
+                Ok(key.verify(message, &signature).is_ok())
+            }
+
+            pub(crate) fn verify_der(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, CipherError> {
+                let key = verifying_key(public_key)?;
+                let Ok(signature) = Signature::from_der(signature) else { return Ok(false) };
+                Ok(key.verify(message, &signature).is_ok())
+            }
+
+            pub(crate) fn to_der(signature: &[u8]) -> Result<Vec<u8>, CipherError> {
+                let signature = Signature::from_slice(signature).map_err(|_| CipherError::InvalidFormat)?;
+                Ok(signature.to_der().as_bytes().to_vec())
+            }
+
+            pub(crate) fn from_der(signature: &[u8]) -> Result<Vec<u8>, CipherError> {
+                let signature = Signature::from_der(signature).map_err(|_| CipherError::InvalidFormat)?;
+                Ok(signature.to_bytes().to_vec())
+            }
+        }
+    };
+}
+
+ecdsa_internal!(p256_internal, p256, 32);
+ecdsa_internal!(p384_internal, p384, 48);
+
+// ============================================================================
+// P-256 KEYS
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_generate_keypair() -> EcdsaKeyPair {
+    p256_internal::generate()
+}
+
+/// Rebuild a keypair from a previously exported 32-byte private scalar.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_keypair_from_private_key(private_key: Vec<u8>) -> Result<EcdsaKeyPair, CipherError> {
+    p256_internal::from_private_key(&private_key)
+}
+
+/// Check that the bytes are a valid SEC1-encoded P-256 point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_is_valid_public_key(public_key: Vec<u8>) -> bool {
+    p256_internal::verifying_key(&public_key).is_ok()
+}
+
+// ============================================================================
+// P-256 SIGN & VERIFY (ES256)
+// ============================================================================
+
+/// Sign with SHA-256, returning the 64-byte r || s encoding.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_sign(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p256_internal::sign(&private_key, &message)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p256_sign_async(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p256_internal::sign(&private_key, &message)
+}
+
+/// Sign with SHA-256, returning an ASN.1 DER signature.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_sign_der(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p256_internal::sign_der(&private_key, &message)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p256_sign_der_async(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p256_internal::sign_der(&private_key, &message)
+}
+
+/// Verify an r || s signature. Returns `Ok(false)` for a bad or malformed
+/// signature and an error only if the public key itself is unusable.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_verify(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p256_internal::verify(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p256_verify_async(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p256_internal::verify(&public_key, &message, &signature)
+}
+
+/// Verify a DER signature, with the same error semantics as `ecdsa_p256_verify`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_verify_der(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p256_internal::verify_der(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p256_verify_der_async(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p256_internal::verify_der(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_signature_to_der(signature: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p256_internal::to_der(&signature)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p256_signature_from_der(signature: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p256_internal::from_der(&signature)
+}
+
+// ============================================================================
+// P-384 KEYS
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_generate_keypair() -> EcdsaKeyPair {
+    p384_internal::generate()
+}
+
+/// Rebuild a keypair from a previously exported 48-byte private scalar.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_keypair_from_private_key(private_key: Vec<u8>) -> Result<EcdsaKeyPair, CipherError> {
+    p384_internal::from_private_key(&private_key)
+}
+
+/// Check that the bytes are a valid SEC1-encoded P-384 point.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_is_valid_public_key(public_key: Vec<u8>) -> bool {
+    p384_internal::verifying_key(&public_key).is_ok()
+}
+
+// ============================================================================
+// P-384 SIGN & VERIFY (ES384)
+// ============================================================================
+
+/// Sign with SHA-384, returning the 96-byte r || s encoding.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_sign(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p384_internal::sign(&private_key, &message)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p384_sign_async(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p384_internal::sign(&private_key, &message)
+}
+
+/// Sign with SHA-384, returning an ASN.1 DER signature.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_sign_der(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p384_internal::sign_der(&private_key, &message)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p384_sign_der_async(private_key: Vec<u8>, message: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p384_internal::sign_der(&private_key, &message)
+}
+
+/// Verify an r || s signature. Returns `Ok(false)` for a bad or malformed
+/// signature and an error only if the public key itself is unusable.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_verify(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p384_internal::verify(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p384_verify_async(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p384_internal::verify(&public_key, &message, &signature)
+}
+
+/// Verify a DER signature, with the same error semantics as `ecdsa_p384_verify`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_verify_der(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p384_internal::verify_der(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ecdsa_p384_verify_der_async(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    p384_internal::verify_der(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_signature_to_der(signature: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p384_internal::to_der(&signature)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ecdsa_p384_signature_from_der(signature: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    p384_internal::from_der(&signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (message, r || s) from RFC 6979 A.2.5 (P-256, SHA-256) and A.2.6 (P-384, SHA-384)
+    type Rfc6979Case = (&'static [u8], &'static str);
+
+    const P256_PRIVATE_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P256_PUBLIC_KEY: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+                                   7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+    const P256_CASES: [Rfc6979Case; 2] = [
+        (
+            b"sample",
+            "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+             f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+        ),
+        (
+            b"test",
+            "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+             019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+        ),
+    ];
+
+    const P384_PRIVATE_KEY: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba\
+                                    9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+    const P384_PUBLIC_KEY: &str = "04ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64\
+                                   def8f0ea9055866064a254515480bc138015d9b72d7d57244ea8ef9ac0c6218967\
+                                   08a59367f9dfb9f54ca84b3f1c9db1288b231c3ae0d4fe7344fd2533264720";
+    const P384_CASES: [Rfc6979Case; 2] = [
+        (
+            b"sample",
+            "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c\
+             81a648152e44acf96e36dd1e80fabe46\
+             99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94f\
+             a329c145786e679e7b82c71a38628ac8",
+        ),
+        (
+            b"test",
+            "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb\
+             0542a7f0812998da8f1dd3ca3cf023db\
+             ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e0\
+             6a739f040649a667bf3b828246baa5a5",
+        ),
+    ];
+
+    #[test]
+    fn p256_rfc6979() {
+        let private_key = hex::decode(P256_PRIVATE_KEY).unwrap();
+        let keypair = ecdsa_p256_keypair_from_private_key(private_key.clone()).unwrap();
+        assert_eq!(hex::encode(&keypair.public_key), P256_PUBLIC_KEY);
+        for (message, expected) in P256_CASES {
+            let signature = ecdsa_p256_sign(private_key.clone(), message.to_vec()).unwrap();
+            assert_eq!(hex::encode(&signature), expected);
+            assert!(ecdsa_p256_verify(keypair.public_key.clone(), message.to_vec(), signature).unwrap());
+
+            let der = ecdsa_p256_sign_der(private_key.clone(), message.to_vec()).unwrap();
+            assert_eq!(ecdsa_p256_signature_from_der(der.clone()).unwrap(), hex::decode(expected).unwrap());
+            assert!(ecdsa_p256_verify_der(keypair.public_key.clone(), message.to_vec(), der).unwrap());
+        }
+    }
+
+    #[test]
+    fn p384_rfc6979() {
+        let private_key = hex::decode(P384_PRIVATE_KEY).unwrap();
+        let keypair = ecdsa_p384_keypair_from_private_key(private_key.clone()).unwrap();
+        assert_eq!(hex::encode(&keypair.public_key), P384_PUBLIC_KEY);
+        for (message, expected) in P384_CASES {
+            let signature = ecdsa_p384_sign(private_key.clone(), message.to_vec()).unwrap();
+            assert_eq!(hex::encode(&signature), expected);
+            assert!(ecdsa_p384_verify(keypair.public_key.clone(), message.to_vec(), signature).unwrap());
+
+            let der = ecdsa_p384_sign_der(private_key.clone(), message.to_vec()).unwrap();
+            assert_eq!(ecdsa_p384_signature_from_der(der.clone()).unwrap(), hex::decode(expected).unwrap());
+            assert!(ecdsa_p384_verify_der(keypair.public_key.clone(), message.to_vec(), der).unwrap());
+        }
+    }
+
+    #[test]
+    fn der_round_trip() {
+        // The A.2.5 "test" signature has a leading zero byte in s, so DER
+        // must drop it on the way out and restore it on the way back
+        let raw = hex::decode(P256_CASES[1].1).unwrap();
+        let der = ecdsa_p256_signature_to_der(raw.clone()).unwrap();
+        assert_eq!(
+            hex::encode(&der),
+            "3045022100f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+             0220019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083"
+        );
+        assert_eq!(ecdsa_p256_signature_from_der(der.clone()).unwrap(), raw);
+        assert_eq!(ecdsa_p256_signature_to_der(ecdsa_p256_signature_from_der(der.clone()).unwrap()).unwrap(), der);
+
+        for (_, expected) in P384_CASES {
+            let raw = hex::decode(expected).unwrap();
+            let der = ecdsa_p384_signature_to_der(raw.clone()).unwrap();
+            assert_eq!(ecdsa_p384_signature_from_der(der.clone()).unwrap(), raw);
+            assert_eq!(ecdsa_p384_signature_to_der(ecdsa_p384_signature_from_der(der.clone()).unwrap()).unwrap(), der);
+        }
+
+        assert!(matches!(ecdsa_p256_signature_to_der(raw[..63].to_vec()), Err(CipherError::InvalidFormat)));
+        assert!(matches!(ecdsa_p256_signature_from_der(raw), Err(CipherError::InvalidFormat)));
+    }
+}
@@ -0,0 +1,308 @@
+// ============================================================================
+// ED25519 SIGNATURES (SYNC & ASYNC)
+// Keys are exchanged as raw bytes: a 32-byte seed (the RFC 8032 private key)
+// and a 32-byte compressed public key. Signatures are 64 bytes.
+// ============================================================================
+
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use rand::Rng;
+
+use crate::{check_key_len, CipherError};
+
+/// Length of an Ed25519 seed (private key)
+pub const ED25519_SEED_LEN: usize = 32;
+
+/// Length of an Ed25519 public key
+pub const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+/// Length of an Ed25519 signature
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+pub struct Ed25519KeyPair {
+    pub seed: [u8; 32],
+    pub public_key: [u8; 32],
+}
+
+#[inline(always)]
+pub(crate) fn signing_key_internal(seed: &[u8]) -> Result<SigningKey, CipherError> {
+    check_key_len(seed, ED25519_SEED_LEN)?;
+    let mut bytes = [0u8; ED25519_SEED_LEN];
+    bytes.copy_from_slice(seed);
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+#[inline(always)]
+fn verifying_key_internal(public_key: &[u8]) -> Result<VerifyingKey, CipherError> {
+    check_key_len(public_key, ED25519_PUBLIC_KEY_LEN)?;
+    let mut bytes = [0u8; ED25519_PUBLIC_KEY_LEN];
+    bytes.copy_from_slice(public_key);
+    VerifyingKey::from_bytes(&bytes).map_err(|_| CipherError::InvalidKey)
+}
+
+#[inline(always)]
+fn signature_internal(signature: &[u8]) -> Option<Signature> {
+    Signature::from_slice(signature).ok()
+}
+
+pub(crate) fn ed25519_verify_internal(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, CipherError> {
+    let key = verifying_key_internal(public_key)?;
+    let Some(signature) = signature_internal(signature) else { return Ok(false) };
+
+    // Strict verification rejects small-order keys and malleable signatures
+    Ok(key.verify_strict(message, &signature).is_ok())
+}
+
+// ============================================================================
+// KEY GENERATION & IMPORT
+// ============================================================================
+
+/// Generate a new keypair from a random seed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ed25519_generate_keypair() -> Ed25519KeyPair {
+    let mut seed = [0u8; ED25519_SEED_LEN];
+    rand::rng().fill_bytes(&mut seed);
+    let public_key = SigningKey::from_bytes(&seed).verifying_key().to_bytes();
+    Ed25519KeyPair { seed, public_key }
+}
+
+/// Rebuild a keypair from a previously exported 32-byte seed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ed25519_keypair_from_seed(seed: Vec<u8>) -> Result<Ed25519KeyPair, CipherError> {
+    let signing_key = signing_key_internal(&seed)?;
+    Ok(Ed25519KeyPair {
+        seed: signing_key.to_bytes(),
+        public_key: signing_key.verifying_key().to_bytes(),
+    })
+}
+
+/// Check that 32 bytes decode to a valid Ed25519 public key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ed25519_is_valid_public_key(public_key: Vec<u8>) -> bool {
+    verifying_key_internal(&public_key).is_ok()
+}
+
+// ============================================================================
+// SIGN & VERIFY
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ed25519_sign(seed: Vec<u8>, message: Vec<u8>) -> Result<[u8; 64], CipherError> {
+    Ok(signing_key_internal(&seed)?.sign(&message).to_bytes())
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ed25519_sign_async(seed: Vec<u8>, message: Vec<u8>) -> Result<[u8; 64], CipherError> {
+    Ok(signing_key_internal(&seed)?.sign(&message).to_bytes())
+}
+
+/// Returns `Ok(false)` for a bad or malformed signature and an error only
+/// if the public key itself is unusable.
+#[flutter_rust_bridge::frb(sync)]
+pub fn ed25519_verify(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    ed25519_verify_internal(&public_key, &message, &signature)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ed25519_verify_async(public_key: Vec<u8>, message: Vec<u8>, signature: Vec<u8>) -> Result<bool, CipherError> {
+    ed25519_verify_internal(&public_key, &message, &signature)
+}
+
+// ============================================================================
+// BATCH VERIFICATION (SYNC & ASYNC)
+// Checks all signatures at once, much faster than one-by-one. Returns
+// `Ok(true)` only if every (public_key, message, signature) triple is valid.
+// The batch equation is cofactorless with random coefficients, so a torsion
+// component in R or A can cancel out of it. Only triples whose R and A lie
+// in the prime-order subgroup are batched; if any does not, every triple is
+// checked with `verify_strict`, so the result always equals `ed25519_verify`.
+// ============================================================================
+
+/// True when R and A are neither small-order nor carry a torsion component
+#[inline(always)]
+fn batchable_internal(key: &VerifyingKey, signature: &Signature) -> bool {
+    let prime_order = |bytes: &[u8; 32]| {
+        CompressedEdwardsY(*bytes)
+            .decompress()
+            .is_some_and(|point| !point.is_small_order() && point.is_torsion_free())
+    };
+    prime_order(signature.r_bytes()) && prime_order(key.as_bytes())
+}
+
+fn ed25519_verify_batch_internal(
+    public_keys: &[Vec<u8>],
+    messages: &[Vec<u8>],
+    signatures: &[Vec<u8>],
+) -> Result<bool, CipherError> {
+    if public_keys.len() != messages.len() || messages.len() != signatures.len() {
+        return Err(CipherError::InvalidParameter {
+            message: "public_keys, messages and signatures must have the same length".to_string(),
+        });
+    }
+
+    let keys = public_keys
+        .iter()
+        .map(|key| verifying_key_internal(key))
+        .collect::<Result<Vec<_>, _>>()?;
+    let Some(signatures) = signatures
+        .iter()
+        .map(|signature| signature_internal(signature))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(false);
+    };
+    let messages: Vec<&[u8]> = messages.iter().map(|message| message.as_slice()).collect();
+
+    if !keys.iter().zip(&signatures).all(|(key, signature)| batchable_internal(key, signature)) {
+        return Ok((0..keys.len()).all(|i| keys[i].verify_strict(messages[i], &signatures[i]).is_ok()));
+    }
+    Ok(ed25519_dalek::verify_batch(&messages, &signatures, &keys).is_ok())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn ed25519_verify_batch(
+    public_keys: Vec<Vec<u8>>,
+    messages: Vec<Vec<u8>>,
+    signatures: Vec<Vec<u8>>,
+) -> Result<bool, CipherError> {
+    ed25519_verify_batch_internal(&public_keys, &messages, &signatures)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn ed25519_verify_batch_async(
+    public_keys: Vec<Vec<u8>>,
+    messages: Vec<Vec<u8>>,
+    signatures: Vec<Vec<u8>>,
+) -> Result<bool, CipherError> {
+    ed25519_verify_batch_internal(&public_keys, &messages, &signatures)
+}
+
+#[cfg(test)]
+mod tests {
+    use curve25519_dalek::constants::{ED25519_BASEPOINT_POINT, EIGHT_TORSION};
+    use curve25519_dalek::Scalar;
+    use curve25519_dalek::traits::IsIdentity;
+    use sha2::{Digest, Sha512};
+
+    use super::*;
+
+    const IDENTITY: [u8; 32] = {
+        let mut bytes = [0u8; 32];
+        bytes[0] = 1;
+        bytes
+    };
+
+    /// k = H(R || A || M) as in RFC 8032
+    fn challenge(nonce: &[u8; 32], public_key: &[u8; 32], message: &[u8]) -> Scalar {
+        let mut hash = Sha512::new();
+        hash.update(nonce);
+        hash.update(public_key);
+        hash.update(message);
+        Scalar::from_bytes_mod_order_wide(&hash.finalize().into())
+    }
+
+    fn agree(public_keys: &[Vec<u8>], messages: &[Vec<u8>], signatures: &[Vec<u8>]) -> bool {
+        let single = (0..public_keys.len()).all(|i| {
+            ed25519_verify_internal(&public_keys[i], &messages[i], &signatures[i]).unwrap()
+        });
+        let batch = ed25519_verify_batch_internal(public_keys, messages, signatures).unwrap();
+        assert_eq!(single, batch);
+        batch
+    }
+
+    type Batch = (Vec<Vec<u8>>, Vec<Vec<u8>>, Vec<Vec<u8>>);
+
+    fn signed(count: u8) -> Batch {
+        let keys: Vec<SigningKey> = (0..count).map(|i| SigningKey::from_bytes(&[i + 1; 32])).collect();
+        let messages: Vec<Vec<u8>> = (0..count).map(|i| vec![i; 10 + i as usize]).collect();
+        let signatures = keys.iter().zip(&messages).map(|(key, message)| key.sign(message).to_vec()).collect();
+        let public_keys = keys.iter().map(|key| key.verifying_key().to_bytes().to_vec()).collect();
+        (public_keys, messages, signatures)
+    }
+
+    #[test]
+    fn batch_agrees_on_valid_and_tampered_signatures() {
+        let (public_keys, messages, mut signatures) = signed(4);
+        assert!(agree(&public_keys, &messages, &signatures));
+
+        signatures[2][40] ^= 1;
+        assert!(!agree(&public_keys, &messages, &signatures));
+    }
+
+    #[test]
+    fn batch_rejects_weak_key_like_single_verify() {
+        // R = identity, s = 0 satisfies the cofactorless equation for A = identity
+        let mut signature = IDENTITY.to_vec();
+        signature.extend_from_slice(&[0u8; 32]);
+        let (mut public_keys, mut messages, mut signatures) = signed(2);
+        public_keys.push(IDENTITY.to_vec());
+        messages.push(b"anything".to_vec());
+        signatures.push(signature);
+
+        assert!(!agree(&public_keys, &messages, &signatures));
+    }
+
+    #[test]
+    fn batch_rejects_small_order_nonce_like_single_verify() {
+        // With R = identity, s = H(R || A || M) * a passes the batch equation
+        let signing_key = SigningKey::from_bytes(&[9u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+        let message = b"small order nonce".to_vec();
+        let k = challenge(&IDENTITY, &public_key, &message);
+        let mut signature = IDENTITY.to_vec();
+        signature.extend_from_slice((k * signing_key.to_scalar()).as_bytes());
+
+        let (mut public_keys, mut messages, mut signatures) = signed(2);
+        public_keys.push(public_key.to_vec());
+        messages.push(message);
+        signatures.push(signature);
+
+        assert!(!agree(&public_keys, &messages, &signatures));
+    }
+
+    #[test]
+    fn batch_rejects_mixed_order_nonce_like_single_verify() {
+        // R = rB + T with T of order 8: s = r + k * a leaves exactly T over,
+        // which the random batch coefficients cancel for some draws
+        let signing_key = SigningKey::from_bytes(&[11u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+        let message = b"mixed order nonce".to_vec();
+        let r = Scalar::from(12345u64);
+        let nonce = (r * ED25519_BASEPOINT_POINT + EIGHT_TORSION[1]).compress().to_bytes();
+        let k = challenge(&nonce, &public_key, &message);
+        let mut signature = nonce.to_vec();
+        signature.extend_from_slice((r + k * signing_key.to_scalar()).as_bytes());
+
+        let (mut public_keys, mut messages, mut signatures) = signed(2);
+        public_keys.push(public_key.to_vec());
+        messages.push(message);
+        signatures.push(signature);
+
+        for _ in 0..32 {
+            assert!(!agree(&public_keys, &messages, &signatures));
+        }
+    }
+
+    #[test]
+    fn batch_accepts_mixed_order_key_when_single_verify_does() {
+        // A = aB + T: `verify_strict` accepts whenever k * T vanishes
+        let a = Scalar::from(987654321u64);
+        let public_key = (a * ED25519_BASEPOINT_POINT + EIGHT_TORSION[1]).compress().to_bytes();
+        let message = b"mixed order key".to_vec();
+        let signature = (1u64..)
+            .find_map(|seed| {
+                let r = Scalar::from(seed);
+                let nonce = (r * ED25519_BASEPOINT_POINT).compress().to_bytes();
+                let k = challenge(&nonce, &public_key, &message);
+                (k * EIGHT_TORSION[1]).is_identity().then(|| [nonce, (r + k * a).to_bytes()].concat())
+            })
+            .unwrap();
+
+        let (mut public_keys, mut messages, mut signatures) = signed(2);
+        public_keys.push(public_key.to_vec());
+        messages.push(message);
+        signatures.push(signature);
+
+        assert!(agree(&public_keys, &messages, &signatures));
+    }
+}
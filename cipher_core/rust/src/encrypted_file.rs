@@ -0,0 +1,404 @@
+// ============================================================================
+// RANDOM-ACCESS ENCRYPTED FILES (SYNC & ASYNC)
+//
+// File layout:
+//   magic "CCEF" (4) || version (1) || chunk_size u32 BE (4) || nonce prefix (7)
+//   || chunk_0 || chunk_1 || ... || chunk_n
+//
+// Plaintext is split into fixed `chunk_size` pieces (the last one may be
+// shorter or empty). Chunk `i` is sealed with AES-256-GCM under the STREAM
+// nonce for position `i` (see `stream.rs`), with the last-chunk flag set on
+// the final one and the 16-byte header as associated data. Because every
+// nonce is derived from the chunk index, any chunk can be opened on its own.
+// ============================================================================
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+
+use aes_gcm::aead::stream::{NewStream, StreamBE32, StreamPrimitive};
+use aes_gcm::aead::{KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Key};
+use rand::Rng;
+
+use crate::stream::{STREAM_HEADER_LEN, STREAM_TAG_LEN};
+use crate::{check_key_len, CipherError};
+
+const MAGIC: &[u8; 4] = b"CCEF";
+const VERSION: u8 = 1;
+
+/// Size of the header at the start of every encrypted file.
+pub const ENCRYPTED_FILE_HEADER_LEN: usize = 16;
+
+/// Plaintext bytes per chunk used when the caller passes a chunk size of 0.
+pub const DEFAULT_CHUNK_SIZE: u32 = 64 * 1024;
+
+/// Largest accepted chunk size. The header is read before anything is
+/// authenticated, so this bounds what a crafted file can make us allocate.
+pub const MAX_CHUNK_SIZE: u32 = 16 * 1024 * 1024;
+
+struct FileHeader {
+    chunk_size: u32,
+    nonce_prefix: [u8; STREAM_HEADER_LEN],
+}
+
+impl FileHeader {
+    fn to_bytes(&self) -> [u8; ENCRYPTED_FILE_HEADER_LEN] {
+        let mut out = [0u8; ENCRYPTED_FILE_HEADER_LEN];
+        out[..4].copy_from_slice(MAGIC);
+        out[4] = VERSION;
+        out[5..9].copy_from_slice(&self.chunk_size.to_be_bytes());
+        out[9..].copy_from_slice(&self.nonce_prefix);
+        out
+    }
+
+    fn parse(bytes: &[u8; ENCRYPTED_FILE_HEADER_LEN]) -> Result<Self, CipherError> {
+        if &bytes[..4] != MAGIC || bytes[4] != VERSION { return Err(CipherError::InvalidFormat); }
+
+        let chunk_size = u32::from_be_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]);
+        if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE { return Err(CipherError::InvalidFormat); }
+
+        let mut nonce_prefix = [0u8; STREAM_HEADER_LEN];
+        nonce_prefix.copy_from_slice(&bytes[9..]);
+        Ok(Self { chunk_size, nonce_prefix })
+    }
+
+    fn stream(&self, key: &[u8]) -> StreamBE32<Aes256Gcm> {
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+        StreamBE32::from_aead(cipher, self.nonce_prefix.as_slice().into())
+    }
+}
+
+/// Chunk geometry derived from the header and the total file length.
+struct Layout {
+    chunk_size: u64,
+    chunk_count: u64,
+    plaintext_len: u64,
+}
+
+impl Layout {
+    fn from_file_len(header: &FileHeader, file_len: u64) -> Result<Self, CipherError> {
+        let body = file_len
+            .checked_sub(ENCRYPTED_FILE_HEADER_LEN as u64)
+            .ok_or(CipherError::InvalidFormat)?;
+        let chunk_size = header.chunk_size as u64;
+        let sealed = chunk_size + STREAM_TAG_LEN as u64;
+
+        let chunk_count = body.div_ceil(sealed);
+        if chunk_count == 0 || chunk_count > u32::MAX as u64 { return Err(CipherError::InvalidFormat); }
+
+        // Every chunk, including the last, carries at least a tag
+        let last_sealed = body - (chunk_count - 1) * sealed;
+        if last_sealed < STREAM_TAG_LEN as u64 { return Err(CipherError::InvalidFormat); }
+
+        Ok(Self {
+            chunk_size,
+            chunk_count,
+            plaintext_len: body - chunk_count * STREAM_TAG_LEN as u64,
+        })
+    }
+
+    fn sealed_offset(&self, index: u64) -> u64 {
+        ENCRYPTED_FILE_HEADER_LEN as u64 + index * (self.chunk_size + STREAM_TAG_LEN as u64)
+    }
+
+    fn sealed_len(&self, index: u64) -> usize {
+        let start = index * self.chunk_size;
+        let end = (start + self.chunk_size).min(self.plaintext_len);
+        (end - start) as usize + STREAM_TAG_LEN
+    }
+}
+
+fn open_encrypted(path: &str) -> Result<(File, FileHeader, Layout), CipherError> {
+    let mut file = File::open(path)?;
+    let file_len = file.metadata()?.len();
+    if file_len < ENCRYPTED_FILE_HEADER_LEN as u64 { return Err(CipherError::InvalidFormat); }
+
+    let mut header_bytes = [0u8; ENCRYPTED_FILE_HEADER_LEN];
+    file.read_exact(&mut header_bytes)?;
+    let header = FileHeader::parse(&header_bytes)?;
+    let layout = Layout::from_file_len(&header, file_len)?;
+    Ok((file, header, layout))
+}
+
+/// Encrypting or decrypting in place would truncate the input before it is
+/// read, so the two paths must name different files
+fn check_distinct_paths(input_path: &str, output_path: &str) -> Result<(), CipherError> {
+    let same = match (fs::canonicalize(input_path), fs::canonicalize(output_path)) {
+        (Ok(input), Ok(output)) => input == output,
+        _ => input_path == output_path,
+    };
+    if same {
+        return Err(CipherError::InvalidParameter { message: "input and output paths must differ".to_string() });
+    }
+    Ok(())
+}
+
+/// Run `write` against a new temporary file next to `output_path` and rename
+/// it into place once everything is written. On failure only that temporary
+/// file is removed; whatever already sits at `output_path` is left alone.
+fn write_output_internal<F>(output_path: &str, write: F) -> Result<u64, CipherError>
+where
+    F: FnOnce(&mut BufWriter<File>) -> Result<u64, CipherError>,
+{
+    let mut suffix = [0u8; 8];
+    rand::rng().fill_bytes(&mut suffix);
+    let temp_path = format!("{output_path}.{}.tmp", hex::encode(suffix));
+    let file = OpenOptions::new().write(true).create_new(true).open(&temp_path)?;
+
+    let written = (|| {
+        let mut writer = BufWriter::new(file);
+        let len = write(&mut writer)?;
+        writer.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        fs::rename(&temp_path, output_path)?;
+        Ok(len)
+    })();
+    if written.is_err() { let _ = fs::remove_file(&temp_path); }
+    written
+}
+
+fn encrypt_file_internal(input_path: &str, output_path: &str, key: &[u8], chunk_size: u32) -> Result<u64, CipherError> {
+    check_key_len(key, 32)?;
+    check_distinct_paths(input_path, output_path)?;
+    let chunk_size = if chunk_size == 0 { DEFAULT_CHUNK_SIZE } else { chunk_size };
+    if chunk_size > MAX_CHUNK_SIZE {
+        return Err(CipherError::InvalidParameter { message: format!("chunk size must be at most {MAX_CHUNK_SIZE} bytes") });
+    }
+
+    let input = File::open(input_path)?;
+    let plaintext_len = input.metadata()?.len();
+    let chunk_count = plaintext_len.div_ceil(chunk_size as u64).max(1);
+    if chunk_count > u32::MAX as u64 { return Err(CipherError::StreamExhausted); }
+
+    let mut nonce_prefix = [0u8; STREAM_HEADER_LEN];
+    rand::rng().fill_bytes(&mut nonce_prefix);
+    let header = FileHeader { chunk_size, nonce_prefix };
+    let header_bytes = header.to_bytes();
+    let stream = header.stream(key);
+
+    let mut reader = BufReader::new(input);
+    write_output_internal(output_path, |writer| {
+        writer.write_all(&header_bytes)?;
+
+        let mut buffer = vec![0u8; chunk_size as usize];
+        let mut remaining = plaintext_len;
+        for index in 0..chunk_count {
+            let len = remaining.min(chunk_size as u64) as usize;
+            reader.read_exact(&mut buffer[..len])?;
+            remaining -= len as u64;
+
+            let last = index == chunk_count - 1;
+            let payload = Payload { msg: &buffer[..len], aad: &header_bytes };
+            let sealed = stream
+                .encrypt(index as u32, last, payload)
+                .map_err(|_| CipherError::EncryptionFailed)?;
+            writer.write_all(&sealed)?;
+        }
+        Ok(plaintext_len)
+    })
+}
+
+fn decrypt_file_internal(input_path: &str, output_path: &str, key: &[u8]) -> Result<u64, CipherError> {
+    check_key_len(key, 32)?;
+    check_distinct_paths(input_path, output_path)?;
+
+    let (file, header, layout) = open_encrypted(input_path)?;
+    let header_bytes = header.to_bytes();
+    let stream = header.stream(key);
+
+    let mut reader = BufReader::new(file);
+    write_output_internal(output_path, |writer| {
+        // Sized per chunk from the file length, never from the header alone
+        let mut buffer = Vec::new();
+        for index in 0..layout.chunk_count {
+            let len = layout.sealed_len(index);
+            buffer.resize(len, 0);
+            reader.read_exact(&mut buffer[..len])?;
+
+            let last = index == layout.chunk_count - 1;
+            let payload = Payload { msg: &buffer[..len], aad: &header_bytes };
+            let plaintext = stream
+                .decrypt(index as u32, last, payload)
+                .map_err(|_| CipherError::AuthenticationFailed)?;
+            writer.write_all(&plaintext)?;
+        }
+        Ok(layout.plaintext_len)
+    })
+}
+
+fn decrypt_range_internal(path: &str, key: &[u8], offset: u64, len: u64) -> Result<Vec<u8>, CipherError> {
+    check_key_len(key, 32)?;
+
+    let (mut file, header, layout) = open_encrypted(path)?;
+    let end = offset.saturating_add(len).min(layout.plaintext_len);
+    if offset >= end { return Ok(Vec::new()); }
+
+    let header_bytes = header.to_bytes();
+    let stream = header.stream(key);
+
+    // Only the chunks overlapping [offset, end) are read and authenticated
+    let first = offset / layout.chunk_size;
+    let last = (end - 1) / layout.chunk_size;
+
+    let mut plaintext = Vec::with_capacity((end - offset) as usize);
+    let mut buffer = Vec::new();
+    file.seek(SeekFrom::Start(layout.sealed_offset(first)))?;
+
+    for index in first..=last {
+        let sealed_len = layout.sealed_len(index);
+        buffer.resize(sealed_len, 0);
+        file.read_exact(&mut buffer[..sealed_len])?;
+
+        let is_last = index == layout.chunk_count - 1;
+        let payload = Payload { msg: &buffer[..sealed_len], aad: &header_bytes };
+        let chunk = stream
+            .decrypt(index as u32, is_last, payload)
+            .map_err(|_| CipherError::AuthenticationFailed)?;
+        plaintext.extend_from_slice(&chunk);
+    }
+
+    let skip = (offset - first * layout.chunk_size) as usize;
+    plaintext.truncate(skip + (end - offset) as usize);
+    plaintext.drain(..skip);
+    Ok(plaintext)
+}
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+/// Encrypt `input_path` into `output_path` using `chunk_size`-byte chunks
+/// (0 selects `DEFAULT_CHUNK_SIZE`, at most `MAX_CHUNK_SIZE`). Returns the
+/// plaintext length.
+/// The output is written to a temporary file and renamed into place, so on
+/// failure an existing file at `output_path` is left untouched.
+#[flutter_rust_bridge::frb(sync)]
+pub fn encrypt_file(input_path: String, output_path: String, key: Vec<u8>, chunk_size: u32) -> Result<u64, CipherError> {
+    encrypt_file_internal(&input_path, &output_path, &key, chunk_size)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn encrypt_file_async(input_path: String, output_path: String, key: Vec<u8>, chunk_size: u32) -> Result<u64, CipherError> {
+    encrypt_file(input_path, output_path, key, chunk_size)
+}
+
+/// Decrypt a whole encrypted file into `output_path`. Returns the plaintext
+/// length. Written like `encrypt_file`: nothing at `output_path` changes
+/// unless the whole file authenticates.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypt_file(input_path: String, output_path: String, key: Vec<u8>) -> Result<u64, CipherError> {
+    decrypt_file_internal(&input_path, &output_path, &key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn decrypt_file_async(input_path: String, output_path: String, key: Vec<u8>) -> Result<u64, CipherError> {
+    decrypt_file(input_path, output_path, key)
+}
+
+/// Decrypt `len` plaintext bytes starting at `offset`, touching only the
+/// chunks that cover the range. The result is clamped to the end of the file.
+#[flutter_rust_bridge::frb(sync)]
+pub fn decrypt_range(path: String, key: Vec<u8>, offset: u64, len: u64) -> Result<Vec<u8>, CipherError> {
+    decrypt_range_internal(&path, &key, offset, len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn decrypt_range_async(path: String, key: Vec<u8>, offset: u64, len: u64) -> Result<Vec<u8>, CipherError> {
+    decrypt_range_internal(&path, &key, offset, len)
+}
+
+/// Plaintext length of an encrypted file, computed from its header and size.
+#[flutter_rust_bridge::frb(sync)]
+pub fn encrypted_file_plaintext_len(path: String) -> Result<u64, CipherError> {
+    open_encrypted(&path).map(|(_, _, layout)| layout.plaintext_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("cipher_core_{}_{name}", std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn range_round_trip() {
+        let (plain, sealed) = (temp_path("range_plain"), temp_path("range_sealed"));
+        let data: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+        fs::write(&plain, &data).unwrap();
+        let key = vec![7u8; 32];
+
+        assert_eq!(encrypt_file(plain.clone(), sealed.clone(), key.clone(), 1000).unwrap(), 10_000);
+        assert_eq!(decrypt_range(sealed.clone(), key.clone(), 1500, 2000).unwrap(), data[1500..3500]);
+        assert_eq!(decrypt_range(sealed.clone(), key.clone(), 9990, 100).unwrap(), data[9990..]);
+
+        let _ = fs::remove_file(plain);
+        let _ = fs::remove_file(sealed);
+    }
+
+    #[test]
+    fn oversized_chunk_header_is_rejected() {
+        let path = temp_path("huge_chunk");
+        let header = FileHeader { chunk_size: u32::MAX - 16, nonce_prefix: [0u8; STREAM_HEADER_LEN] };
+        let mut bytes = header.to_bytes().to_vec();
+        bytes.extend_from_slice(&[0u8; 16]);
+        fs::write(&path, &bytes).unwrap();
+
+        assert!(matches!(decrypt_range(path.clone(), vec![0u8; 32], 0, 1), Err(CipherError::InvalidFormat)));
+        assert!(matches!(encrypted_file_plaintext_len(path.clone()), Err(CipherError::InvalidFormat)));
+        let _ = fs::remove_file(path);
+    }
+
+    fn leftover_temp_files(output: &str) -> usize {
+        let name = std::path::Path::new(output).file_name().unwrap().to_string_lossy().into_owned();
+        fs::read_dir(std::env::temp_dir())
+            .unwrap()
+            .filter(|entry| {
+                let entry = entry.as_ref().unwrap().file_name().to_string_lossy().into_owned();
+                entry.starts_with(&format!("{name}.")) && entry.ends_with(".tmp")
+            })
+            .count()
+    }
+
+    #[test]
+    fn identical_paths_are_rejected_and_input_is_kept() {
+        let path = temp_path("in_place");
+        fs::write(&path, b"precious").unwrap();
+
+        let result = encrypt_file(path.clone(), path.clone(), vec![7u8; 32], 0);
+        assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+        let result = decrypt_file(path.clone(), path.clone(), vec![7u8; 32]);
+        assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+        assert_eq!(fs::read(&path).unwrap(), b"precious");
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn failures_leave_existing_output_untouched() {
+        let (plain, sealed, output) = (temp_path("keep_plain"), temp_path("keep_sealed"), temp_path("keep_output"));
+        fs::write(&plain, vec![1u8; 5000]).unwrap();
+        fs::write(&output, b"existing").unwrap();
+        encrypt_file(plain.clone(), sealed.clone(), vec![7u8; 32], 1000).unwrap();
+
+        // Bad key, missing input, and a failure after output has been written
+        assert!(encrypt_file(plain.clone(), output.clone(), vec![7u8; 31], 0).is_err());
+        assert!(encrypt_file(temp_path("keep_missing"), output.clone(), vec![7u8; 32], 0).is_err());
+        let result = decrypt_file(sealed.clone(), output.clone(), vec![8u8; 32]);
+        assert!(matches!(result, Err(CipherError::AuthenticationFailed)));
+
+        assert_eq!(fs::read(&output).unwrap(), b"existing");
+        assert_eq!(leftover_temp_files(&output), 0);
+
+        assert_eq!(decrypt_file(sealed.clone(), output.clone(), vec![7u8; 32]).unwrap(), 5000);
+        assert_eq!(fs::read(&output).unwrap(), vec![1u8; 5000]);
+        for path in [plain, sealed, output] { let _ = fs::remove_file(path); }
+    }
+
+    #[test]
+    fn oversized_chunk_size_is_refused_on_encrypt() {
+        let result = encrypt_file(temp_path("unused_in"), temp_path("unused_out"), vec![0u8; 32], MAX_CHUNK_SIZE + 1);
+        assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+    }
+}
@@ -0,0 +1,353 @@
+// ============================================================================
+// GENERIC HASH / HMAC DISPATCH (SYNC & ASYNC)
+// One entry point per operation, selected by `HashAlgorithm`, plus metadata
+// so callers can be data-driven. The per-algorithm functions in the crate
+// root remain the fastest path when the algorithm is known statically.
+// ============================================================================
+
+use blake2b_simd::State as Blake2bState;
+use blake2s_simd::State as Blake2sState;
+use hmac::{Hmac, Mac};
+use sha2::{Sha512_224, Sha512_256};
+use sha3::{Keccak256, Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+use crate::midstate::Engine;
+use crate::{
+    blake2b_internal, blake2s_internal, blake3_internal, blake3_update_internal, hmac_md5_internal,
+    hmac_sha1_internal, hmac_sha224_internal, hmac_sha256_internal, hmac_sha384_internal, hmac_sha512_internal,
+    keccak256_internal, md5_internal, sha1_internal, sha224_internal, sha256_internal, sha384_internal,
+    sha3_224_internal, sha3_256_internal, sha3_384_internal, sha3_512_internal, sha512_224_internal,
+    sha512_256_internal, sha512_internal, shake128_internal, shake256_internal, CipherError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+    Md5,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Keccak256,
+    Shake128,
+    Shake256,
+    Blake2b,
+    Blake2s,
+    Blake3,
+}
+
+/// Static properties of a `HashAlgorithm`.
+pub struct HashAlgorithmInfo {
+    pub algorithm: HashAlgorithm,
+    /// Name used by `hash_size` and `get_all_algorithms`
+    pub name: String,
+    /// Digest length in bytes (the default length for SHAKE)
+    pub digest_size: usize,
+    /// Internal block (or sponge rate) size in bytes
+    pub block_size: usize,
+    /// Extendable output: any digest length can be requested
+    pub is_xof: bool,
+    /// Practical collision attacks exist; do not use for new designs
+    pub is_broken: bool,
+    /// Usable with `hmac`. BLAKE2/BLAKE3 have their
+    /// own keyed modes and SHAKE has no fixed output, so they are excluded
+    pub supports_hmac: bool,
+}
+
+impl HashAlgorithm {
+    pub(crate) const ALL: [HashAlgorithm; 18] = [
+        Self::Sha1,
+        Self::Sha224,
+        Self::Sha256,
+        Self::Sha384,
+        Self::Sha512,
+        Self::Sha512_224,
+        Self::Sha512_256,
+        Self::Md5,
+        Self::Sha3_224,
+        Self::Sha3_256,
+        Self::Sha3_384,
+        Self::Sha3_512,
+        Self::Keccak256,
+        Self::Shake128,
+        Self::Shake256,
+        Self::Blake2b,
+        Self::Blake2s,
+        Self::Blake3,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Sha1 => "sha1",
+            Self::Sha224 => "sha224",
+            Self::Sha256 => "sha256",
+            Self::Sha384 => "sha384",
+            Self::Sha512 => "sha512",
+            Self::Sha512_224 => "sha512_224",
+            Self::Sha512_256 => "sha512_256",
+            Self::Md5 => "md5",
+            Self::Sha3_224 => "sha3_224",
+            Self::Sha3_256 => "sha3_256",
+            Self::Sha3_384 => "sha3_384",
+            Self::Sha3_512 => "sha3_512",
+            Self::Keccak256 => "keccak256",
+            Self::Shake128 => "shake128",
+            Self::Shake256 => "shake256",
+            Self::Blake2b => "blake2b",
+            Self::Blake2s => "blake2s",
+            Self::Blake3 => "blake3",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|algorithm| algorithm.name() == name)
+    }
+
+    /// (digest size, block size)
+    pub(crate) fn sizes(self) -> (usize, usize) {
+        match self {
+            Self::Sha1 => (20, 64),
+            Self::Sha224 => (28, 64),
+            Self::Sha256 => (32, 64),
+            Self::Sha384 => (48, 128),
+            Self::Sha512 => (64, 128),
+            Self::Sha512_224 => (28, 128),
+            Self::Sha512_256 => (32, 128),
+            Self::Md5 => (16, 64),
+            Self::Sha3_224 => (28, 144),
+            Self::Sha3_256 => (32, 136),
+            Self::Sha3_384 => (48, 104),
+            Self::Sha3_512 => (64, 72),
+            Self::Keccak256 => (32, 136),
+            Self::Shake128 => (32, 168),
+            Self::Shake256 => (64, 136),
+            Self::Blake2b => (64, 128),
+            Self::Blake2s => (32, 64),
+            Self::Blake3 => (32, 64),
+        }
+    }
+
+    fn is_xof(self) -> bool {
+        matches!(self, Self::Shake128 | Self::Shake256)
+    }
+
+    fn supports_hmac(self) -> bool {
+        !matches!(self, Self::Shake128 | Self::Shake256 | Self::Blake2b | Self::Blake2s | Self::Blake3)
+    }
+
+    pub(crate) fn info(self) -> HashAlgorithmInfo {
+        let (digest_size, block_size) = self.sizes();
+        HashAlgorithmInfo {
+            algorithm: self,
+            name: self.name().to_string(),
+            digest_size,
+            block_size,
+            is_xof: self.is_xof(),
+            is_broken: matches!(self, Self::Md5 | Self::Sha1),
+            supports_hmac: self.supports_hmac(),
+        }
+    }
+}
+
+pub(crate) fn unsupported_hmac(algorithm: HashAlgorithm) -> CipherError {
+    CipherError::UnsupportedAlgorithm { algorithm: format!("hmac-{}", algorithm.name()) }
+}
+
+#[inline(always)]
+fn hmac_generic_internal<M: Mac + hmac::digest::KeyInit>(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = <M as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+pub(crate) fn hash_internal(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+    match algorithm {
+        HashAlgorithm::Sha1 => sha1_internal(data).to_vec(),
+        HashAlgorithm::Sha224 => sha224_internal(data).to_vec(),
+        HashAlgorithm::Sha256 => sha256_internal(data).to_vec(),
+        HashAlgorithm::Sha384 => sha384_internal(data).to_vec(),
+        HashAlgorithm::Sha512 => sha512_internal(data).to_vec(),
+        HashAlgorithm::Sha512_224 => sha512_224_internal(data).to_vec(),
+        HashAlgorithm::Sha512_256 => sha512_256_internal(data).to_vec(),
+        HashAlgorithm::Md5 => md5_internal(data).to_vec(),
+        HashAlgorithm::Sha3_224 => sha3_224_internal(data).to_vec(),
+        HashAlgorithm::Sha3_256 => sha3_256_internal(data).to_vec(),
+        HashAlgorithm::Sha3_384 => sha3_384_internal(data).to_vec(),
+        HashAlgorithm::Sha3_512 => sha3_512_internal(data).to_vec(),
+        HashAlgorithm::Keccak256 => keccak256_internal(data).to_vec(),
+        HashAlgorithm::Shake128 => shake128_internal(data, 32),
+        HashAlgorithm::Shake256 => shake256_internal(data, 64),
+        HashAlgorithm::Blake2b => blake2b_internal(data).to_vec(),
+        HashAlgorithm::Blake2s => blake2s_internal(data).to_vec(),
+        HashAlgorithm::Blake3 => blake3_internal(data).to_vec(),
+    }
+}
+
+pub(crate) fn hmac_internal(algorithm: HashAlgorithm, key: &[u8], data: &[u8]) -> Result<Vec<u8>, CipherError> {
+    Ok(match algorithm {
+        HashAlgorithm::Sha1 => hmac_sha1_internal(key, data).to_vec(),
+        HashAlgorithm::Sha224 => hmac_sha224_internal(key, data).to_vec(),
+        HashAlgorithm::Sha256 => hmac_sha256_internal(key, data).to_vec(),
+        HashAlgorithm::Sha384 => hmac_sha384_internal(key, data).to_vec(),
+        HashAlgorithm::Sha512 => hmac_sha512_internal(key, data).to_vec(),
+        HashAlgorithm::Sha512_224 => hmac_generic_internal::<Hmac<Sha512_224>>(key, data),
+        HashAlgorithm::Sha512_256 => hmac_generic_internal::<Hmac<Sha512_256>>(key, data),
+        HashAlgorithm::Md5 => hmac_md5_internal(key, data).to_vec(),
+        HashAlgorithm::Sha3_224 => hmac_generic_internal::<Hmac<Sha3_224>>(key, data),
+        HashAlgorithm::Sha3_256 => hmac_generic_internal::<Hmac<Sha3_256>>(key, data),
+        HashAlgorithm::Sha3_384 => hmac_generic_internal::<Hmac<Sha3_384>>(key, data),
+        HashAlgorithm::Sha3_512 => hmac_generic_internal::<Hmac<Sha3_512>>(key, data),
+        HashAlgorithm::Keccak256 => hmac_generic_internal::<Hmac<Keccak256>>(key, data),
+        _ => return Err(unsupported_hmac(algorithm)),
+    })
+}
+
+// ============================================================================
+// GENERIC STATEFUL HASHER (SYNC ONLY - stateful object)
+// ============================================================================
+
+#[derive(Clone)]
+enum HasherState {
+    // SHA-1/2/3, MD5, Keccak and SHAKE, whose midstate can be exported
+    Engine(Engine),
+    Blake2b(Blake2bState),
+    Blake2s(Blake2sState),
+    // Boxed: the BLAKE3 chunk stack dwarfs every other state
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl HasherState {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Blake2b => Self::Blake2b(Blake2bState::new()),
+            HashAlgorithm::Blake2s => Self::Blake2s(Blake2sState::new()),
+            HashAlgorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+            _ => Self::Engine(Engine::of(algorithm)),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Engine(h) => h.update(data),
+            Self::Blake2b(h) => { h.update(data); }
+            Self::Blake2s(h) => { h.update(data); }
+            Self::Blake3(h) => blake3_update_internal(h, data),
+        }
+    }
+
+    fn finalize(self, output_len: usize) -> Vec<u8> {
+        match self {
+            Self::Engine(h) => h.finalize(output_len),
+            Self::Blake2b(h) => h.finalize().as_bytes().to_vec(),
+            Self::Blake2s(h) => h.finalize().as_bytes().to_vec(),
+            Self::Blake3(h) => h.finalize().as_bytes().to_vec(),
+        }
+    }
+}
+
+/// Incremental hasher for any `HashAlgorithm`, created by `new_hasher`.
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Hasher {
+    algorithm: HashAlgorithm,
+    state: HasherState,
+}
+
+impl Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.state.update(&data);
+    }
+
+    /// Digest of everything fed so far; SHAKE yields `digest_size` bytes.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> Vec<u8> {
+        self.state.finalize(self.algorithm.sizes().0)
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.state = HasherState::new(self.algorithm);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Opaque versioned midstate for `import_state`; BLAKE2/BLAKE3 fail with
+    /// `UnsupportedAlgorithm`.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Result<Vec<u8>, CipherError> {
+        match &self.state {
+            HasherState::Engine(engine) => Ok(engine.export()),
+            _ => Err(CipherError::UnsupportedAlgorithm { algorithm: self.algorithm.name().to_string() }),
+        }
+    }
+
+    /// Resume a hasher from `export_state` output; the algorithm travels in the blob.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        let engine = Engine::import_any(&state)?;
+        Ok(Self { algorithm: engine.algorithm(), state: HasherState::Engine(engine) })
+    }
+}
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hash(algorithm: HashAlgorithm, data: Vec<u8>) -> Vec<u8> {
+    hash_internal(algorithm, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hash_async(algorithm: HashAlgorithm, data: Vec<u8>) -> Vec<u8> {
+    hash_internal(algorithm, &data)
+}
+
+/// HMAC with any algorithm whose `supports_hmac` is true; others fail with
+/// `UnsupportedAlgorithm`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn hmac(algorithm: HashAlgorithm, key: Vec<u8>, data: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    hmac_internal(algorithm, &key, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_async(algorithm: HashAlgorithm, key: Vec<u8>, data: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    hmac_internal(algorithm, &key, &data)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn new_hasher(algorithm: HashAlgorithm) -> Hasher {
+    Hasher { algorithm, state: HasherState::new(algorithm) }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hash_algorithm_info(algorithm: HashAlgorithm) -> HashAlgorithmInfo {
+    algorithm.info()
+}
+
+/// Metadata for every supported algorithm, in `get_all_algorithms` order.
+#[flutter_rust_bridge::frb(sync)]
+pub fn all_hash_algorithms() -> Vec<HashAlgorithmInfo> {
+    HashAlgorithm::ALL.into_iter().map(HashAlgorithm::info).collect()
+}
+
+/// Map a name from `get_all_algorithms` back to its `HashAlgorithm`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn hash_algorithm_from_name(name: String) -> Result<HashAlgorithm, CipherError> {
+    HashAlgorithm::from_name(&name).ok_or(CipherError::UnsupportedAlgorithm { algorithm: name })
+}
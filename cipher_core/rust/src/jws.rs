@@ -0,0 +1,387 @@
+// ============================================================================
+// JWS COMPACT / JWT (SYNC & ASYNC)
+// Tokens are `base64url(header) . base64url(claims) . base64url(signature)`
+// (RFC 7515 / RFC 7519), unpadded. Supported `alg` values and their keys:
+//   HS256 / HS384 / HS512   shared secret bytes
+//   RS256 / RS384 / RS512   RSA key, any encoding accepted by `rsa_crypto`
+//   PS256 / PS384 / PS512   RSA key, as above
+//   ES256 / ES384           EC private scalar / SEC1 public key (see `ecdsa`)
+//   EdDSA                   Ed25519 seed / public key
+// Verification is pinned to the algorithm the caller expects; the token's
+// own `alg` header is never trusted to pick the key type. `none` is rejected.
+// ============================================================================
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ed25519_dalek::Signer;
+use hmac::Mac;
+use serde_json::{Map, Value};
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::ecdsa::{p256_internal, p384_internal};
+use crate::ed25519::{ed25519_verify_internal, signing_key_internal};
+use crate::rsa_crypto::{rsa_pkcs1v15_sign_internal, rsa_pkcs1v15_verify_internal, rsa_pss_sign_internal, rsa_pss_verify_internal};
+use crate::{run_blocking, CipherError, HmacSha256, HmacSha384, HmacSha512};
+
+pub struct JwsToken {
+    /// Decoded protected header, as compact JSON
+    pub header_json: String,
+    /// Decoded claims (payload), as compact JSON
+    pub claims_json: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JwsAlgorithm {
+    Hs256,
+    Hs384,
+    Hs512,
+    Rs256,
+    Rs384,
+    Rs512,
+    Ps256,
+    Ps384,
+    Ps512,
+    Es256,
+    Es384,
+    EdDsa,
+}
+
+impl JwsAlgorithm {
+    fn parse(name: &str) -> Result<Self, CipherError> {
+        Ok(match name {
+            "HS256" => Self::Hs256,
+            "HS384" => Self::Hs384,
+            "HS512" => Self::Hs512,
+            "RS256" => Self::Rs256,
+            "RS384" => Self::Rs384,
+            "RS512" => Self::Rs512,
+            "PS256" => Self::Ps256,
+            "PS384" => Self::Ps384,
+            "PS512" => Self::Ps512,
+            "ES256" => Self::Es256,
+            "ES384" => Self::Es384,
+            "EdDSA" => Self::EdDsa,
+            _ => return Err(CipherError::UnsupportedAlgorithm { algorithm: name.to_string() }),
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Hs256 => "HS256",
+            Self::Hs384 => "HS384",
+            Self::Hs512 => "HS512",
+            Self::Rs256 => "RS256",
+            Self::Rs384 => "RS384",
+            Self::Rs512 => "RS512",
+            Self::Ps256 => "PS256",
+            Self::Ps384 => "PS384",
+            Self::Ps512 => "PS512",
+            Self::Es256 => "ES256",
+            Self::Es384 => "ES384",
+            Self::EdDsa => "EdDSA",
+        }
+    }
+
+    fn sign(self, key: &[u8], input: &[u8]) -> Result<Vec<u8>, CipherError> {
+        match self {
+            Self::Hs256 => hmac_sign_internal::<HmacSha256>(key, input),
+            Self::Hs384 => hmac_sign_internal::<HmacSha384>(key, input),
+            Self::Hs512 => hmac_sign_internal::<HmacSha512>(key, input),
+            Self::Rs256 => rsa_pkcs1v15_sign_internal::<Sha256>(key, input),
+            Self::Rs384 => rsa_pkcs1v15_sign_internal::<Sha384>(key, input),
+            Self::Rs512 => rsa_pkcs1v15_sign_internal::<Sha512>(key, input),
+            Self::Ps256 => rsa_pss_sign_internal::<Sha256>(key, input),
+            Self::Ps384 => rsa_pss_sign_internal::<Sha384>(key, input),
+            Self::Ps512 => rsa_pss_sign_internal::<Sha512>(key, input),
+            Self::Es256 => p256_internal::sign(key, input),
+            Self::Es384 => p384_internal::sign(key, input),
+            Self::EdDsa => Ok(signing_key_internal(key)?.sign(input).to_bytes().to_vec()),
+        }
+    }
+
+    fn verify(self, key: &[u8], input: &[u8], signature: &[u8]) -> Result<bool, CipherError> {
+        match self {
+            Self::Hs256 => hmac_verify_internal::<HmacSha256>(key, input, signature),
+            Self::Hs384 => hmac_verify_internal::<HmacSha384>(key, input, signature),
+            Self::Hs512 => hmac_verify_internal::<HmacSha512>(key, input, signature),
+            Self::Rs256 => rsa_pkcs1v15_verify_internal::<Sha256>(key, input, signature),
+            Self::Rs384 => rsa_pkcs1v15_verify_internal::<Sha384>(key, input, signature),
+            Self::Rs512 => rsa_pkcs1v15_verify_internal::<Sha512>(key, input, signature),
+            Self::Ps256 => rsa_pss_verify_internal::<Sha256>(key, input, signature),
+            Self::Ps384 => rsa_pss_verify_internal::<Sha384>(key, input, signature),
+            Self::Ps512 => rsa_pss_verify_internal::<Sha512>(key, input, signature),
+            Self::Es256 => p256_internal::verify(key, input, signature),
+            Self::Es384 => p384_internal::verify(key, input, signature),
+            Self::EdDsa => ed25519_verify_internal(key, input, signature),
+        }
+    }
+}
+
+fn hmac_sign_internal<M: Mac + hmac::digest::KeyInit>(key: &[u8], input: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let mut mac = <M as Mac>::new_from_slice(key).map_err(|_| CipherError::InvalidKey)?;
+    mac.update(input);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn hmac_verify_internal<M: Mac + hmac::digest::KeyInit>(key: &[u8], input: &[u8], signature: &[u8]) -> Result<bool, CipherError> {
+    let mut mac = <M as Mac>::new_from_slice(key).map_err(|_| CipherError::InvalidKey)?;
+    mac.update(input);
+    Ok(mac.verify_slice(signature).is_ok())
+}
+
+pub(crate) fn json_object_internal(json: &str) -> Result<Map<String, Value>, CipherError> {
+    match serde_json::from_str(json) {
+        Ok(Value::Object(object)) => Ok(object),
+        _ => Err(CipherError::InvalidParameter { message: "expected a JSON object".to_string() }),
+    }
+}
+
+fn decode_part_internal(part: &str) -> Result<Map<String, Value>, CipherError> {
+    let bytes = URL_SAFE_NO_PAD.decode(part).map_err(|_| CipherError::InvalidFormat)?;
+    match serde_json::from_slice(&bytes) {
+        Ok(Value::Object(object)) => Ok(object),
+        _ => Err(CipherError::InvalidFormat),
+    }
+}
+
+struct ParsedToken<'a> {
+    header: Map<String, Value>,
+    claims: Map<String, Value>,
+    signing_input: &'a str,
+    signature: Vec<u8>,
+}
+
+impl ParsedToken<'_> {
+    fn into_token(self) -> JwsToken {
+        JwsToken {
+            header_json: Value::Object(self.header).to_string(),
+            claims_json: Value::Object(self.claims).to_string(),
+        }
+    }
+}
+
+fn parse_internal(token: &str) -> Result<ParsedToken<'_>, CipherError> {
+    let (signing_input, signature) = token.rsplit_once('.').ok_or(CipherError::InvalidFormat)?;
+    let (header, claims) = signing_input.split_once('.').ok_or(CipherError::InvalidFormat)?;
+    if claims.contains('.') { return Err(CipherError::InvalidFormat); }
+
+    Ok(ParsedToken {
+        header: decode_part_internal(header)?,
+        claims: decode_part_internal(claims)?,
+        signing_input,
+        signature: URL_SAFE_NO_PAD.decode(signature).map_err(|_| CipherError::InvalidFormat)?,
+    })
+}
+
+/// Reads a NumericDate claim; absent is fine, anything but a number is not
+fn numeric_claim_internal(claims: &Map<String, Value>, name: &str) -> Result<Option<f64>, CipherError> {
+    match claims.get(name) {
+        None => Ok(None),
+        Some(value) => value.as_f64().map(Some).ok_or(CipherError::InvalidFormat),
+    }
+}
+
+fn validate_times_internal(claims: &Map<String, Value>, leeway_seconds: u64) -> Result<(), CipherError> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64()).unwrap_or(0.0);
+    let leeway = leeway_seconds as f64;
+
+    if let Some(exp) = numeric_claim_internal(claims, "exp")?
+        && now >= exp + leeway
+    {
+        return Err(CipherError::TokenExpired);
+    }
+    for name in ["nbf", "iat"] {
+        if let Some(time) = numeric_claim_internal(claims, name)?
+            && now + leeway < time
+        {
+            return Err(CipherError::TokenNotYetValid);
+        }
+    }
+    Ok(())
+}
+
+fn jws_sign_internal(algorithm: &str, key: &[u8], claims_json: &str, header_json: Option<&str>) -> Result<String, CipherError> {
+    let algorithm = JwsAlgorithm::parse(algorithm)?;
+    let claims = json_object_internal(claims_json)?;
+    let mut header = match header_json {
+        Some(json) => json_object_internal(json)?,
+        None => Map::new(),
+    };
+    header.insert("alg".to_string(), Value::from(algorithm.name()));
+    header.entry("typ").or_insert_with(|| Value::from("JWT"));
+
+    let mut token = URL_SAFE_NO_PAD.encode(Value::Object(header).to_string());
+    token.push('.');
+    token.push_str(&URL_SAFE_NO_PAD.encode(Value::Object(claims).to_string()));
+
+    let signature = algorithm.sign(key, token.as_bytes())?;
+    token.push('.');
+    token.push_str(&URL_SAFE_NO_PAD.encode(signature));
+    Ok(token)
+}
+
+fn jws_verify_internal(token: &str, algorithm: &str, key: &[u8], leeway_seconds: u64) -> Result<JwsToken, CipherError> {
+    let algorithm = JwsAlgorithm::parse(algorithm)?;
+    let parsed = parse_internal(token)?;
+
+    // A token claiming a different algorithm is rejected before any crypto
+    if parsed.header.get("alg").and_then(Value::as_str) != Some(algorithm.name()) {
+        return Err(CipherError::AuthenticationFailed);
+    }
+    // No critical extensions are understood (RFC 7515 section 4.1.11)
+    if parsed.header.contains_key("crit") { return Err(CipherError::InvalidFormat); }
+
+    if !algorithm.verify(key, parsed.signing_input.as_bytes(), &parsed.signature)? {
+        return Err(CipherError::AuthenticationFailed);
+    }
+    validate_times_internal(&parsed.claims, leeway_seconds)?;
+    Ok(parsed.into_token())
+}
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+/// Sign `claims_json` (a JSON object) as a compact JWS. `header_json` may add
+/// fields such as `kid`; `alg` is always set from `algorithm` and `typ`
+/// defaults to "JWT".
+#[flutter_rust_bridge::frb(sync)]
+pub fn jws_sign(algorithm: String, key: Vec<u8>, claims_json: String, header_json: Option<String>) -> Result<String, CipherError> {
+    jws_sign_internal(&algorithm, &key, &claims_json, header_json.as_deref())
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn jws_sign_async(algorithm: String, key: Vec<u8>, claims_json: String, header_json: Option<String>) -> Result<String, CipherError> {
+    run_blocking(move || jws_sign_internal(&algorithm, &key, &claims_json, header_json.as_deref())).await
+}
+
+/// Verify a compact JWS signed with `algorithm`, then check `exp`, `nbf`
+/// and `iat` (when present) allowing `leeway_seconds` of clock skew.
+/// Fails with `AuthenticationFailed` on a bad signature or `alg` mismatch,
+/// and `TokenExpired` / `TokenNotYetValid` on time claims.
+#[flutter_rust_bridge::frb(sync)]
+pub fn jws_verify(token: String, algorithm: String, key: Vec<u8>, leeway_seconds: u64) -> Result<JwsToken, CipherError> {
+    jws_verify_internal(&token, &algorithm, &key, leeway_seconds)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn jws_verify_async(token: String, algorithm: String, key: Vec<u8>, leeway_seconds: u64) -> Result<JwsToken, CipherError> {
+    run_blocking(move || jws_verify_internal(&token, &algorithm, &key, leeway_seconds)).await
+}
+
+/// Decode header and claims WITHOUT checking the signature or time claims,
+/// e.g. to read `kid` and pick the verification key. Never trust the result.
+#[flutter_rust_bridge::frb(sync)]
+pub fn jws_decode_unverified(token: String) -> Result<JwsToken, CipherError> {
+    parse_internal(&token).map(ParsedToken::into_token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecdsa::{ecdsa_p256_generate_keypair, ecdsa_p384_generate_keypair};
+    use crate::ed25519::ed25519_generate_keypair;
+
+    // RFC 7515 appendix A.1
+    const RFC7515_A1_TOKEN: &str = "eyJ0eXAiOiJKV1QiLA0KICJhbGciOiJIUzI1NiJ9\
+        .eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ\
+        .dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    const RFC7515_A1_KEY: &str = "AyM1SysPpbyDfgZld3umj1qzKObwVMkoqQ-EstJQLr_T-1qS0gZH75aKtMN3Yj0iPS4hcgUuTwjAzZr1Z9CAow";
+
+    fn now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    fn verify(token: &str, algorithm: &str, key: &[u8], leeway_seconds: u64) -> Result<JwsToken, CipherError> {
+        jws_verify(token.to_string(), algorithm.to_string(), key.to_vec(), leeway_seconds)
+    }
+
+    #[test]
+    fn rfc7515_a1_hs256_verifies() {
+        let key = URL_SAFE_NO_PAD.decode(RFC7515_A1_KEY).unwrap();
+
+        // The token expired in 2011: the signature checks out, then `exp` fails
+        assert_eq!(verify(RFC7515_A1_TOKEN, "HS256", &key, 0).err(), Some(CipherError::TokenExpired));
+        let decoded = verify(RFC7515_A1_TOKEN, "HS256", &key, now()).unwrap();
+        let claims = json_object_internal(&decoded.claims_json).unwrap();
+        assert_eq!(claims["iss"], "joe");
+        assert_eq!(claims["http://example.com/is_root"], true);
+
+        let mut wrong_key = key.clone();
+        wrong_key[0] ^= 1;
+        assert_eq!(verify(RFC7515_A1_TOKEN, "HS256", &wrong_key, now()).err(), Some(CipherError::AuthenticationFailed));
+        assert_eq!(verify(RFC7515_A1_TOKEN, "HS384", &key, now()).err(), Some(CipherError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn algorithm_must_match_the_expected_one() {
+        let ec = ecdsa_p256_generate_keypair();
+        let es256 = jws_sign("ES256".to_string(), ec.private_key, "{}".to_string(), None).unwrap();
+        // The public key is no HMAC secret, even though it is known to everyone
+        assert_eq!(verify(&es256, "HS256", &ec.public_key, 0).err(), Some(CipherError::AuthenticationFailed));
+
+        let forged = jws_sign("HS256".to_string(), ec.public_key.clone(), "{}".to_string(), None).unwrap();
+        assert_eq!(verify(&forged, "ES256", &ec.public_key, 0).err(), Some(CipherError::AuthenticationFailed));
+        assert_eq!(verify(&forged, "RS256", b"unused", 0).err(), Some(CipherError::AuthenticationFailed));
+
+        let none = format!("{}.{}.", URL_SAFE_NO_PAD.encode(r#"{"alg":"none"}"#), URL_SAFE_NO_PAD.encode("{}"));
+        assert_eq!(verify(&none, "HS256", b"secret", 0).err(), Some(CipherError::AuthenticationFailed));
+        assert!(matches!(verify(&none, "none", b"", 0), Err(CipherError::UnsupportedAlgorithm { .. })));
+    }
+
+    #[test]
+    fn time_claims_respect_leeway() {
+        let sign = |claims: String| jws_sign("HS256".to_string(), b"secret".to_vec(), claims, None).unwrap();
+
+        let expired = sign(format!(r#"{{"exp":{}}}"#, now() - 30));
+        assert!(verify(&expired, "HS256", b"secret", 60).is_ok());
+        assert_eq!(verify(&expired, "HS256", b"secret", 10).err(), Some(CipherError::TokenExpired));
+
+        let early = sign(format!(r#"{{"nbf":{}}}"#, now() + 30));
+        assert!(verify(&early, "HS256", b"secret", 60).is_ok());
+        assert_eq!(verify(&early, "HS256", b"secret", 10).err(), Some(CipherError::TokenNotYetValid));
+
+        let valid = sign(format!(r#"{{"nbf":{},"exp":{}}}"#, now() - 30, now() + 30));
+        assert!(verify(&valid, "HS256", b"secret", 0).is_ok());
+        let malformed = sign(r#"{"exp":"tomorrow"}"#.to_string());
+        assert_eq!(verify(&malformed, "HS256", b"secret", 0).err(), Some(CipherError::InvalidFormat));
+    }
+
+    #[test]
+    fn crit_header_is_rejected() {
+        let header = Some(r#"{"crit":["exp"]}"#.to_string());
+        let token = jws_sign("HS256".to_string(), b"secret".to_vec(), "{}".to_string(), header).unwrap();
+        assert_eq!(verify(&token, "HS256", b"secret", 0).err(), Some(CipherError::InvalidFormat));
+    }
+
+    #[test]
+    fn asymmetric_round_trips() {
+        let claims = r#"{"sub":"alice"}"#.to_string();
+        let p256 = ecdsa_p256_generate_keypair();
+        let p384 = ecdsa_p384_generate_keypair();
+        let ed25519 = ed25519_generate_keypair();
+        let cases = [
+            ("ES256", p256.private_key, p256.public_key, 64),
+            ("ES384", p384.private_key, p384.public_key, 96),
+            ("EdDSA", ed25519.seed.to_vec(), ed25519.public_key.to_vec(), 64),
+        ];
+        for (algorithm, private_key, public_key, signature_len) in cases {
+            let header = Some(r#"{"kid":"k1"}"#.to_string());
+            let token = jws_sign(algorithm.to_string(), private_key, claims.clone(), header).unwrap();
+            let signature = URL_SAFE_NO_PAD.decode(token.rsplit_once('.').unwrap().1).unwrap();
+            assert_eq!(signature.len(), signature_len, "{algorithm}");
+
+            let decoded = verify(&token, algorithm, &public_key, 0).unwrap();
+            assert_eq!(decoded.claims_json, claims);
+            let header = json_object_internal(&decoded.header_json).unwrap();
+            assert_eq!((header["alg"].as_str(), header["kid"].as_str()), (Some(algorithm), Some("k1")));
+
+            // Swap in other claims under the same signature
+            let parts: Vec<&str> = token.split('.').collect();
+            let tampered = format!("{}.{}.{}", parts[0], URL_SAFE_NO_PAD.encode(r#"{"sub":"mallory"}"#), parts[2]);
+            assert_eq!(verify(&tampered, algorithm, &public_key, 0).err(), Some(CipherError::AuthenticationFailed));
+        }
+    }
+}
@@ -0,0 +1,666 @@
+// ============================================================================
+// KEY DERIVATION FUNCTIONS
+// ============================================================================
+
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::Rng;
+
+use crate::{ct_eq_internal, run_blocking, CipherError, HmacSha1, HmacSha256, HmacSha512};
+
+// ============================================================================
+// PBKDF2-HMAC (SYNC & ASYNC)
+// Async variants run on the blocking pool: 600k iterations take seconds
+// ============================================================================
+
+/// Longest PBKDF2 output accepted; derived keys are tens of bytes, and the
+/// length comes straight from Dart
+pub const PBKDF2_MAX_OUTPUT_LEN: usize = 1024;
+
+#[inline(always)]
+fn check_pbkdf2_params(iterations: u32, output_len: usize) -> Result<(), CipherError> {
+    if iterations == 0 {
+        return Err(CipherError::InvalidParameter { message: "iterations must be at least 1".to_string() });
+    }
+    if output_len == 0 || output_len > PBKDF2_MAX_OUTPUT_LEN {
+        return Err(CipherError::InvalidParameter {
+            message: format!("output length must be 1..={PBKDF2_MAX_OUTPUT_LEN}"),
+        });
+    }
+    Ok(())
+}
+
+macro_rules! pbkdf2_internal {
+    ($name:ident, $mac:ty) => {
+        fn $name(password: &[u8], salt: &[u8], iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+            check_pbkdf2_params(iterations, output_len)?;
+            let mut output = vec![0u8; output_len];
+            pbkdf2::pbkdf2::<$mac>(password, salt, iterations, &mut output)
+                .map_err(|_| CipherError::InvalidKey)?;
+            Ok(output)
+        }
+    };
+}
+
+pbkdf2_internal!(pbkdf2_hmac_sha1_internal, HmacSha1);
+pbkdf2_internal!(pbkdf2_hmac_sha256_internal, HmacSha256);
+pbkdf2_internal!(pbkdf2_hmac_sha512_internal, HmacSha512);
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn pbkdf2_hmac_sha1(password: Vec<u8>, salt: Vec<u8>, iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    pbkdf2_hmac_sha1_internal(&password, &salt, iterations, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn pbkdf2_hmac_sha1_async(password: Vec<u8>, salt: Vec<u8>, iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    run_blocking(move || pbkdf2_hmac_sha1_internal(&password, &salt, iterations, output_len)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn pbkdf2_hmac_sha256(password: Vec<u8>, salt: Vec<u8>, iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    pbkdf2_hmac_sha256_internal(&password, &salt, iterations, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn pbkdf2_hmac_sha256_async(password: Vec<u8>, salt: Vec<u8>, iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    run_blocking(move || pbkdf2_hmac_sha256_internal(&password, &salt, iterations, output_len)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn pbkdf2_hmac_sha512(password: Vec<u8>, salt: Vec<u8>, iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    pbkdf2_hmac_sha512_internal(&password, &salt, iterations, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn pbkdf2_hmac_sha512_async(password: Vec<u8>, salt: Vec<u8>, iterations: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    run_blocking(move || pbkdf2_hmac_sha512_internal(&password, &salt, iterations, output_len)).await
+}
+
+// ============================================================================
+// HKDF (RFC 5869) (SYNC & ASYNC)
+// extract: (salt, ikm) -> PRK, expand: (PRK, info, length) -> OKM
+// Use distinct `info` strings to derive independent keys from one secret
+// ============================================================================
+
+macro_rules! hkdf_internal {
+    ($extract:ident, $expand:ident, $derive:ident, $digest:ty, $size:literal) => {
+        #[inline(always)]
+        pub(crate) fn $extract(salt: &[u8], ikm: &[u8]) -> [u8; $size] {
+            let (prk, _) = hkdf::Hkdf::<$digest>::extract(Some(salt), ikm);
+            prk.into()
+        }
+
+        pub(crate) fn $expand(prk: &[u8], info: &[u8], output_len: usize) -> Result<Vec<u8>, CipherError> {
+            let hk = hkdf::Hkdf::<$digest>::from_prk(prk)
+                .map_err(|_| CipherError::InvalidKeyLength { expected: $size, actual: prk.len() })?;
+            // Checked before allocating: `output_len` comes straight from Dart
+            if output_len > 255 * $size {
+                return Err(CipherError::InvalidParameter {
+                    message: format!("output length must be at most {}", 255 * $size),
+                });
+            }
+            let mut okm = vec![0u8; output_len];
+            hk.expand(info, &mut okm).map_err(|_| CipherError::InvalidParameter {
+                message: format!("output length must be at most {}", 255 * $size),
+            })?;
+            Ok(okm)
+        }
+
+        pub(crate) fn $derive(ikm: &[u8], salt: &[u8], info: &[u8], output_len: usize) -> Result<Vec<u8>, CipherError> {
+            let prk = $extract(salt, ikm);
+            $expand(&prk, info, output_len)
+        }
+    };
+}
+
+hkdf_internal!(hkdf_sha256_extract_internal, hkdf_sha256_expand_internal, hkdf_sha256_internal, sha2::Sha256, 32);
+hkdf_internal!(hkdf_sha384_extract_internal, hkdf_sha384_expand_internal, hkdf_sha384_internal, sha2::Sha384, 48);
+hkdf_internal!(hkdf_sha512_extract_internal, hkdf_sha512_expand_internal, hkdf_sha512_internal, sha2::Sha512, 64);
+
+// HKDF-SHA256
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha256_extract(salt: Vec<u8>, ikm: Vec<u8>) -> [u8; 32] {
+    hkdf_sha256_extract_internal(&salt, &ikm)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha256_extract_async(salt: Vec<u8>, ikm: Vec<u8>) -> [u8; 32] {
+    hkdf_sha256_extract_internal(&salt, &ikm)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha256_expand(prk: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha256_expand_internal(&prk, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha256_expand_async(prk: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha256_expand_internal(&prk, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha256(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha256_internal(&ikm, &salt, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha256_async(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha256_internal(&ikm, &salt, &info, output_len)
+}
+
+// HKDF-SHA384
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha384_extract(salt: Vec<u8>, ikm: Vec<u8>) -> [u8; 48] {
+    hkdf_sha384_extract_internal(&salt, &ikm)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha384_extract_async(salt: Vec<u8>, ikm: Vec<u8>) -> [u8; 48] {
+    hkdf_sha384_extract_internal(&salt, &ikm)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha384_expand(prk: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha384_expand_internal(&prk, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha384_expand_async(prk: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha384_expand_internal(&prk, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha384(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha384_internal(&ikm, &salt, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha384_async(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha384_internal(&ikm, &salt, &info, output_len)
+}
+
+// HKDF-SHA512
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha512_extract(salt: Vec<u8>, ikm: Vec<u8>) -> [u8; 64] {
+    hkdf_sha512_extract_internal(&salt, &ikm)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha512_extract_async(salt: Vec<u8>, ikm: Vec<u8>) -> [u8; 64] {
+    hkdf_sha512_extract_internal(&salt, &ikm)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha512_expand(prk: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha512_expand_internal(&prk, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha512_expand_async(prk: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha512_expand_internal(&prk, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hkdf_sha512(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha512_internal(&ikm, &salt, &info, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hkdf_sha512_async(ikm: Vec<u8>, salt: Vec<u8>, info: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    hkdf_sha512_internal(&ikm, &salt, &info, output_len)
+}
+
+// ============================================================================
+// ARGON2ID (SYNC & ASYNC)
+// Memory-hard password hashing. `memory_kib` is the memory cost in KiB,
+// `iterations` the time cost and `parallelism` the number of lanes.
+// OWASP minimum: 19456 KiB, 2 iterations, 1 lane.
+// ============================================================================
+
+/// Salt length used for newly created PHC strings
+const ARGON2_SALT_LEN: usize = 16;
+
+/// Hash length used for newly created PHC strings
+const ARGON2_HASH_LEN: usize = 32;
+
+/// Largest Argon2 memory cost accepted (1 GiB, the scrypt limit). Stored
+/// PHC strings are untrusted input, and an allocation failure aborts the app.
+pub const ARGON2_MAX_MEMORY_KIB: u32 = 1 << 20;
+
+#[inline(always)]
+fn check_argon2_params(memory_kib: u32, output_len: usize) -> Result<(), CipherError> {
+    if memory_kib > ARGON2_MAX_MEMORY_KIB {
+        return Err(CipherError::InvalidParameter {
+            message: format!("argon2 memory must be at most {ARGON2_MAX_MEMORY_KIB} KiB"),
+        });
+    }
+    if output_len > PBKDF2_MAX_OUTPUT_LEN {
+        return Err(CipherError::InvalidParameter {
+            message: format!("output length must be at most {PBKDF2_MAX_OUTPUT_LEN}"),
+        });
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn argon2id_context(memory_kib: u32, iterations: u32, parallelism: u32, output_len: usize) -> Result<Argon2<'static>, CipherError> {
+    check_argon2_params(memory_kib, output_len)?;
+    let params = Params::new(memory_kib, iterations, parallelism, Some(output_len))
+        .map_err(|e| CipherError::InvalidParameter { message: e.to_string() })?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+fn argon2id_hash_password_internal(password: &[u8], memory_kib: u32, iterations: u32, parallelism: u32) -> Result<String, CipherError> {
+    let argon2 = argon2id_context(memory_kib, iterations, parallelism, ARGON2_HASH_LEN)?;
+
+    let mut salt_bytes = [0u8; ARGON2_SALT_LEN];
+    rand::rng().fill_bytes(&mut salt_bytes);
+    let salt = SaltString::encode_b64(&salt_bytes)
+        .map_err(|e| CipherError::InvalidParameter { message: e.to_string() })?;
+
+    argon2
+        .hash_password(password, &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| CipherError::InvalidParameter { message: e.to_string() })
+}
+
+fn argon2_verify_password_internal(password: &[u8], phc: &str) -> Result<bool, CipherError> {
+    let hash = PasswordHash::new(phc).map_err(|_| CipherError::InvalidFormat)?;
+
+    // Cost parameters and variant are read from the PHC string itself, so
+    // bound them before `verify_password` allocates
+    let params = Params::try_from(&hash).map_err(|_| CipherError::InvalidFormat)?;
+    check_argon2_params(params.m_cost(), params.output_len().unwrap_or(ARGON2_HASH_LEN))?;
+
+    match Argon2::default().verify_password(password, &hash) {
+        Ok(()) => Ok(true),
+        Err(argon2::password_hash::Error::Password) => Ok(false),
+        Err(_) => Err(CipherError::InvalidFormat),
+    }
+}
+
+fn argon2id_derive_key_internal(
+    password: &[u8],
+    salt: &[u8],
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    output_len: usize,
+) -> Result<Vec<u8>, CipherError> {
+    let argon2 = argon2id_context(memory_kib, iterations, parallelism, output_len)?;
+    let mut output = vec![0u8; output_len];
+    argon2
+        .hash_password_into(password, salt, &mut output)
+        .map_err(|e| CipherError::InvalidParameter { message: e.to_string() })?;
+    Ok(output)
+}
+
+/// Hash a password with a random salt and return a PHC string
+/// (`$argon2id$v=19$m=...,t=...,p=...$salt$hash`) for storage.
+#[flutter_rust_bridge::frb(sync)]
+pub fn argon2id_hash_password(password: Vec<u8>, memory_kib: u32, iterations: u32, parallelism: u32) -> Result<String, CipherError> {
+    argon2id_hash_password_internal(&password, memory_kib, iterations, parallelism)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn argon2id_hash_password_async(password: Vec<u8>, memory_kib: u32, iterations: u32, parallelism: u32) -> Result<String, CipherError> {
+    run_blocking(move || argon2id_hash_password_internal(&password, memory_kib, iterations, parallelism)).await
+}
+
+/// Check a password against a stored PHC string. Returns `Ok(false)` on a
+/// mismatch and an error only if the PHC string itself is malformed or asks
+/// for more than `ARGON2_MAX_MEMORY_KIB`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn argon2_verify_password(password: Vec<u8>, phc: String) -> Result<bool, CipherError> {
+    argon2_verify_password_internal(&password, &phc)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn argon2_verify_password_async(password: Vec<u8>, phc: String) -> Result<bool, CipherError> {
+    run_blocking(move || argon2_verify_password_internal(&password, &phc)).await
+}
+
+/// Derive raw key bytes with Argon2id (salt must be at least 8 bytes).
+#[flutter_rust_bridge::frb(sync)]
+pub fn argon2id_derive_key(
+    password: Vec<u8>,
+    salt: Vec<u8>,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    output_len: usize,
+) -> Result<Vec<u8>, CipherError> {
+    argon2id_derive_key_internal(&password, &salt, memory_kib, iterations, parallelism, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn argon2id_derive_key_async(
+    password: Vec<u8>,
+    salt: Vec<u8>,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+    output_len: usize,
+) -> Result<Vec<u8>, CipherError> {
+    run_blocking(move || {
+        argon2id_derive_key_internal(&password, &salt, memory_kib, iterations, parallelism, output_len)
+    })
+    .await
+}
+
+// ============================================================================
+// SCRYPT (SYNC & ASYNC)
+// N = 2^log_n, r = block size, p = parallelism (RFC 7914)
+// ============================================================================
+
+/// Largest scrypt working set accepted (`128 * r * (N + p)` bytes). Stored
+/// records are untrusted input, and an allocation failure aborts the app.
+pub const SCRYPT_MAX_MEMORY_BYTES: u64 = 1 << 30;
+
+fn check_scrypt_params(log_n: u8, r: u32, p: u32, output_len: usize) -> Result<(), CipherError> {
+    let memory = (log_n < 64).then(|| 128 * r as u128 * ((1u128 << log_n) + p as u128));
+    if memory.is_none_or(|memory| memory > SCRYPT_MAX_MEMORY_BYTES as u128) {
+        return Err(CipherError::InvalidParameter {
+            message: format!("scrypt memory must be at most {SCRYPT_MAX_MEMORY_BYTES} bytes"),
+        });
+    }
+    if output_len == 0 || output_len > PBKDF2_MAX_OUTPUT_LEN {
+        return Err(CipherError::InvalidParameter {
+            message: format!("output length must be 1..={PBKDF2_MAX_OUTPUT_LEN}"),
+        });
+    }
+    Ok(())
+}
+
+fn scrypt_derive_key_internal(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    check_scrypt_params(log_n, r, p, output_len)?;
+    // `Params` only accepts 10..=64 byte lengths, but the output buffer sets
+    // the real one, so legacy records with any dkLen still verify
+    let params = scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+        .map_err(|_| CipherError::InvalidParameter { message: "invalid scrypt parameters".to_string() })?;
+    let mut output = vec![0u8; output_len];
+    scrypt::scrypt(password, salt, &params, &mut output)
+        .map_err(|_| CipherError::InvalidParameter { message: "invalid scrypt output length".to_string() })?;
+    Ok(output)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn scrypt_derive_key(password: Vec<u8>, salt: Vec<u8>, log_n: u8, r: u32, p: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    scrypt_derive_key_internal(&password, &salt, log_n, r, p, output_len)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn scrypt_derive_key_async(password: Vec<u8>, salt: Vec<u8>, log_n: u8, r: u32, p: u32, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    run_blocking(move || scrypt_derive_key_internal(&password, &salt, log_n, r, p, output_len)).await
+}
+
+/// Re-derive with the stored parameters and compare against `expected`
+/// in constant time. The output length is taken from `expected`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn scrypt_verify(password: Vec<u8>, salt: Vec<u8>, log_n: u8, r: u32, p: u32, expected: Vec<u8>) -> Result<bool, CipherError> {
+    let derived = scrypt_derive_key_internal(&password, &salt, log_n, r, p, expected.len())?;
+    Ok(ct_eq_internal(&derived, &expected))
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn scrypt_verify_async(password: Vec<u8>, salt: Vec<u8>, log_n: u8, r: u32, p: u32, expected: Vec<u8>) -> Result<bool, CipherError> {
+    run_blocking(move || {
+        let derived = scrypt_derive_key_internal(&password, &salt, log_n, r, p, expected.len())?;
+        Ok(ct_eq_internal(&derived, &expected))
+    })
+    .await
+}
+
+// ============================================================================
+// BCRYPT (SYNC & ASYNC)
+// Produces `$2b$` hashes; verification also accepts `$2a$`, `$2x$` and `$2y$`.
+// Like every bcrypt implementation, only the first 72 password bytes count.
+// ============================================================================
+
+fn bcrypt_hash_internal(password: &[u8], cost: u32) -> Result<String, CipherError> {
+    let mut salt = [0u8; 16];
+    rand::rng().fill_bytes(&mut salt);
+
+    bcrypt::hash_with_salt(password, cost, salt)
+        .map(|parts| parts.format_for_version(bcrypt::Version::TwoB))
+        .map_err(|e| CipherError::InvalidParameter { message: e.to_string() })
+}
+
+fn bcrypt_verify_internal(password: &[u8], hash: &str) -> Result<bool, CipherError> {
+    bcrypt::verify(password, hash).map_err(|_| CipherError::InvalidFormat)
+}
+
+/// Hash a password with bcrypt at the given cost (4..=31, 12 is typical).
+#[flutter_rust_bridge::frb(sync)]
+pub fn bcrypt_hash(password: Vec<u8>, cost: u32) -> Result<String, CipherError> {
+    bcrypt_hash_internal(&password, cost)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn bcrypt_hash_async(password: Vec<u8>, cost: u32) -> Result<String, CipherError> {
+    run_blocking(move || bcrypt_hash_internal(&password, cost)).await
+}
+
+/// Check a password against a stored bcrypt hash. Returns `Ok(false)` on a
+/// mismatch and an error only if the hash string is malformed.
+#[flutter_rust_bridge::frb(sync)]
+pub fn bcrypt_verify(password: Vec<u8>, hash: String) -> Result<bool, CipherError> {
+    bcrypt_verify_internal(&password, &hash)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn bcrypt_verify_async(password: Vec<u8>, hash: String) -> Result<bool, CipherError> {
+    run_blocking(move || bcrypt_verify_internal(&password, &hash)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6070 PBKDF2-HMAC-SHA1 vectors (the 16,777,216-iteration case is omitted)
+    #[test]
+    fn pbkdf2_hmac_sha1_rfc6070() {
+        let cases: [(&[u8], &[u8], u32, &str); 5] = [
+            (b"password", b"salt", 1, "0c60c80f961f0e71f3a9b524af6012062fe037a6"),
+            (b"password", b"salt", 2, "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"),
+            (b"password", b"salt", 4096, "4b007901b765489abead49d926f721d065a429c1"),
+            (
+                b"passwordPASSWORDpassword",
+                b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+                4096,
+                "3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038",
+            ),
+            (b"pass\0word", b"sa\0lt", 4096, "56fa6aa75548099dcc37d7f03425e0c3"),
+        ];
+        for (password, salt, iterations, expected) in cases {
+            let expected = hex::decode(expected).unwrap();
+            let derived = pbkdf2_hmac_sha1(password.to_vec(), salt.to_vec(), iterations, expected.len()).unwrap();
+            assert_eq!(derived, expected);
+        }
+    }
+
+    #[test]
+    fn pbkdf2_rejects_bad_parameters() {
+        for (iterations, output_len) in [(0, 32), (1, 0), (1, PBKDF2_MAX_OUTPUT_LEN + 1), (1, usize::MAX)] {
+            let result = pbkdf2_hmac_sha256(b"pin".to_vec(), b"salt".to_vec(), iterations, output_len);
+            assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+        }
+        let longest = pbkdf2_hmac_sha512(b"pin".to_vec(), b"salt".to_vec(), 1, PBKDF2_MAX_OUTPUT_LEN).unwrap();
+        assert_eq!(longest.len(), PBKDF2_MAX_OUTPUT_LEN);
+    }
+
+    // RFC 5869 appendix A.1-A.3 (HKDF-SHA256)
+    #[test]
+    fn hkdf_sha256_rfc5869() {
+        let cases = [
+            (
+                vec![0x0b; 22],
+                (0x00..=0x0c).collect::<Vec<u8>>(),
+                (0xf0..=0xf9).collect::<Vec<u8>>(),
+                "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+                "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+            ),
+            (
+                (0x00..=0x4f).collect(),
+                (0x60..=0xaf).collect(),
+                (0xb0..=0xff).collect(),
+                "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+                "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c\
+                 59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71\
+                 cc30c58179ec3e87c14c01d5c1f3434f1d87",
+            ),
+            // Empty salt and empty info
+            (
+                vec![0x0b; 22],
+                Vec::new(),
+                Vec::new(),
+                "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+                "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+            ),
+        ];
+        for (ikm, salt, info, prk, okm) in cases {
+            let (prk, okm) = (hex::decode(prk).unwrap(), hex::decode(okm.replace(' ', "")).unwrap());
+            assert_eq!(hkdf_sha256_extract(salt.clone(), ikm.clone()).to_vec(), prk);
+            assert_eq!(hkdf_sha256_expand(prk.clone(), info.clone(), okm.len()).unwrap(), okm);
+            assert_eq!(hkdf_sha256(ikm, salt, info, okm.len()).unwrap(), okm);
+        }
+    }
+
+    #[test]
+    fn hkdf_rejects_oversized_output() {
+        let result = hkdf_sha256(vec![1; 32], Vec::new(), Vec::new(), 255 * 32 + 1);
+        assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+        assert!(hkdf_sha512(vec![1; 32], Vec::new(), Vec::new(), usize::MAX).is_err());
+    }
+
+    // RFC 9106 section 5.3; secret and associated data are not exposed to
+    // Dart, so this pins the Argon2id v1.3 backend that `argon2id_context` uses
+    #[test]
+    fn argon2id_rfc9106() {
+        let params = argon2::ParamsBuilder::new()
+            .m_cost(32)
+            .t_cost(3)
+            .p_cost(4)
+            .output_len(32)
+            .data(argon2::AssociatedData::new(&[0x04; 12]).unwrap())
+            .build()
+            .unwrap();
+        let argon2 = Argon2::new_with_secret(&[0x03; 8], Algorithm::Argon2id, Version::V0x13, params).unwrap();
+        let mut tag = [0u8; 32];
+        argon2.hash_password_into(&[0x01; 32], &[0x02; 16], &mut tag).unwrap();
+        assert_eq!(hex::encode(tag), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659");
+    }
+
+    // Reference implementation (phc-winner-argon2) Argon2id v1.3 vector
+    #[test]
+    fn argon2id_reference_vector() {
+        let derived = argon2id_derive_key(b"password".to_vec(), b"somesalt".to_vec(), 65536, 2, 1, 32).unwrap();
+        assert_eq!(hex::encode(derived), "09316115d5cf24ed5a15a31a3ba326e5cf32edc24702987c02b6566f61913cf7");
+
+        let phc = "$argon2id$v=19$m=65536,t=2,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc";
+        assert!(argon2_verify_password(b"password".to_vec(), phc.to_string()).unwrap());
+        assert!(!argon2_verify_password(b"Password".to_vec(), phc.to_string()).unwrap());
+    }
+
+    #[test]
+    fn argon2id_hash_then_verify() {
+        let phc = argon2id_hash_password(b"hunter2".to_vec(), 256, 2, 1).unwrap();
+        assert!(phc.starts_with("$argon2id$v=19$m=256,t=2,p=1$"));
+        assert!(argon2_verify_password(b"hunter2".to_vec(), phc.clone()).unwrap());
+        assert!(!argon2_verify_password(b"hunter3".to_vec(), phc).unwrap());
+
+        // Fresh salt every time
+        let again = argon2id_hash_password(b"hunter2".to_vec(), 256, 2, 1).unwrap();
+        assert_ne!(again, argon2id_hash_password(b"hunter2".to_vec(), 256, 2, 1).unwrap());
+
+        assert!(matches!(
+            argon2_verify_password(b"pw".to_vec(), "not a phc string".to_string()),
+            Err(CipherError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn argon2_rejects_oversized_parameters() {
+        let too_much = ARGON2_MAX_MEMORY_KIB + 1;
+        assert!(matches!(
+            argon2id_hash_password(b"pw".to_vec(), too_much, 1, 1),
+            Err(CipherError::InvalidParameter { .. })
+        ));
+        for (memory_kib, output_len) in [(too_much, 32), (u32::MAX, 32), (256, PBKDF2_MAX_OUTPUT_LEN + 1)] {
+            let result = argon2id_derive_key(b"pw".to_vec(), b"somesalt".to_vec(), memory_kib, 1, 1, output_len);
+            assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+        }
+
+        // A stored hash is checked before anything is allocated for it
+        let phc = format!("$argon2id$v=19$m={},t=1,p=1$c29tZXNhbHQ$CTFhFdXPJO1aFaMaO6Mm5c8y7cJHAph8ArZWb2GRPPc", u32::MAX);
+        assert!(matches!(
+            argon2_verify_password(b"password".to_vec(), phc),
+            Err(CipherError::InvalidParameter { .. })
+        ));
+    }
+
+    type ScryptCase = (&'static [u8], &'static [u8], u8, u32, u32, &'static str);
+
+    // RFC 7914 section 12 (the N = 2^20 case needs 1 GiB and is omitted)
+    #[test]
+    fn scrypt_rfc7914() {
+        let cases: [ScryptCase; 3] = [
+            (b"", b"", 4, 1, 1, "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+                                 fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"),
+            (b"password", b"NaCl", 10, 8, 16, "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+                                               2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"),
+            (b"pleaseletmein", b"SodiumChloride", 14, 8, 1, "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2\
+                                                             d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"),
+        ];
+        for (password, salt, log_n, r, p, expected) in cases {
+            let expected = hex::decode(expected).unwrap();
+            let derived = scrypt_derive_key(password.to_vec(), salt.to_vec(), log_n, r, p, 64).unwrap();
+            assert_eq!(derived, expected);
+            assert!(scrypt_verify(password.to_vec(), salt.to_vec(), log_n, r, p, expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn scrypt_accepts_any_output_length() {
+        // dkLen outside the 10..=64 that `scrypt::Params` allows, e.g. legacy records
+        let full = scrypt_derive_key(b"pw".to_vec(), b"salt".to_vec(), 4, 1, 1, 100).unwrap();
+        let short = scrypt_derive_key(b"pw".to_vec(), b"salt".to_vec(), 4, 1, 1, 8).unwrap();
+        assert_eq!(full.len(), 100);
+        assert_eq!(short, full[..8]);
+        assert!(scrypt_verify(b"pw".to_vec(), b"salt".to_vec(), 4, 1, 1, short).unwrap());
+    }
+
+    #[test]
+    fn scrypt_rejects_oversized_parameters() {
+        for (log_n, r, p, output_len) in [(30, 8, 1, 32), (20, 9, 1, 32), (63, u32::MAX, 1, 32), (200, 1, 1, 32), (4, 1, 1, 0)] {
+            let result = scrypt_derive_key(b"pw".to_vec(), b"salt".to_vec(), log_n, r, p, output_len);
+            assert!(matches!(result, Err(CipherError::InvalidParameter { .. })), "{log_n} {r} {p} {output_len}");
+        }
+        let result = scrypt_verify(b"pw".to_vec(), b"salt".to_vec(), 40, 8, 1, vec![0u8; 32]);
+        assert!(matches!(result, Err(CipherError::InvalidParameter { .. })));
+    }
+
+    // OpenBSD / John the Ripper bcrypt vectors; `$2b$` only differs from
+    // `$2a$` for passwords of 255+ bytes, so the same digests verify
+    #[test]
+    fn bcrypt_verifies_2a_and_2b_hashes() {
+        let cases: [(&[u8], &str); 2] = [
+            (b"U*U", "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"),
+            (b"U*U*", "$2a$05$CCCCCCCCCCCCCCCCCCCCC.VGOzA784oUp/Z0DY336zx7pLYAy0lwK"),
+        ];
+        for (password, hash) in cases {
+            assert!(bcrypt_verify(password.to_vec(), hash.to_string()).unwrap());
+            assert!(bcrypt_verify(password.to_vec(), hash.replacen("$2a$", "$2b$", 1)).unwrap());
+            assert!(!bcrypt_verify(b"wrong".to_vec(), hash.to_string()).unwrap());
+        }
+
+        let hash = bcrypt_hash(b"hunter2".to_vec(), 4).unwrap();
+        assert!(hash.starts_with("$2b$04$"));
+        assert!(bcrypt_verify(b"hunter2".to_vec(), hash.clone()).unwrap());
+        assert!(!bcrypt_verify(b"hunter3".to_vec(), hash).unwrap());
+        assert!(matches!(bcrypt_verify(b"pw".to_vec(), "$2b$nope".to_string()), Err(CipherError::InvalidFormat)));
+    }
+}
@@ -0,0 +1,195 @@
+// ============================================================================
+// AES KEY WRAP: AES-KW (RFC 3394) & AES-KWP (RFC 5649)
+// Wrap data-encryption keys under a key-encryption key (KEK) of 16, 24 or
+// 32 bytes. Output is interoperable with cloud KMS exports and JWE A*KW.
+//   AES-KW   input is a multiple of 8 bytes, at least 16; output is 8 longer
+//   AES-KWP  any non-empty input; padded to a multiple of 8, plus 8 bytes
+// A KEK of any other length is `InvalidKeyLength`, reporting 32 as expected.
+// ============================================================================
+
+use aes_kw::{KekAes128, KekAes192, KekAes256};
+
+use crate::CipherError;
+
+/// Block the wrapped key is processed in
+const SEMIBLOCK_LEN: usize = 8;
+/// Shortest AES-KW output: the integrity block plus two key semiblocks
+const KW_MIN_WRAPPED_LEN: usize = 3 * SEMIBLOCK_LEN;
+/// Shortest AES-KWP output: the integrity block plus one padded semiblock
+const KWP_MIN_WRAPPED_LEN: usize = 2 * SEMIBLOCK_LEN;
+
+/// Run `$body` with `$kek` bound to the `Kek` matching the key length
+macro_rules! with_kek {
+    ($key:expr, |$kek:ident| $body:expr) => {
+        match $key.len() {
+            16 => { let $kek = KekAes128::new($key.into()); $body }
+            24 => { let $kek = KekAes192::new($key.into()); $body }
+            32 => { let $kek = KekAes256::new($key.into()); $body }
+            actual => Err(CipherError::InvalidKeyLength { expected: 32, actual }),
+        }
+    };
+}
+
+#[inline(always)]
+fn unwrap_error(error: aes_kw::Error) -> CipherError {
+    match error {
+        aes_kw::Error::IntegrityCheckFailed => CipherError::AuthenticationFailed,
+        _ => CipherError::InvalidFormat,
+    }
+}
+
+pub(crate) fn aes_kw_wrap_internal(kek: &[u8], key: &[u8]) -> Result<Vec<u8>, CipherError> {
+    if key.len() < 2 * SEMIBLOCK_LEN || !key.len().is_multiple_of(SEMIBLOCK_LEN) {
+        return Err(CipherError::InvalidParameter {
+            message: "AES-KW input must be a multiple of 8 bytes and at least 16".to_string(),
+        });
+    }
+    with_kek!(kek, |k| k.wrap_vec(key).map_err(|_| CipherError::EncryptionFailed))
+}
+
+/// Unwrap with the RFC 3394 integrity check; a wrong KEK or tampered input
+/// is `AuthenticationFailed`
+pub(crate) fn aes_kw_unwrap_internal(kek: &[u8], wrapped: &[u8]) -> Result<Vec<u8>, CipherError> {
+    if wrapped.len() < KW_MIN_WRAPPED_LEN {
+        return Err(CipherError::CiphertextTooShort { minimum: KW_MIN_WRAPPED_LEN, actual: wrapped.len() });
+    }
+    with_kek!(kek, |k| k.unwrap_vec(wrapped).map_err(unwrap_error))
+}
+
+pub(crate) fn aes_kwp_wrap_internal(kek: &[u8], key: &[u8]) -> Result<Vec<u8>, CipherError> {
+    if key.is_empty() {
+        return Err(CipherError::InvalidParameter { message: "AES-KWP input must not be empty".to_string() });
+    }
+    with_kek!(kek, |k| k.wrap_with_padding_vec(key).map_err(|_| CipherError::EncryptionFailed))
+}
+
+pub(crate) fn aes_kwp_unwrap_internal(kek: &[u8], wrapped: &[u8]) -> Result<Vec<u8>, CipherError> {
+    if wrapped.len() < KWP_MIN_WRAPPED_LEN {
+        return Err(CipherError::CiphertextTooShort { minimum: KWP_MIN_WRAPPED_LEN, actual: wrapped.len() });
+    }
+    with_kek!(kek, |k| k.unwrap_with_padding_vec(wrapped).map_err(unwrap_error))
+}
+
+// ============================================================================
+// AES-KW (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_key_wrap(key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kw_wrap_internal(&kek, &key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_key_wrap_async(key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kw_wrap_internal(&kek, &key)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_key_unwrap(wrapped_key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kw_unwrap_internal(&kek, &wrapped_key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_key_unwrap_async(wrapped_key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kw_unwrap_internal(&kek, &wrapped_key)
+}
+
+// ============================================================================
+// AES-KWP (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_key_wrap_with_padding(key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kwp_wrap_internal(&kek, &key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_key_wrap_with_padding_async(key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kwp_wrap_internal(&kek, &key)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_key_unwrap_with_padding(wrapped_key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kwp_unwrap_internal(&kek, &wrapped_key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_key_unwrap_with_padding_async(wrapped_key: Vec<u8>, kek: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes_kwp_unwrap_internal(&kek, &wrapped_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(data: &str) -> Vec<u8> {
+        hex::decode(data).unwrap()
+    }
+
+    #[test]
+    fn rfc3394_vectors() {
+        // Section 4.1: 128-bit key under a 128-bit KEK
+        let kek = unhex("000102030405060708090A0B0C0D0E0F");
+        let key = unhex("00112233445566778899AABBCCDDEEFF");
+        let wrapped = unhex("1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5");
+        assert_eq!(aes_key_wrap(key.clone(), kek.clone()).unwrap(), wrapped);
+        assert_eq!(aes_key_unwrap(wrapped, kek).unwrap(), key);
+
+        // Section 4.6: 256-bit key under a 256-bit KEK
+        let kek = unhex("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+        let key = unhex("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F");
+        let wrapped = unhex("28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21");
+        assert_eq!(aes_key_wrap(key.clone(), kek.clone()).unwrap(), wrapped);
+        assert_eq!(aes_key_unwrap(wrapped, kek).unwrap(), key);
+    }
+
+    #[test]
+    fn rfc5649_vectors() {
+        let kek = unhex("5840df6e29b02af1ab493b705bf16ea1ae8338f4dcc176a8");
+
+        let key = unhex("c37b7e6492584340bed12207808941155068f738");
+        let wrapped = unhex("138bdeaa9b8fa7fc61f97742e72248ee5ae6ae5360d1ae6a5f54f373fa543b6a");
+        assert_eq!(aes_key_wrap_with_padding(key.clone(), kek.clone()).unwrap(), wrapped);
+        assert_eq!(aes_key_unwrap_with_padding(wrapped, kek.clone()).unwrap(), key);
+
+        let key = unhex("466f7250617369");
+        let wrapped = unhex("afbeb0f07dfbf5419200f2ccb50bb24f");
+        assert_eq!(aes_key_wrap_with_padding(key.clone(), kek.clone()).unwrap(), wrapped);
+        assert_eq!(aes_key_unwrap_with_padding(wrapped, kek).unwrap(), key);
+    }
+
+    #[test]
+    fn bad_lengths_are_rejected() {
+        let kek = vec![1u8; 16];
+        for key in [vec![], vec![2u8; 8], vec![2u8; 20]] {
+            assert!(matches!(aes_key_wrap(key, kek.clone()), Err(CipherError::InvalidParameter { .. })));
+        }
+        assert!(matches!(
+            aes_key_unwrap(vec![0xA6; 8], kek.clone()),
+            Err(CipherError::CiphertextTooShort { minimum: 24, actual: 8 })
+        ));
+        assert!(matches!(
+            aes_key_unwrap_with_padding(vec![0xA6; 8], kek.clone()),
+            Err(CipherError::CiphertextTooShort { minimum: 16, actual: 8 })
+        ));
+        assert!(matches!(
+            aes_key_wrap(vec![2u8; 16], vec![1u8; 20]),
+            Err(CipherError::InvalidKeyLength { expected: 32, actual: 20 })
+        ));
+        assert!(matches!(
+            aes_key_unwrap_with_padding(vec![0u8; 16], vec![1u8; 8]),
+            Err(CipherError::InvalidKeyLength { expected: 32, actual: 8 })
+        ));
+    }
+
+    #[test]
+    fn tampering_is_rejected() {
+        let kek = vec![1u8; 32];
+        let mut wrapped = aes_key_wrap(vec![2u8; 32], kek.clone()).unwrap();
+        wrapped[10] ^= 1;
+        assert!(matches!(aes_key_unwrap(wrapped, kek.clone()), Err(CipherError::AuthenticationFailed)));
+
+        let wrapped = aes_key_wrap(vec![2u8; 32], kek).unwrap();
+        assert!(matches!(aes_key_unwrap(wrapped, vec![3u8; 32]), Err(CipherError::AuthenticationFailed)));
+    }
+}
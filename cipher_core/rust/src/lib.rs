@@ -1,12 +1,36 @@
 mod frb_generated;
 
+pub mod aes_modes;
+pub mod batch;
+pub mod ecdsa;
+pub mod ed25519;
+pub mod encrypted_file;
+pub mod hash_algorithm;
+pub mod jwe;
+pub mod jws;
+pub mod kdf;
+pub mod key_wrap;
+mod midstate;
+pub mod rsa_crypto;
+pub mod siv;
+pub mod stream;
+pub mod x25519;
+
 use aes_gcm::{Aes256Gcm, Key, Nonce};
-use aes_gcm::aead::{Aead, Payload, KeyInit};
+use aes_gcm::aead::{Aead, AeadCore, Payload, KeyInit};
+use aes_gcm::aead::generic_array::typenum::Unsigned;
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
 use hmac::{Hmac, Mac};
 use md5::Md5;
 use sha1::Sha1;
 use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256, Digest};
+use sha3::{Keccak256, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256};
+use sha3::digest::{ExtendableOutput, XofReader};
 use rand::Rng;
+use subtle::ConstantTimeEq;
+
+use crate::hash_algorithm::HashAlgorithm;
+use crate::midstate::{Engine, HmacEngine};
 
 type HmacMd5 = Hmac<Md5>;
 type HmacSha1 = Hmac<Sha1>;
@@ -15,6 +39,82 @@ type HmacSha256 = Hmac<Sha256>;
 type HmacSha384 = Hmac<Sha384>;
 type HmacSha512 = Hmac<Sha512>;
 
+// ============================================================================
+// ERRORS
+// ============================================================================
+
+/// Error returned by every fallible cipher_core operation.
+/// Surfaced to Dart as an exception by flutter_rust_bridge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CipherError {
+    /// The key does not have the length the algorithm requires
+    InvalidKeyLength { expected: usize, actual: usize },
+    /// The key was rejected by the algorithm (malformed or unsupported)
+    InvalidKey,
+    /// The ciphertext is shorter than the nonce, header or tag it must contain
+    CiphertextTooShort { minimum: usize, actual: usize },
+    /// Authentication tag or MAC did not verify: wrong key, wrong AAD or tampering
+    AuthenticationFailed,
+    /// The cipher refused to encrypt (e.g. message too long for the nonce scheme)
+    EncryptionFailed,
+    /// A STREAM encryptor or decryptor ran out of chunk counters
+    StreamExhausted,
+    /// Encrypted data has an unknown magic, version or inconsistent layout
+    InvalidFormat,
+    /// The input string is not valid hex
+    InvalidHex,
+    /// Reading or writing a file failed
+    Io { message: String },
+    /// A parameter is out of range (iterations, output length, cost...)
+    InvalidParameter { message: String },
+    /// The algorithm name is unknown or not allowed here
+    UnsupportedAlgorithm { algorithm: String },
+    /// The token's `exp` claim is in the past (beyond the allowed leeway)
+    TokenExpired,
+    /// The token's `nbf` or `iat` claim is in the future (beyond the allowed leeway)
+    TokenNotYetValid,
+}
+
+impl std::fmt::Display for CipherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidKeyLength { expected, actual } => {
+                write!(f, "invalid key length: expected {expected} bytes, got {actual}")
+            }
+            Self::InvalidKey => write!(f, "invalid key"),
+            Self::CiphertextTooShort { minimum, actual } => {
+                write!(f, "ciphertext too short: need at least {minimum} bytes, got {actual}")
+            }
+            Self::AuthenticationFailed => write!(f, "authentication failed"),
+            Self::EncryptionFailed => write!(f, "encryption failed"),
+            Self::StreamExhausted => write!(f, "stream chunk counter exhausted"),
+            Self::InvalidFormat => write!(f, "invalid encrypted data format"),
+            Self::InvalidHex => write!(f, "invalid hex string"),
+            Self::Io { message } => write!(f, "i/o error: {message}"),
+            Self::InvalidParameter { message } => write!(f, "invalid parameter: {message}"),
+            Self::UnsupportedAlgorithm { algorithm } => write!(f, "unsupported algorithm: {algorithm}"),
+            Self::TokenExpired => write!(f, "token expired"),
+            Self::TokenNotYetValid => write!(f, "token not yet valid"),
+        }
+    }
+}
+
+impl std::error::Error for CipherError {}
+
+impl From<std::io::Error> for CipherError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io { message: err.to_string() }
+    }
+}
+
+#[inline(always)]
+fn check_key_len(key: &[u8], expected: usize) -> Result<(), CipherError> {
+    if key.len() != expected {
+        return Err(CipherError::InvalidKeyLength { expected, actual: key.len() });
+    }
+    Ok(())
+}
+
 // ============================================================================
 // INTERNAL HELPER FUNCTIONS (for zero-copy operations)
 // ============================================================================
@@ -75,6 +175,163 @@ fn sha512_224_internal(data: &[u8]) -> [u8; 28] {
     hasher.finalize().into()
 }
 
+#[inline(always)]
+fn sha3_224_internal(data: &[u8]) -> [u8; 28] {
+    let mut hasher = Sha3_224::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+#[inline(always)]
+fn sha3_256_internal(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+#[inline(always)]
+fn sha3_384_internal(data: &[u8]) -> [u8; 48] {
+    let mut hasher = Sha3_384::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+#[inline(always)]
+fn sha3_512_internal(data: &[u8]) -> [u8; 64] {
+    let mut hasher = Sha3_512::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+#[inline(always)]
+fn keccak256_internal(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Longest SHAKE output accepted; the length comes straight from Dart and
+/// is allocated up front
+pub const SHAKE_MAX_OUTPUT_LEN: usize = 1 << 20;
+
+#[inline(always)]
+fn check_shake_output_len(output_len: usize) -> Result<(), CipherError> {
+    if output_len == 0 || output_len > SHAKE_MAX_OUTPUT_LEN {
+        return Err(CipherError::InvalidParameter {
+            message: format!("output length must be 1..={SHAKE_MAX_OUTPUT_LEN}"),
+        });
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn shake128_internal(data: &[u8], output_len: usize) -> Vec<u8> {
+    let mut hasher = Shake128::default();
+    sha3::digest::Update::update(&mut hasher, data);
+    let mut output = vec![0u8; output_len];
+    hasher.finalize_xof().read(&mut output);
+    output
+}
+
+#[inline(always)]
+fn shake256_internal(data: &[u8], output_len: usize) -> Vec<u8> {
+    let mut hasher = Shake256::default();
+    sha3::digest::Update::update(&mut hasher, data);
+    let mut output = vec![0u8; output_len];
+    hasher.finalize_xof().read(&mut output);
+    output
+}
+
+/// Inputs at least this large are hashed by BLAKE3 on multiple threads
+const BLAKE3_PARALLEL_THRESHOLD: usize = 128 * 1024;
+
+#[inline(always)]
+fn blake2b_internal(data: &[u8]) -> [u8; 64] {
+    let mut output = [0u8; 64];
+    output.copy_from_slice(blake2b_simd::blake2b(data).as_bytes());
+    output
+}
+
+#[inline(always)]
+fn blake2s_internal(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    output.copy_from_slice(blake2s_simd::blake2s(data).as_bytes());
+    output
+}
+
+/// BLAKE2 parameters for an optional key (empty = unkeyed) and output length
+fn blake2b_params_internal(key: &[u8], output_len: usize) -> Result<blake2b_simd::Params, CipherError> {
+    if !(1..=blake2b_simd::OUTBYTES).contains(&output_len) {
+        return Err(CipherError::InvalidParameter { message: format!("BLAKE2b output length must be 1..={}", blake2b_simd::OUTBYTES) });
+    }
+    if key.len() > blake2b_simd::KEYBYTES {
+        return Err(CipherError::InvalidParameter { message: format!("BLAKE2b key must be at most {} bytes", blake2b_simd::KEYBYTES) });
+    }
+    let mut params = blake2b_simd::Params::new();
+    params.hash_length(output_len).key(key);
+    Ok(params)
+}
+
+fn blake2s_params_internal(key: &[u8], output_len: usize) -> Result<blake2s_simd::Params, CipherError> {
+    if !(1..=blake2s_simd::OUTBYTES).contains(&output_len) {
+        return Err(CipherError::InvalidParameter { message: format!("BLAKE2s output length must be 1..={}", blake2s_simd::OUTBYTES) });
+    }
+    if key.len() > blake2s_simd::KEYBYTES {
+        return Err(CipherError::InvalidParameter { message: format!("BLAKE2s key must be at most {} bytes", blake2s_simd::KEYBYTES) });
+    }
+    let mut params = blake2s_simd::Params::new();
+    params.hash_length(output_len).key(key);
+    Ok(params)
+}
+
+#[inline(always)]
+fn blake2b_keyed_internal(key: &[u8], data: &[u8], output_len: usize) -> Result<Vec<u8>, CipherError> {
+    Ok(blake2b_params_internal(key, output_len)?.hash(data).as_bytes().to_vec())
+}
+
+#[inline(always)]
+fn blake2s_keyed_internal(key: &[u8], data: &[u8], output_len: usize) -> Result<Vec<u8>, CipherError> {
+    Ok(blake2s_params_internal(key, output_len)?.hash(data).as_bytes().to_vec())
+}
+
+#[inline(always)]
+fn blake3_update_internal(hasher: &mut blake3::Hasher, data: &[u8]) {
+    if data.len() >= BLAKE3_PARALLEL_THRESHOLD {
+        hasher.update_rayon(data);
+    } else {
+        hasher.update(data);
+    }
+}
+
+#[inline(always)]
+fn blake3_internal(data: &[u8]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    blake3_update_internal(&mut hasher, data);
+    hasher.finalize().into()
+}
+
+#[inline(always)]
+fn blake3_key_internal(key: &[u8]) -> Result<[u8; 32], CipherError> {
+    check_key_len(key, blake3::KEY_LEN)?;
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(key);
+    Ok(bytes)
+}
+
+#[inline(always)]
+fn blake3_keyed_internal(key: &[u8], data: &[u8]) -> Result<[u8; 32], CipherError> {
+    let mut hasher = blake3::Hasher::new_keyed(&blake3_key_internal(key)?);
+    blake3_update_internal(&mut hasher, data);
+    Ok(hasher.finalize().into())
+}
+
+#[inline(always)]
+fn blake3_derive_key_internal(context: &str, key_material: &[u8]) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new_derive_key(context);
+    blake3_update_internal(&mut hasher, key_material);
+    hasher.finalize().into()
+}
+
 #[inline(always)]
 fn hmac_sha256_internal(key: &[u8], data: &[u8]) -> [u8; 32] {
     let mut mac = <HmacSha256 as Mac>::new_from_slice(key).unwrap();
@@ -110,6 +367,24 @@ fn hmac_sha224_internal(key: &[u8], data: &[u8]) -> [u8; 28] {
     mac.finalize().into_bytes().into()
 }
 
+/// Run CPU-heavy work on the blocking thread pool so async callers
+/// don't stall the runtime's worker threads
+async fn run_blocking<R, F>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    flutter_rust_bridge::spawn_blocking_with(f, frb_generated::FLUTTER_RUST_BRIDGE_HANDLER.thread_pool())
+        .await
+        .expect("blocking task panicked")
+}
+
+/// Constant-time comparison; only the lengths are compared in variable time
+#[inline(always)]
+fn ct_eq_internal(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
 #[inline(always)]
 fn hmac_md5_internal(key: &[u8], data: &[u8]) -> [u8; 16] {
     let mut mac = <HmacMd5 as Mac>::new_from_slice(key).unwrap();
@@ -117,6 +392,43 @@ fn hmac_md5_internal(key: &[u8], data: &[u8]) -> [u8; 16] {
     mac.finalize().into_bytes().into()
 }
 
+/// Encrypt with any AEAD, generating a random nonce and prepending it.
+/// `aad` is authenticated but not encrypted (pass `&[]` for none).
+#[inline(always)]
+fn aead_encrypt_internal<A: Aead + AeadCore + KeyInit>(key: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_key_len(key, A::key_size())?;
+    let cipher = A::new_from_slice(key).map_err(|_| CipherError::InvalidKey)?;
+
+    let mut nonce = aes_gcm::aead::Nonce::<A>::default();
+    rand::rng().fill_bytes(&mut nonce);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, Payload { msg: plaintext, aad })
+        .map_err(|_| CipherError::EncryptionFailed)?;
+
+    let mut result = nonce.to_vec();
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+/// Decrypt a nonce-prepended AEAD ciphertext; `aad` must match encryption
+#[inline(always)]
+fn aead_decrypt_internal<A: Aead + AeadCore + KeyInit>(key: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_key_len(key, A::key_size())?;
+    let cipher = A::new_from_slice(key).map_err(|_| CipherError::InvalidKey)?;
+
+    let nonce_len = aes_gcm::aead::Nonce::<A>::default().len();
+    let minimum = nonce_len + <A as AeadCore>::TagSize::USIZE;
+    if ciphertext.len() < minimum {
+        return Err(CipherError::CiphertextTooShort { minimum, actual: ciphertext.len() });
+    }
+    let (nonce, body) = ciphertext.split_at(nonce_len);
+
+    cipher
+        .decrypt(nonce.into(), Payload { msg: body, aad })
+        .map_err(|_| CipherError::AuthenticationFailed)
+}
+
 // ============================================================================
 // SHA-256 (SYNC & ASYNC)
 // ============================================================================
@@ -238,297 +550,1428 @@ pub async fn sha512_224_async(data: Vec<u8>) -> [u8; 28] {
 }
 
 // ============================================================================
-// HMAC-SHA256 (SYNC & ASYNC)
+// SHA3-224 (SYNC & ASYNC)
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn hmac_sha256(key: Vec<u8>, data: Vec<u8>) -> [u8; 32] {
-    hmac_sha256_internal(&key, &data)
+pub fn sha3_224(data: Vec<u8>) -> [u8; 28] {
+    sha3_224_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha256_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 32] {
-    hmac_sha256_internal(&key, &data)
+pub async fn sha3_224_async(data: Vec<u8>) -> [u8; 28] {
+    sha3_224_internal(&data)
 }
 
 // ============================================================================
-// HMAC-SHA512 (SYNC & ASYNC)
+// SHA3-256 (SYNC & ASYNC)
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn hmac_sha512(key: Vec<u8>, data: Vec<u8>) -> [u8; 64] {
-    hmac_sha512_internal(&key, &data)
+pub fn sha3_256(data: Vec<u8>) -> [u8; 32] {
+    sha3_256_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha512_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 64] {
-    hmac_sha512_internal(&key, &data)
+pub async fn sha3_256_async(data: Vec<u8>) -> [u8; 32] {
+    sha3_256_internal(&data)
 }
 
 // ============================================================================
-// HMAC-SHA1 (SYNC & ASYNC)
+// SHA3-384 (SYNC & ASYNC)
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn hmac_sha1(key: Vec<u8>, data: Vec<u8>) -> [u8; 20] {
-    hmac_sha1_internal(&key, &data)
+pub fn sha3_384(data: Vec<u8>) -> [u8; 48] {
+    sha3_384_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha1_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 20] {
-    hmac_sha1_internal(&key, &data)
+pub async fn sha3_384_async(data: Vec<u8>) -> [u8; 48] {
+    sha3_384_internal(&data)
 }
 
 // ============================================================================
-// HMAC-SHA384 (SYNC & ASYNC)
+// SHA3-512 (SYNC & ASYNC)
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn hmac_sha384(key: Vec<u8>, data: Vec<u8>) -> [u8; 48] {
-    hmac_sha384_internal(&key, &data)
+pub fn sha3_512(data: Vec<u8>) -> [u8; 64] {
+    sha3_512_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha384_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 48] {
-    hmac_sha384_internal(&key, &data)
+pub async fn sha3_512_async(data: Vec<u8>) -> [u8; 64] {
+    sha3_512_internal(&data)
 }
 
 // ============================================================================
-// HMAC-SHA224 (SYNC & ASYNC)
+// KECCAK-256 (SYNC & ASYNC)
+// Original Keccak padding as used by Ethereum; NOT the same as SHA3-256
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn hmac_sha224(key: Vec<u8>, data: Vec<u8>) -> [u8; 28] {
-    hmac_sha224_internal(&key, &data)
+pub fn keccak256(data: Vec<u8>) -> [u8; 32] {
+    keccak256_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha224_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 28] {
-    hmac_sha224_internal(&key, &data)
+pub async fn keccak256_async(data: Vec<u8>) -> [u8; 32] {
+    keccak256_internal(&data)
 }
 
 // ============================================================================
-// HMAC-MD5 (SYNC & ASYNC)
+// SHAKE128 (SYNC & ASYNC)
+// Extendable output: the caller picks the digest length
+// (1..=SHAKE_MAX_OUTPUT_LEN)
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn hmac_md5(key: Vec<u8>, data: Vec<u8>) -> [u8; 16] {
-    hmac_md5_internal(&key, &data)
+pub fn shake128(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(shake128_internal(&data, output_len))
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_md5_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 16] {
-    hmac_md5_internal(&key, &data)
+pub async fn shake128_async(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(shake128_internal(&data, output_len))
 }
 
 // ============================================================================
-// AES-256-GCM ENCRYPTION (SYNC & ASYNC)
-// Nonce is automatically generated and prepended to ciphertext
+// SHAKE256 (SYNC & ASYNC)
+// Extendable output: the caller picks the digest length
+// (1..=SHAKE_MAX_OUTPUT_LEN)
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn aes256_encrypt(plaintext: Vec<u8>, key: Vec<u8>) -> Option<Vec<u8>> {
-    if key.len() != 32 { return None; }
-
-    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
-
-    // Generate random nonce (CRITICAL for security)
-    let mut nonce_bytes = [0u8; 12];
-    rand::rng().fill_bytes(&mut nonce_bytes);
-    let nonce = Nonce::from_slice(&nonce_bytes);
-
-    // Encrypt
-    let ciphertext = cipher.encrypt(nonce, Payload::from(&plaintext[..])).ok()?;
-
-    // Prepend nonce to ciphertext so decryption can extract it
-    let mut result = nonce_bytes.to_vec();
-    result.extend_from_slice(&ciphertext);
-    Some(result)
+pub fn shake256(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(shake256_internal(&data, output_len))
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn aes256_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>) -> Option<Vec<u8>> {
-    aes256_encrypt(plaintext, key)
+pub async fn shake256_async(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(shake256_internal(&data, output_len))
 }
 
 // ============================================================================
-// AES-256-GCM DECRYPTION (SYNC & ASYNC)
-// Nonce is extracted from first 12 bytes of ciphertext
+// BLAKE2b (SYNC & ASYNC)
+// `_with_len` picks the digest length (1..=64); `_keyed` takes a key of up
+// to 64 bytes and is a MAC on its own, no HMAC construction needed
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn aes256_decrypt(ciphertext: Vec<u8>, key: Vec<u8>) -> Option<Vec<u8>> {
-    if key.len() != 32 || ciphertext.len() < 12 { return None; }
-
-    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+pub fn blake2b(data: Vec<u8>) -> [u8; 64] {
+    blake2b_internal(&data)
+}
 
-    // Extract nonce from ciphertext (first 12 bytes)
-    let nonce = Nonce::from_slice(&ciphertext[..12]);
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn blake2b_async(data: Vec<u8>) -> [u8; 64] {
+    blake2b_internal(&data)
+}
 
-    // Decrypt remaining bytes
-    cipher.decrypt(nonce, Payload::from(&ciphertext[12..])).ok()
+#[flutter_rust_bridge::frb(sync)]
+pub fn blake2b_with_len(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2b_keyed_internal(&[], &data, output_len)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn aes256_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>) -> Option<Vec<u8>> {
-    aes256_decrypt(ciphertext, key)
+pub async fn blake2b_with_len_async(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2b_keyed_internal(&[], &data, output_len)
 }
 
-// ============================================================================
-// BATCH OPERATIONS (SYNC & ASYNC)
-// ============================================================================
-
 #[flutter_rust_bridge::frb(sync)]
-pub fn sha256_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
-    inputs.into_iter().map(|input| sha256_internal(&input)).collect()
+pub fn blake2b_keyed(key: Vec<u8>, data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2b_keyed_internal(&key, &data, output_len)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn sha256_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
-    inputs.into_iter().map(|input| sha256_internal(&input)).collect()
+pub async fn blake2b_keyed_async(key: Vec<u8>, data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2b_keyed_internal(&key, &data, output_len)
 }
 
+// ============================================================================
+// BLAKE2s (SYNC & ASYNC)
+// `_with_len` picks the digest length (1..=32); `_keyed` takes a key of up
+// to 32 bytes and is a MAC on its own, no HMAC construction needed
+// ============================================================================
+
 #[flutter_rust_bridge::frb(sync)]
-pub fn sha512_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
-    inputs.into_iter().map(|input| sha512_internal(&input)).collect()
+#[inline(always)]
+pub fn blake2s(data: Vec<u8>) -> [u8; 32] {
+    blake2s_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn sha512_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
-    inputs.into_iter().map(|input| sha512_internal(&input)).collect()
+pub async fn blake2s_async(data: Vec<u8>) -> [u8; 32] {
+    blake2s_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn hmac_sha256_batch(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
-    // OPTIMIZED: No key cloning
-    messages.into_iter()
-        .map(|msg| hmac_sha256_internal(&key, &msg))
-        .collect()
+pub fn blake2s_with_len(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2s_keyed_internal(&[], &data, output_len)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha256_batch_async(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
-    // OPTIMIZED: No key cloning
-    messages.into_iter()
-        .map(|msg| hmac_sha256_internal(&key, &msg))
-        .collect()
+pub async fn blake2s_with_len_async(data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2s_keyed_internal(&[], &data, output_len)
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn hmac_sha512_batch(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
-    messages.into_iter()
-        .map(|msg| hmac_sha512_internal(&key, &msg))
-        .collect()
+pub fn blake2s_keyed(key: Vec<u8>, data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2s_keyed_internal(&key, &data, output_len)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hmac_sha512_batch_async(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
-    messages.into_iter()
-        .map(|msg| hmac_sha512_internal(&key, &msg))
-        .collect()
+pub async fn blake2s_keyed_async(key: Vec<u8>, data: Vec<u8>, output_len: usize) -> Result<Vec<u8>, CipherError> {
+    blake2s_keyed_internal(&key, &data, output_len)
 }
 
 // ============================================================================
-// COMBINED OPERATIONS (SYNC & ASYNC)
+// BLAKE3 (SYNC & ASYNC)
+// Inputs of 128 KiB or more are hashed on multiple threads.
+// `_keyed` takes a 32-byte key (a MAC); `_derive_key` takes a hardcoded,
+// globally unique context string such as "myapp 2024-01-01 session keys"
 // ============================================================================
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn hash_then_encrypt(data: Vec<u8>, key: Vec<u8>) -> Option<Vec<u8>> {
-    let hash = sha256_internal(&data);
-    aes256_encrypt(hash.to_vec(), key)
+#[inline(always)]
+pub fn blake3(data: Vec<u8>) -> [u8; 32] {
+    blake3_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn hash_then_encrypt_async(data: Vec<u8>, key: Vec<u8>) -> Option<Vec<u8>> {
-    let hash = sha256_internal(&data);
-    aes256_encrypt(hash.to_vec(), key)
+pub async fn blake3_async(data: Vec<u8>) -> [u8; 32] {
+    blake3_internal(&data)
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn encrypt_then_hmac(
-    plaintext: Vec<u8>,
-    enc_key: Vec<u8>,
-    mac_key: Vec<u8>,
-) -> Option<(Vec<u8>, [u8; 32])> {
-    let ciphertext = aes256_encrypt(plaintext, enc_key)?;
-    let mac = hmac_sha256_internal(&mac_key, &ciphertext);
-    Some((ciphertext, mac))
+pub fn blake3_keyed(key: Vec<u8>, data: Vec<u8>) -> Result<[u8; 32], CipherError> {
+    blake3_keyed_internal(&key, &data)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn encrypt_then_hmac_async(
-    plaintext: Vec<u8>,
-    enc_key: Vec<u8>,
-    mac_key: Vec<u8>,
-) -> Option<(Vec<u8>, [u8; 32])> {
-    let ciphertext = aes256_encrypt(plaintext, enc_key)?;
-    let mac = hmac_sha256_internal(&mac_key, &ciphertext);
-    Some((ciphertext, mac))
+pub async fn blake3_keyed_async(key: Vec<u8>, data: Vec<u8>) -> Result<[u8; 32], CipherError> {
+    blake3_keyed_internal(&key, &data)
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn verify_hmac_then_decrypt(
-    ciphertext: Vec<u8>,
-    mac: Vec<u8>,
-    enc_key: Vec<u8>,
-    mac_key: Vec<u8>,
-) -> Option<Vec<u8>> {
-    // OPTIMIZED: No unnecessary clones
-    let computed = hmac_sha256_internal(&mac_key, &ciphertext);
-    if computed.as_slice() != mac.as_slice() { return None; }
-    aes256_decrypt(ciphertext, enc_key)
+pub fn blake3_derive_key(context: String, key_material: Vec<u8>) -> [u8; 32] {
+    blake3_derive_key_internal(&context, &key_material)
 }
 
 #[flutter_rust_bridge::frb(dart_async)]
-pub async fn verify_hmac_then_decrypt_async(
-    ciphertext: Vec<u8>,
-    mac: Vec<u8>,
-    enc_key: Vec<u8>,
-    mac_key: Vec<u8>,
-) -> Option<Vec<u8>> {
-    let computed = hmac_sha256_internal(&mac_key, &ciphertext);
-    if computed.as_slice() != mac.as_slice() { return None; }
-    aes256_decrypt(ciphertext, enc_key)
+pub async fn blake3_derive_key_async(context: String, key_material: Vec<u8>) -> [u8; 32] {
+    blake3_derive_key_internal(&context, &key_material)
 }
 
 // ============================================================================
-// STATEFUL HASHERS (SYNC ONLY - required by Flutter)
+// HMAC-SHA256 (SYNC & ASYNC)
 // ============================================================================
 
-pub struct Sha256Hasher { inner: Sha256 }
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn hmac_sha256(key: Vec<u8>, data: Vec<u8>) -> [u8; 32] {
+    hmac_sha256_internal(&key, &data)
+}
 
-impl Sha256Hasher {
-    #[flutter_rust_bridge::frb(sync)]
-    pub fn new() -> Self {
-        Self { inner: Sha256::new() }
-    }
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha256_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 32] {
+    hmac_sha256_internal(&key, &data)
+}
 
-    #[flutter_rust_bridge::frb(sync)]
-    pub fn update(&mut self, data: Vec<u8>) {
-        self.inner.update(&data);
-    }
+// ============================================================================
+// HMAC-SHA512 (SYNC & ASYNC)
+// ============================================================================
 
-    #[flutter_rust_bridge::frb(sync)]
-    pub fn finalize(self) -> [u8; 32] {
-        self.inner.finalize().into()
-    }
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn hmac_sha512(key: Vec<u8>, data: Vec<u8>) -> [u8; 64] {
+    hmac_sha512_internal(&key, &data)
 }
 
-pub struct Sha512Hasher { inner: Sha512 }
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha512_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 64] {
+    hmac_sha512_internal(&key, &data)
+}
 
-impl Sha512Hasher {
-    #[flutter_rust_bridge::frb(sync)]
-    pub fn new() -> Self {
-        Self { inner: Sha512::new() }
-    }
+// ============================================================================
+// HMAC-SHA1 (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn hmac_sha1(key: Vec<u8>, data: Vec<u8>) -> [u8; 20] {
+    hmac_sha1_internal(&key, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha1_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 20] {
+    hmac_sha1_internal(&key, &data)
+}
+
+// ============================================================================
+// HMAC-SHA384 (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn hmac_sha384(key: Vec<u8>, data: Vec<u8>) -> [u8; 48] {
+    hmac_sha384_internal(&key, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha384_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 48] {
+    hmac_sha384_internal(&key, &data)
+}
+
+// ============================================================================
+// HMAC-SHA224 (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn hmac_sha224(key: Vec<u8>, data: Vec<u8>) -> [u8; 28] {
+    hmac_sha224_internal(&key, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha224_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 28] {
+    hmac_sha224_internal(&key, &data)
+}
+
+// ============================================================================
+// HMAC-MD5 (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn hmac_md5(key: Vec<u8>, data: Vec<u8>) -> [u8; 16] {
+    hmac_md5_internal(&key, &data)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_md5_async(key: Vec<u8>, data: Vec<u8>) -> [u8; 16] {
+    hmac_md5_internal(&key, &data)
+}
+
+// ============================================================================
+// CONSTANT-TIME MAC VERIFICATION (SYNC & ASYNC)
+// Never compare tags with `==`: it returns at the first differing byte
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn constant_time_eq(a: Vec<u8>, b: Vec<u8>) -> bool {
+    ct_eq_internal(&a, &b)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_sha256(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha256_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_sha256_async(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha256_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_sha512(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha512_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_sha512_async(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha512_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_sha1(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha1_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_sha1_async(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha1_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_sha384(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha384_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_sha384_async(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha384_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_sha224(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha224_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_sha224_async(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_sha224_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_md5(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_md5_internal(&key, &data), &mac)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_md5_async(key: Vec<u8>, data: Vec<u8>, mac: Vec<u8>) -> bool {
+    ct_eq_internal(&hmac_md5_internal(&key, &data), &mac)
+}
+
+// ============================================================================
+// AES-256-GCM ENCRYPTION (SYNC & ASYNC)
+// Nonce is automatically generated and prepended to ciphertext
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn aes256_encrypt(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    check_key_len(&key, 32)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+
+    // Generate random nonce (CRITICAL for security)
+    let mut nonce_bytes = [0u8; 12];
+    rand::rng().fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    // Encrypt
+    let ciphertext = cipher
+        .encrypt(nonce, Payload::from(&plaintext[..]))
+        .map_err(|_| CipherError::EncryptionFailed)?;
+
+    // Prepend nonce to ciphertext so decryption can extract it
+    let mut result = nonce_bytes.to_vec();
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes256_encrypt(plaintext, key)
+}
+
+// ============================================================================
+// AES-256-GCM DECRYPTION (SYNC & ASYNC)
+// Nonce is extracted from first 12 bytes of ciphertext
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn aes256_decrypt(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    check_key_len(&key, 32)?;
+    if ciphertext.len() < 12 + 16 {
+        return Err(CipherError::CiphertextTooShort { minimum: 12 + 16, actual: ciphertext.len() });
+    }
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+
+    // Extract nonce from ciphertext (first 12 bytes)
+    let nonce = Nonce::from_slice(&ciphertext[..12]);
+
+    // Decrypt remaining bytes
+    cipher
+        .decrypt(nonce, Payload::from(&ciphertext[12..]))
+        .map_err(|_| CipherError::AuthenticationFailed)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aes256_decrypt(ciphertext, key)
+}
+
+// ============================================================================
+// AES-256-GCM WITH ASSOCIATED DATA (SYNC & ASYNC)
+// `aad` (record ID, user ID, header...) is authenticated but not encrypted,
+// so a ciphertext only decrypts under the exact same `aad`
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn aes256_encrypt_with_aad(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<Aes256Gcm>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_encrypt_with_aad_async(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<Aes256Gcm>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn aes256_decrypt_with_aad(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<Aes256Gcm>(&key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_decrypt_with_aad_async(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<Aes256Gcm>(&key, &ciphertext, &aad)
+}
+
+// ============================================================================
+// CHACHA20-POLY1305 (SYNC & ASYNC)
+// 12-byte nonce is automatically generated and prepended to ciphertext
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn chacha20_poly1305_encrypt(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<ChaCha20Poly1305>(&key, &plaintext, &[])
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn chacha20_poly1305_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<ChaCha20Poly1305>(&key, &plaintext, &[])
+}
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn chacha20_poly1305_decrypt(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<ChaCha20Poly1305>(&key, &ciphertext, &[])
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn chacha20_poly1305_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<ChaCha20Poly1305>(&key, &ciphertext, &[])
+}
+
+// ============================================================================
+// CHACHA20-POLY1305 WITH ASSOCIATED DATA (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn chacha20_poly1305_encrypt_with_aad(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<ChaCha20Poly1305>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn chacha20_poly1305_encrypt_with_aad_async(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<ChaCha20Poly1305>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn chacha20_poly1305_decrypt_with_aad(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<ChaCha20Poly1305>(&key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn chacha20_poly1305_decrypt_with_aad_async(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<ChaCha20Poly1305>(&key, &ciphertext, &aad)
+}
+
+// ============================================================================
+// XCHACHA20-POLY1305 (SYNC & ASYNC)
+// 24-byte nonce is random-safe even for very high message volumes per key
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn xchacha20_poly1305_encrypt(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<XChaCha20Poly1305>(&key, &plaintext, &[])
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn xchacha20_poly1305_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<XChaCha20Poly1305>(&key, &plaintext, &[])
+}
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn xchacha20_poly1305_decrypt(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<XChaCha20Poly1305>(&key, &ciphertext, &[])
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn xchacha20_poly1305_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<XChaCha20Poly1305>(&key, &ciphertext, &[])
+}
+
+// ============================================================================
+// XCHACHA20-POLY1305 WITH ASSOCIATED DATA (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn xchacha20_poly1305_encrypt_with_aad(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<XChaCha20Poly1305>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn xchacha20_poly1305_encrypt_with_aad_async(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<XChaCha20Poly1305>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+#[inline(always)]
+pub fn xchacha20_poly1305_decrypt_with_aad(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<XChaCha20Poly1305>(&key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn xchacha20_poly1305_decrypt_with_aad_async(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<XChaCha20Poly1305>(&key, &ciphertext, &aad)
+}
+
+// ============================================================================
+// BATCH OPERATIONS (SYNC & ASYNC)
+// Inputs fan out over the batch worker pool (see `batch`); HMAC batches set
+// the key up once. `batch::hash_batch` / `hmac_batch` cover every algorithm
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn sha256_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    batch::par_map_internal(&inputs, sha256_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn sha256_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    run_blocking(move || batch::par_map_internal(&inputs, sha256_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn sha512_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    batch::par_map_internal(&inputs, sha512_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn sha512_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    run_blocking(move || batch::par_map_internal(&inputs, sha512_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn sha3_224_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 28]> {
+    batch::par_map_internal(&inputs, sha3_224_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn sha3_224_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 28]> {
+    run_blocking(move || batch::par_map_internal(&inputs, sha3_224_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn sha3_256_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    batch::par_map_internal(&inputs, sha3_256_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn sha3_256_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    run_blocking(move || batch::par_map_internal(&inputs, sha3_256_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn sha3_384_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 48]> {
+    batch::par_map_internal(&inputs, sha3_384_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn sha3_384_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 48]> {
+    run_blocking(move || batch::par_map_internal(&inputs, sha3_384_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn sha3_512_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    batch::par_map_internal(&inputs, sha3_512_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn sha3_512_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    run_blocking(move || batch::par_map_internal(&inputs, sha3_512_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn keccak256_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    batch::par_map_internal(&inputs, keccak256_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn keccak256_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    run_blocking(move || batch::par_map_internal(&inputs, keccak256_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn shake128_batch(inputs: Vec<Vec<u8>>, output_len: usize) -> Result<Vec<Vec<u8>>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(batch::par_map_internal(&inputs, |input| shake128_internal(input, output_len)))
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn shake128_batch_async(inputs: Vec<Vec<u8>>, output_len: usize) -> Result<Vec<Vec<u8>>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(run_blocking(move || batch::par_map_internal(&inputs, |input| shake128_internal(input, output_len))).await)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn shake256_batch(inputs: Vec<Vec<u8>>, output_len: usize) -> Result<Vec<Vec<u8>>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(batch::par_map_internal(&inputs, |input| shake256_internal(input, output_len)))
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn shake256_batch_async(inputs: Vec<Vec<u8>>, output_len: usize) -> Result<Vec<Vec<u8>>, CipherError> {
+    check_shake_output_len(output_len)?;
+    Ok(run_blocking(move || batch::par_map_internal(&inputs, |input| shake256_internal(input, output_len))).await)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn blake2b_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    batch::par_map_internal(&inputs, blake2b_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn blake2b_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    run_blocking(move || batch::par_map_internal(&inputs, blake2b_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn blake2s_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    batch::par_map_internal(&inputs, blake2s_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn blake2s_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    run_blocking(move || batch::par_map_internal(&inputs, blake2s_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn blake3_batch(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    batch::par_map_internal(&inputs, blake3_internal)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn blake3_batch_async(inputs: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    run_blocking(move || batch::par_map_internal(&inputs, blake3_internal)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hmac_sha256_batch(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    batch::hmac_map_internal::<HmacSha256, _>(&key, &messages, Into::into)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha256_batch_async(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 32]> {
+    run_blocking(move || batch::hmac_map_internal::<HmacSha256, _>(&key, &messages, Into::into)).await
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hmac_sha512_batch(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    batch::hmac_map_internal::<HmacSha512, _>(&key, &messages, Into::into)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hmac_sha512_batch_async(key: Vec<u8>, messages: Vec<Vec<u8>>) -> Vec<[u8; 64]> {
+    run_blocking(move || batch::hmac_map_internal::<HmacSha512, _>(&key, &messages, Into::into)).await
+}
+
+// ============================================================================
+// COMBINED OPERATIONS (SYNC & ASYNC)
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hash_then_encrypt(data: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    let hash = sha256_internal(&data);
+    aes256_encrypt(hash.to_vec(), key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hash_then_encrypt_async(data: Vec<u8>, key: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    let hash = sha256_internal(&data);
+    aes256_encrypt(hash.to_vec(), key)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn encrypt_then_hmac(
+    plaintext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+) -> Result<(Vec<u8>, [u8; 32]), CipherError> {
+    let ciphertext = aes256_encrypt(plaintext, enc_key)?;
+    let mac = hmac_sha256_internal(&mac_key, &ciphertext);
+    Ok((ciphertext, mac))
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn encrypt_then_hmac_async(
+    plaintext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+) -> Result<(Vec<u8>, [u8; 32]), CipherError> {
+    let ciphertext = aes256_encrypt(plaintext, enc_key)?;
+    let mac = hmac_sha256_internal(&mac_key, &ciphertext);
+    Ok((ciphertext, mac))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_then_decrypt(
+    ciphertext: Vec<u8>,
+    mac: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    // OPTIMIZED: No unnecessary clones
+    let computed = hmac_sha256_internal(&mac_key, &ciphertext);
+    if !ct_eq_internal(&computed, &mac) { return Err(CipherError::AuthenticationFailed); }
+    aes256_decrypt(ciphertext, enc_key)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_then_decrypt_async(
+    ciphertext: Vec<u8>,
+    mac: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    let computed = hmac_sha256_internal(&mac_key, &ciphertext);
+    if !ct_eq_internal(&computed, &mac) { return Err(CipherError::AuthenticationFailed); }
+    aes256_decrypt(ciphertext, enc_key)
+}
+
+// ============================================================================
+// COMBINED OPERATIONS WITH ASSOCIATED DATA (SYNC & ASYNC)
+// The HMAC covers aad || ciphertext || aad length in bits (u64 BE), as in
+// RFC 7518 section 5.2.2.1, so `aad` is bound by both GCM and the MAC
+// ============================================================================
+
+#[inline(always)]
+fn etm_mac_internal(mac_key: &[u8], aad: &[u8], ciphertext: &[u8]) -> [u8; 32] {
+    let mut mac = <HmacSha256 as Mac>::new_from_slice(mac_key).unwrap();
+    mac.update(aad);
+    mac.update(ciphertext);
+    mac.update(&((aad.len() as u64) * 8).to_be_bytes());
+    mac.finalize().into_bytes().into()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn hash_then_encrypt_with_aad(data: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    let hash = sha256_internal(&data);
+    aead_encrypt_internal::<Aes256Gcm>(&key, &hash, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn hash_then_encrypt_with_aad_async(data: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    let hash = sha256_internal(&data);
+    aead_encrypt_internal::<Aes256Gcm>(&key, &hash, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn encrypt_then_hmac_with_aad(
+    plaintext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<(Vec<u8>, [u8; 32]), CipherError> {
+    let ciphertext = aead_encrypt_internal::<Aes256Gcm>(&enc_key, &plaintext, &aad)?;
+    let mac = etm_mac_internal(&mac_key, &aad, &ciphertext);
+    Ok((ciphertext, mac))
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn encrypt_then_hmac_with_aad_async(
+    plaintext: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<(Vec<u8>, [u8; 32]), CipherError> {
+    encrypt_then_hmac_with_aad(plaintext, enc_key, mac_key, aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn verify_hmac_then_decrypt_with_aad(
+    ciphertext: Vec<u8>,
+    mac: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    let computed = etm_mac_internal(&mac_key, &aad, &ciphertext);
+    if !ct_eq_internal(&computed, &mac) { return Err(CipherError::AuthenticationFailed); }
+    aead_decrypt_internal::<Aes256Gcm>(&enc_key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn verify_hmac_then_decrypt_with_aad_async(
+    ciphertext: Vec<u8>,
+    mac: Vec<u8>,
+    enc_key: Vec<u8>,
+    mac_key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    verify_hmac_then_decrypt_with_aad(ciphertext, mac, enc_key, mac_key, aad)
+}
+
+// ============================================================================
+// STATEFUL HASHERS (SYNC ONLY - required by Flutter)
+// Every hasher can `reset` to its initial (keyed) state and `fork` into an
+// independent copy, e.g. to read an intermediate digest and keep going.
+// SHA-1/2/3, MD5, Keccak, SHAKE and their HMACs can also `export_state` and
+// later `import_state` to resume exactly where they stopped; BLAKE2/BLAKE3
+// state is held inside their crates and cannot be exported.
+// `export_state` returns an opaque versioned blob holding the buffered input,
+// length and chaining values. An HMAC blob holds the keyed states, so anyone
+// with it can compute tags: store it as carefully as the key
+// ============================================================================
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha256Hasher { inner: Engine }
+
+impl Sha256Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha256) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 32] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha256, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha512Hasher { inner: Engine }
+
+impl Sha512Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha512) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 64] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha512, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha1Hasher { inner: Engine }
+
+impl Sha1Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha1) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 20] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha1, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha224Hasher { inner: Engine }
+
+impl Sha224Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha224) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 28] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha224, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha384Hasher { inner: Engine }
+
+impl Sha384Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha384) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 48] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha384, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha512_224Hasher { inner: Engine }
+
+impl Sha512_224Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha512_224) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 28] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha512_224, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha512_256Hasher { inner: Engine }
+
+impl Sha512_256Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha512_256) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 32] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha512_256, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Md5Hasher { inner: Engine }
+
+impl Md5Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Md5) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 16] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Md5, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha3_224Hasher { inner: Engine }
+
+impl Sha3_224Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha3_224) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 28] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha3_224, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha3_256Hasher { inner: Engine }
+
+impl Sha3_256Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha3_256) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 32] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha3_256, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha3_384Hasher { inner: Engine }
+
+impl Sha3_384Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha3_384) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 48] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha3_384, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha3_512Hasher { inner: Engine }
+
+impl Sha3_512Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Sha3_512) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 64] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Sha3_512, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Keccak256Hasher { inner: Engine }
+
+impl Keccak256Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Keccak256) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 32] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Keccak256, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Shake128Hasher { inner: Engine }
+
+impl Shake128Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: Engine::of(HashAlgorithm::Shake128) }
+    }
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn update(&mut self, data: Vec<u8>) {
@@ -536,17 +1979,40 @@ impl Sha512Hasher {
     }
 
     #[flutter_rust_bridge::frb(sync)]
-    pub fn finalize(self) -> [u8; 64] {
-        self.inner.finalize().into()
+    pub fn finalize(self, output_len: usize) -> Result<Vec<u8>, CipherError> {
+        check_shake_output_len(output_len)?;
+        Ok(self.inner.finalize(output_len))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Shake128, &state).map(|inner| Self { inner })
     }
 }
 
-pub struct Sha1Hasher { inner: Sha1 }
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Shake256Hasher { inner: Engine }
 
-impl Sha1Hasher {
+impl Shake256Hasher {
     #[flutter_rust_bridge::frb(sync)]
     pub fn new() -> Self {
-        Self { inner: Sha1::new() }
+        Self { inner: Engine::of(HashAlgorithm::Shake256) }
     }
 
     #[flutter_rust_bridge::frb(sync)]
@@ -555,19 +2021,167 @@ impl Sha1Hasher {
     }
 
     #[flutter_rust_bridge::frb(sync)]
-    pub fn finalize(self) -> [u8; 20] {
+    pub fn finalize(self, output_len: usize) -> Result<Vec<u8>, CipherError> {
+        check_shake_output_len(output_len)?;
+        Ok(self.inner.finalize(output_len))
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        Engine::import(HashAlgorithm::Shake256, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Blake2bHasher {
+    inner: blake2b_simd::State,
+    // BLAKE2 states cannot be rewound, so `reset` restarts from this copy
+    initial: blake2b_simd::State,
+}
+
+impl Blake2bHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        let initial = blake2b_simd::State::new();
+        Self { inner: initial.clone(), initial }
+    }
+
+    /// Keyed and/or shortened variant; an empty key means unkeyed.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new_keyed(key: Vec<u8>, output_len: usize) -> Result<Self, CipherError> {
+        let initial = blake2b_params_internal(&key, output_len)?.to_state();
+        Ok(Self { inner: initial.clone(), initial })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().as_bytes().to_vec()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner = self.initial.clone();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Blake2sHasher {
+    inner: blake2s_simd::State,
+    // BLAKE2 states cannot be rewound, so `reset` restarts from this copy
+    initial: blake2s_simd::State,
+}
+
+impl Blake2sHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        let initial = blake2s_simd::State::new();
+        Self { inner: initial.clone(), initial }
+    }
+
+    /// Keyed and/or shortened variant; an empty key means unkeyed.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new_keyed(key: Vec<u8>, output_len: usize) -> Result<Self, CipherError> {
+        let initial = blake2s_params_internal(&key, output_len)?.to_state();
+        Ok(Self { inner: initial.clone(), initial })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> Vec<u8> {
+        self.inner.finalize().as_bytes().to_vec()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner = self.initial.clone();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Blake3Hasher { inner: blake3::Hasher }
+
+impl Blake3Hasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self { inner: blake3::Hasher::new() }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new_keyed(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: blake3::Hasher::new_keyed(&blake3_key_internal(&key)?) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new_derive_key(context: String) -> Self {
+        Self { inner: blake3::Hasher::new_derive_key(&context) }
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        blake3_update_internal(&mut self.inner, &data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 32] {
         self.inner.finalize().into()
     }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
 }
 
-pub struct Sha256HmacHasher { inner: HmacSha256 }
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha256HmacHasher { inner: HmacEngine }
 
 impl Sha256HmacHasher {
     #[flutter_rust_bridge::frb(sync)]
-    pub fn new(key: Vec<u8>) -> Option<Self> {
-        <HmacSha256 as Mac>::new_from_slice(&key)
-            .ok()
-            .map(|inner| Self { inner })
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: HmacEngine::new(HashAlgorithm::Sha256, &key) })
     }
 
     #[flutter_rust_bridge::frb(sync)]
@@ -577,7 +2191,244 @@ impl Sha256HmacHasher {
 
     #[flutter_rust_bridge::frb(sync)]
     pub fn finalize(self) -> [u8; 32] {
-        self.inner.finalize().into_bytes().into()
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+    /// keep computing MACs under this key, so protect it like the key itself
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        HmacEngine::import(HashAlgorithm::Sha256, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha512HmacHasher { inner: HmacEngine }
+
+impl Sha512HmacHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: HmacEngine::new(HashAlgorithm::Sha512, &key) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 64] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+    /// keep computing MACs under this key, so protect it like the key itself
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        HmacEngine::import(HashAlgorithm::Sha512, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha1HmacHasher { inner: HmacEngine }
+
+impl Sha1HmacHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: HmacEngine::new(HashAlgorithm::Sha1, &key) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 20] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+    /// keep computing MACs under this key, so protect it like the key itself
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        HmacEngine::import(HashAlgorithm::Sha1, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha384HmacHasher { inner: HmacEngine }
+
+impl Sha384HmacHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: HmacEngine::new(HashAlgorithm::Sha384, &key) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 48] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+    /// keep computing MACs under this key, so protect it like the key itself
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        HmacEngine::import(HashAlgorithm::Sha384, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Sha224HmacHasher { inner: HmacEngine }
+
+impl Sha224HmacHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: HmacEngine::new(HashAlgorithm::Sha224, &key) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 28] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+    /// keep computing MACs under this key, so protect it like the key itself
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        HmacEngine::import(HashAlgorithm::Sha224, &state).map(|inner| Self { inner })
+    }
+}
+
+#[derive(Clone)]
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Md5HmacHasher { inner: HmacEngine }
+
+impl Md5HmacHasher {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        Ok(Self { inner: HmacEngine::new(HashAlgorithm::Md5, &key) })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn update(&mut self, data: Vec<u8>) {
+        self.inner.update(&data);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self) -> [u8; 16] {
+        self.inner.finalize_fixed()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Export the keyed midstate as an opaque versioned blob; it lets anyone
+    /// keep computing MACs under this key, so protect it like the key itself
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn export_state(&self) -> Vec<u8> {
+        self.inner.export()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn import_state(state: Vec<u8>) -> Result<Self, CipherError> {
+        HmacEngine::import(HashAlgorithm::Md5, &state).map(|inner| Self { inner })
     }
 }
 
@@ -593,35 +2444,115 @@ pub fn to_hex(bytes: Vec<u8>) -> String {
 
 #[flutter_rust_bridge::frb(sync)]
 #[inline(always)]
-pub fn from_hex(hex_string: String) -> Option<Vec<u8>> {
-    hex::decode(hex_string).ok()
+pub fn from_hex(hex_string: String) -> Result<Vec<u8>, CipherError> {
+    hex::decode(hex_string).map_err(|_| CipherError::InvalidHex)
 }
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn hash_size(algorithm: String) -> usize {
-    match algorithm.as_str() {
-        "sha1" => 20,
-        "sha224" => 28,
-        "sha256" => 32,
-        "sha384" => 48,
-        "sha512" => 64,
-        "sha512_224" => 28,
-        "sha512_256" => 32,
-        "md5" => 16,
-        _ => 0,
-    }
+    HashAlgorithm::from_name(&algorithm).map_or(0, |algorithm| algorithm.sizes().0)
 }
 
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_all_algorithms() -> Vec<String> {
-    vec![
-        "sha1".to_string(),
-        "sha224".to_string(),
-        "sha256".to_string(),
-        "sha384".to_string(),
-        "sha512".to_string(),
-        "sha512_224".to_string(),
-        "sha512_256".to_string(),
-        "md5".to_string(),
-    ]
-}
\ No newline at end of file
+    HashAlgorithm::ALL.iter().map(|algorithm| algorithm.name().to_string()).collect()
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha3_and_keccak_match_known_digests() {
+        assert_eq!(hex::encode(sha3_224(b"abc".to_vec())), "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf");
+        assert_eq!(
+            hex::encode(sha3_256(b"abc".to_vec())),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        assert_eq!(
+            hex::encode(sha3_384(b"abc".to_vec())),
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+        );
+        assert_eq!(
+            hex::encode(sha3_512(b"abc".to_vec())),
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        );
+        // Keccak padding differs from SHA3-256 on the same input
+        assert_eq!(
+            hex::encode(keccak256(Vec::new())),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(b"abc".to_vec())),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn shake_matches_known_digests_at_any_length() {
+        assert_eq!(
+            hex::encode(shake128(Vec::new(), 32).unwrap()),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            hex::encode(shake256(Vec::new(), 64).unwrap()),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+             d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+        assert_eq!(hex::encode(shake128(b"abc".to_vec(), 16).unwrap()), "5881092dd818bf5cf8a3ddb793fbcba7");
+        assert_eq!(
+            hex::encode(shake256(b"abc".to_vec(), 32).unwrap()),
+            "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739"
+        );
+
+        // A shorter output is a prefix of a longer one
+        let long = shake256(b"abc".to_vec(), 1000).unwrap();
+        assert_eq!(long.len(), 1000);
+        assert_eq!(long[..32], shake256(b"abc".to_vec(), 32).unwrap()[..]);
+    }
+
+    #[test]
+    fn shake_hashers_and_batches_match_one_shot() {
+        let inputs = vec![Vec::new(), b"abc".to_vec(), vec![0x5a; 500]];
+
+        let batch = shake128_batch(inputs.clone(), 48).unwrap();
+        for (input, digest) in inputs.iter().zip(&batch) {
+            assert_eq!(*digest, shake128(input.clone(), 48).unwrap());
+        }
+        let batch = shake256_batch(inputs.clone(), 80).unwrap();
+        for (input, digest) in inputs.iter().zip(&batch) {
+            assert_eq!(*digest, shake256(input.clone(), 80).unwrap());
+        }
+
+        let mut hasher = Shake128Hasher::new();
+        hasher.update(vec![0x5a; 200]);
+        hasher.update(vec![0x5a; 300]);
+        assert_eq!(hasher.finalize(300).unwrap(), shake128(vec![0x5a; 500], 300).unwrap());
+        let mut hasher = Shake256Hasher::new();
+        hasher.update(vec![0x5a; 200]);
+        hasher.update(vec![0x5a; 300]);
+        assert_eq!(hasher.finalize(300).unwrap(), shake256(vec![0x5a; 500], 300).unwrap());
+    }
+
+    #[test]
+    fn shake_rejects_empty_and_oversized_output() {
+        for output_len in [0, SHAKE_MAX_OUTPUT_LEN + 1, usize::MAX] {
+            let rejected = |result: Result<Vec<u8>, CipherError>| {
+                matches!(result, Err(CipherError::InvalidParameter { .. }))
+            };
+            assert!(rejected(shake128(b"abc".to_vec(), output_len)));
+            assert!(rejected(shake256(b"abc".to_vec(), output_len)));
+            assert!(rejected(Shake128Hasher::new().finalize(output_len)));
+            assert!(rejected(Shake256Hasher::new().finalize(output_len)));
+            assert!(matches!(
+                shake128_batch(vec![b"abc".to_vec()], output_len),
+                Err(CipherError::InvalidParameter { .. })
+            ));
+            assert!(matches!(
+                shake256_batch(vec![b"abc".to_vec()], output_len),
+                Err(CipherError::InvalidParameter { .. })
+            ));
+        }
+        assert_eq!(shake128(b"abc".to_vec(), SHAKE_MAX_OUTPUT_LEN).unwrap().len(), SHAKE_MAX_OUTPUT_LEN);
+    }
+}
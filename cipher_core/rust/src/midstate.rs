@@ -0,0 +1,754 @@
+// ============================================================================
+// EXPORTABLE HASH STATE
+//
+// The RustCrypto hashers keep their chaining values private, so stateful
+// hashers that must survive a process restart run on these engines instead:
+// the same compression functions (`sha1::compress`, `sha2::compress256/512`,
+// `keccak::f1600`) driven by our own buffering and padding, with every field
+// reachable for export. MD5 has no public compression function and gets a
+// straightforward RFC 1321 one here.
+//
+// State blob layout (all integers big-endian unless noted):
+//   magic "CCHS" (4) || version (1) || mode (1: 0 = hash, 1 = HMAC)
+//   || algorithm (1: `algorithm_id`) || engine state...
+// Merkle-Damgard engine:
+//   chaining values 8 x u64 (32-bit words in the low half) || total length
+//   u128 || buffered length u8 || buffered bytes
+// Sponge engine:
+//   lanes 25 x u64 (little-endian) || buffered length u8 || buffered bytes
+// HMAC: keyed inner start state || current inner state || outer state.
+// The keyed states stand in for the key itself: anyone holding an HMAC blob
+// can compute tags under that key, so store it as carefully as the key.
+// ============================================================================
+
+use sha2::digest::generic_array::GenericArray;
+
+use crate::hash_algorithm::HashAlgorithm;
+use crate::CipherError;
+
+const MAGIC: &[u8; 4] = b"CCHS";
+const VERSION: u8 = 1;
+const MODE_HASH: u8 = 0;
+const MODE_HMAC: u8 = 1;
+
+// ----------------------------------------------------------------------------
+// Merkle-Damgard family (MD5, SHA-1, SHA-2)
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MdKind {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+}
+
+impl MdKind {
+    fn block_len(self) -> usize {
+        match self {
+            Self::Sha384 | Self::Sha512 | Self::Sha512_224 | Self::Sha512_256 => 128,
+            _ => 64,
+        }
+    }
+
+    fn is_64bit(self) -> bool {
+        self.block_len() == 128
+    }
+
+    fn initial_state(self) -> [u64; 8] {
+        match self {
+            Self::Md5 => [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0, 0, 0, 0],
+            Self::Sha1 => [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0, 0, 0, 0],
+            Self::Sha224 => [
+                0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7, 0xbefa4fa4,
+            ],
+            Self::Sha256 => [
+                0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+            ],
+            Self::Sha384 => [
+                0xcbbb9d5dc1059ed8, 0x629a292a367cd507, 0x9159015a3070dd17, 0x152fecd8f70e5939,
+                0x67332667ffc00b31, 0x8eb44a8768581511, 0xdb0c2e0d64f98fa7, 0x47b5481dbefa4fa4,
+            ],
+            Self::Sha512 => [
+                0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+                0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+            ],
+            Self::Sha512_224 => [
+                0x8c3d37c819544da2, 0x73e1996689dcd4d6, 0x1dfab7ae32ff9c82, 0x679dd514582f9fcf,
+                0x0f6d2b697bd44da8, 0x77e36f7304c48942, 0x3f9d85a86a1d36c8, 0x1112e6ad91d692a1,
+            ],
+            Self::Sha512_256 => [
+                0x22312194fc2bf72c, 0x9f555fa3c84c64c2, 0x2393b86b6f53b151, 0x963877195940eabd,
+                0x96283ee2a88effe3, 0xbe5e1e2553863992, 0x2b0199fc2c85b8aa, 0x0eb72ddc81c52ca2,
+            ],
+        }
+    }
+
+    /// Run the compression function over whole blocks
+    fn compress(self, state: &mut [u64; 8], blocks: &[u8]) {
+        match self {
+            Self::Md5 => {
+                let mut words = [state[0] as u32, state[1] as u32, state[2] as u32, state[3] as u32];
+                for block in blocks.chunks_exact(64) {
+                    md5_compress(&mut words, block);
+                }
+                for (lane, word) in state.iter_mut().zip(words) { *lane = word as u64; }
+            }
+            Self::Sha1 => {
+                let mut words = [state[0] as u32, state[1] as u32, state[2] as u32, state[3] as u32, state[4] as u32];
+                for block in blocks.chunks_exact(64) {
+                    sha1::compress(&mut words, core::slice::from_ref(GenericArray::from_slice(block)));
+                }
+                for (lane, word) in state.iter_mut().zip(words) { *lane = word as u64; }
+            }
+            Self::Sha224 | Self::Sha256 => {
+                let mut words = state.map(|lane| lane as u32);
+                for block in blocks.chunks_exact(64) {
+                    sha2::compress256(&mut words, core::slice::from_ref(GenericArray::from_slice(block)));
+                }
+                *state = words.map(u64::from);
+            }
+            _ => {
+                for block in blocks.chunks_exact(128) {
+                    sha2::compress512(state, core::slice::from_ref(GenericArray::from_slice(block)));
+                }
+            }
+        }
+    }
+}
+
+const MD5_SHIFTS: [u32; 16] = [7, 12, 17, 22, 5, 9, 14, 20, 4, 11, 16, 23, 6, 10, 15, 21];
+
+const MD5_K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// MD5 compression function (RFC 1321 section 3.4)
+fn md5_compress(state: &mut [u32; 4], block: &[u8]) {
+    let mut m = [0u32; 16];
+    for (word, bytes) in m.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+    for i in 0..64 {
+        let (f, g) = match i / 16 {
+            0 => ((b & c) | (!b & d), i),
+            1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+            2 => (b ^ c ^ d, (3 * i + 5) % 16),
+            _ => (c ^ (b | !d), (7 * i) % 16),
+        };
+        let rotated = a
+            .wrapping_add(f)
+            .wrapping_add(MD5_K[i])
+            .wrapping_add(m[g])
+            .rotate_left(MD5_SHIFTS[(i / 16) * 4 + i % 4]);
+        (a, b, c, d) = (d, b.wrapping_add(rotated), b, c);
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+}
+
+#[derive(Clone)]
+struct MdEngine {
+    kind: MdKind,
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffer_len: usize,
+    total_len: u128,
+}
+
+impl MdEngine {
+    fn new(kind: MdKind) -> Self {
+        Self { kind, state: kind.initial_state(), buffer: [0u8; 128], buffer_len: 0, total_len: 0 }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        let block_len = self.kind.block_len();
+        self.total_len += data.len() as u128;
+
+        if self.buffer_len > 0 {
+            let take = (block_len - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < block_len { return; }
+            let block = self.buffer;
+            self.kind.compress(&mut self.state, &block[..block_len]);
+            self.buffer_len = 0;
+        }
+
+        let whole = data.len() - data.len() % block_len;
+        self.kind.compress(&mut self.state, &data[..whole]);
+        let rest = &data[whole..];
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    fn finalize(mut self, output_len: usize) -> Vec<u8> {
+        let block_len = self.kind.block_len();
+        let length_len = if self.kind.is_64bit() { 16 } else { 8 };
+        let bit_len = self.total_len.wrapping_mul(8);
+
+        let mut tail = [0u8; 256];
+        tail[..self.buffer_len].copy_from_slice(&self.buffer[..self.buffer_len]);
+        tail[self.buffer_len] = 0x80;
+        let padded = (self.buffer_len + 1 + length_len).div_ceil(block_len) * block_len;
+        match self.kind {
+            MdKind::Md5 => tail[padded - 8..padded].copy_from_slice(&(bit_len as u64).to_le_bytes()),
+            _ => tail[padded - length_len..padded].copy_from_slice(&bit_len.to_be_bytes()[16 - length_len..]),
+        }
+        self.kind.compress(&mut self.state, &tail[..padded]);
+
+        let mut output = Vec::with_capacity(64);
+        for lane in self.state {
+            match self.kind {
+                MdKind::Md5 => output.extend_from_slice(&(lane as u32).to_le_bytes()),
+                _ if self.kind.is_64bit() => output.extend_from_slice(&lane.to_be_bytes()),
+                _ => output.extend_from_slice(&(lane as u32).to_be_bytes()),
+            }
+        }
+        output.truncate(output_len);
+        output
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        for lane in self.state { out.extend_from_slice(&lane.to_be_bytes()); }
+        out.extend_from_slice(&self.total_len.to_be_bytes());
+        out.push(self.buffer_len as u8);
+        out.extend_from_slice(&self.buffer[..self.buffer_len]);
+    }
+
+    fn read(kind: MdKind, reader: &mut Reader) -> Result<Self, CipherError> {
+        let mut state = [0u64; 8];
+        for lane in state.iter_mut() { *lane = u64::from_be_bytes(reader.array()?); }
+        let total_len = u128::from_be_bytes(reader.array()?);
+        let buffer_len = reader.byte()? as usize;
+
+        let block_len = kind.block_len();
+        let narrow = !kind.is_64bit() && state.iter().any(|lane| *lane > u32::MAX as u64);
+        if narrow || buffer_len >= block_len || total_len % block_len as u128 != buffer_len as u128 {
+            return Err(CipherError::InvalidFormat);
+        }
+
+        let mut buffer = [0u8; 128];
+        buffer[..buffer_len].copy_from_slice(reader.take(buffer_len)?);
+        Ok(Self { kind, state, buffer, buffer_len, total_len })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Keccak sponge family (SHA-3, Keccak-256, SHAKE)
+// ----------------------------------------------------------------------------
+
+#[derive(Clone)]
+struct SpongeEngine {
+    rate: usize,
+    /// Domain separation byte: 0x06 SHA-3, 0x01 Keccak, 0x1f SHAKE
+    padding: u8,
+    lanes: [u64; 25],
+    buffer: [u8; 168],
+    buffer_len: usize,
+}
+
+impl SpongeEngine {
+    fn new(rate: usize, padding: u8) -> Self {
+        Self { rate, padding, lanes: [0u64; 25], buffer: [0u8; 168], buffer_len: 0 }
+    }
+
+    fn absorb_block(&mut self, block: &[u8]) {
+        for (lane, bytes) in self.lanes.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().expect("8-byte chunk"));
+        }
+        keccak::f1600(&mut self.lanes);
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        if self.buffer_len > 0 {
+            let take = (self.rate - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < self.rate { return; }
+            let block = self.buffer;
+            self.absorb_block(&block[..self.rate]);
+            self.buffer_len = 0;
+        }
+
+        let mut blocks = data.chunks_exact(self.rate);
+        for block in blocks.by_ref() {
+            self.absorb_block(block);
+        }
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    fn finalize(mut self, output_len: usize) -> Vec<u8> {
+        let rate = self.rate;
+        let mut block = [0u8; 168];
+        block[..self.buffer_len].copy_from_slice(&self.buffer[..self.buffer_len]);
+        block[self.buffer_len] ^= self.padding;
+        block[rate - 1] ^= 0x80;
+        self.absorb_block(&block[..rate]);
+
+        let mut output = Vec::with_capacity(output_len);
+        loop {
+            for lane in &self.lanes[..rate / 8] {
+                output.extend_from_slice(&lane.to_le_bytes());
+            }
+            if output.len() >= output_len { break; }
+            keccak::f1600(&mut self.lanes);
+        }
+        output.truncate(output_len);
+        output
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        for lane in self.lanes { out.extend_from_slice(&lane.to_le_bytes()); }
+        out.push(self.buffer_len as u8);
+        out.extend_from_slice(&self.buffer[..self.buffer_len]);
+    }
+
+    fn read(mut self, reader: &mut Reader) -> Result<Self, CipherError> {
+        for lane in self.lanes.iter_mut() { *lane = u64::from_le_bytes(reader.array()?); }
+        self.buffer_len = reader.byte()? as usize;
+        if self.buffer_len >= self.rate { return Err(CipherError::InvalidFormat); }
+        self.buffer[..self.buffer_len].copy_from_slice(reader.take(self.buffer_len)?);
+        Ok(self)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Engine: one exportable hash state
+// ----------------------------------------------------------------------------
+
+#[derive(Clone)]
+enum EngineState {
+    Md(MdEngine),
+    Sponge(SpongeEngine),
+}
+
+#[derive(Clone)]
+pub(crate) struct Engine {
+    algorithm: HashAlgorithm,
+    state: EngineState,
+}
+
+impl Engine {
+    /// Fresh state, or `None` for algorithms whose state cannot be exported
+    pub(crate) fn new(algorithm: HashAlgorithm) -> Option<Self> {
+        let md = |kind| EngineState::Md(MdEngine::new(kind));
+        let sponge = |rate, padding| EngineState::Sponge(SpongeEngine::new(rate, padding));
+        let state = match algorithm {
+            HashAlgorithm::Md5 => md(MdKind::Md5),
+            HashAlgorithm::Sha1 => md(MdKind::Sha1),
+            HashAlgorithm::Sha224 => md(MdKind::Sha224),
+            HashAlgorithm::Sha256 => md(MdKind::Sha256),
+            HashAlgorithm::Sha384 => md(MdKind::Sha384),
+            HashAlgorithm::Sha512 => md(MdKind::Sha512),
+            HashAlgorithm::Sha512_224 => md(MdKind::Sha512_224),
+            HashAlgorithm::Sha512_256 => md(MdKind::Sha512_256),
+            HashAlgorithm::Sha3_224 => sponge(144, 0x06),
+            HashAlgorithm::Sha3_256 => sponge(136, 0x06),
+            HashAlgorithm::Sha3_384 => sponge(104, 0x06),
+            HashAlgorithm::Sha3_512 => sponge(72, 0x06),
+            HashAlgorithm::Keccak256 => sponge(136, 0x01),
+            HashAlgorithm::Shake128 => sponge(168, 0x1f),
+            HashAlgorithm::Shake256 => sponge(136, 0x1f),
+            HashAlgorithm::Blake2b | HashAlgorithm::Blake2s | HashAlgorithm::Blake3 => return None,
+        };
+        Some(Self { algorithm, state })
+    }
+
+    /// Fresh state for an algorithm known to be exportable
+    pub(crate) fn of(algorithm: HashAlgorithm) -> Self {
+        Self::new(algorithm).expect("exportable algorithm")
+    }
+
+    pub(crate) fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            EngineState::Md(engine) => engine.update(data),
+            EngineState::Sponge(engine) => engine.update(data),
+        }
+    }
+
+    /// Digest of `output_len` bytes; only SHAKE may ask for more than the
+    /// algorithm's digest size
+    pub(crate) fn finalize(self, output_len: usize) -> Vec<u8> {
+        match self.state {
+            EngineState::Md(engine) => engine.finalize(output_len),
+            EngineState::Sponge(engine) => engine.finalize(output_len),
+        }
+    }
+
+    pub(crate) fn finalize_fixed<const N: usize>(self) -> [u8; N] {
+        self.finalize(N).try_into().expect("digest length")
+    }
+
+    pub(crate) fn reset(&mut self) {
+        *self = Self::of(self.algorithm);
+    }
+
+    fn block_len(&self) -> usize {
+        match &self.state {
+            EngineState::Md(engine) => engine.kind.block_len(),
+            EngineState::Sponge(engine) => engine.rate,
+        }
+    }
+
+    /// True when exactly one padded key block has been absorbed. A sponge
+    /// keeps no length, so a whole block absorbed shows as non-zero lanes
+    fn holds_one_block(&self) -> bool {
+        match &self.state {
+            EngineState::Md(engine) => engine.total_len == engine.kind.block_len() as u128,
+            EngineState::Sponge(engine) => engine.buffer_len == 0 && engine.lanes != [0u64; 25],
+        }
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        match &self.state {
+            EngineState::Md(engine) => engine.write(out),
+            EngineState::Sponge(engine) => engine.write(out),
+        }
+    }
+
+    fn read(algorithm: HashAlgorithm, reader: &mut Reader) -> Result<Self, CipherError> {
+        let fresh = Self::new(algorithm).ok_or(CipherError::InvalidFormat)?;
+        let state = match fresh.state {
+            EngineState::Md(engine) => EngineState::Md(MdEngine::read(engine.kind, reader)?),
+            EngineState::Sponge(engine) => EngineState::Sponge(engine.read(reader)?),
+        };
+        Ok(Self { algorithm, state })
+    }
+
+    pub(crate) fn export(&self) -> Vec<u8> {
+        let mut out = header(MODE_HASH, self.algorithm);
+        self.write(&mut out);
+        out
+    }
+
+    /// Restore an exported state, checking it belongs to `algorithm`
+    pub(crate) fn import(algorithm: HashAlgorithm, blob: &[u8]) -> Result<Self, CipherError> {
+        let mut reader = Reader::open(blob, MODE_HASH, algorithm)?;
+        let engine = Self::read(algorithm, &mut reader)?;
+        reader.finish()?;
+        Ok(engine)
+    }
+
+    /// Restore an exported plain-hash state of any algorithm
+    pub(crate) fn import_any(blob: &[u8]) -> Result<Self, CipherError> {
+        let algorithm = blob
+            .get(MAGIC.len() + 2)
+            .and_then(|&id| algorithm_from_id(id))
+            .ok_or(CipherError::InvalidFormat)?;
+        Self::import(algorithm, blob)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HMAC over an engine (RFC 2104)
+// ----------------------------------------------------------------------------
+
+#[derive(Clone)]
+pub(crate) struct HmacEngine {
+    /// Inner state right after absorbing key ^ ipad, for `reset`
+    start: Engine,
+    inner: Engine,
+    /// Outer state right after absorbing key ^ opad
+    outer: Engine,
+}
+
+impl HmacEngine {
+    pub(crate) fn new(algorithm: HashAlgorithm, key: &[u8]) -> Self {
+        let mut inner = Engine::of(algorithm);
+        let block_len = inner.block_len();
+        let digest_len = algorithm.sizes().0;
+
+        let mut block_key = vec![0u8; block_len];
+        if key.len() > block_len {
+            let mut hashed = Engine::of(algorithm);
+            hashed.update(key);
+            block_key[..digest_len].copy_from_slice(&hashed.finalize(digest_len));
+        } else {
+            block_key[..key.len()].copy_from_slice(key);
+        }
+
+        let mut outer = inner.clone();
+        inner.update(&block_key.iter().map(|byte| byte ^ 0x36).collect::<Vec<u8>>());
+        outer.update(&block_key.iter().map(|byte| byte ^ 0x5c).collect::<Vec<u8>>());
+        Self { start: inner.clone(), inner, outer }
+    }
+
+    pub(crate) fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub(crate) fn finalize(self) -> Vec<u8> {
+        let digest_len = self.inner.algorithm.sizes().0;
+        let mut outer = self.outer;
+        outer.update(&self.inner.finalize(digest_len));
+        outer.finalize(digest_len)
+    }
+
+    pub(crate) fn finalize_fixed<const N: usize>(self) -> [u8; N] {
+        self.finalize().try_into().expect("digest length")
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.inner = self.start.clone();
+    }
+
+    pub(crate) fn export(&self) -> Vec<u8> {
+        let mut out = header(MODE_HMAC, self.inner.algorithm);
+        self.start.write(&mut out);
+        self.inner.write(&mut out);
+        self.outer.write(&mut out);
+        out
+    }
+
+    pub(crate) fn import(algorithm: HashAlgorithm, blob: &[u8]) -> Result<Self, CipherError> {
+        let mut reader = Reader::open(blob, MODE_HMAC, algorithm)?;
+        let start = Engine::read(algorithm, &mut reader)?;
+        let inner = Engine::read(algorithm, &mut reader)?;
+        let outer = Engine::read(algorithm, &mut reader)?;
+        reader.finish()?;
+        if !outer.holds_one_block() || !start.holds_one_block() {
+            return Err(CipherError::InvalidFormat);
+        }
+        Ok(Self { start, inner, outer })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Blob encoding
+// ----------------------------------------------------------------------------
+
+/// Algorithm byte of a state blob. Blobs outlive releases, so an ID is
+/// never changed or reused; `HashAlgorithm` order plays no part
+fn algorithm_id(algorithm: HashAlgorithm) -> Option<u8> {
+    Some(match algorithm {
+        HashAlgorithm::Sha1 => 0,
+        HashAlgorithm::Sha224 => 1,
+        HashAlgorithm::Sha256 => 2,
+        HashAlgorithm::Sha384 => 3,
+        HashAlgorithm::Sha512 => 4,
+        HashAlgorithm::Sha512_224 => 5,
+        HashAlgorithm::Sha512_256 => 6,
+        HashAlgorithm::Md5 => 7,
+        HashAlgorithm::Sha3_224 => 8,
+        HashAlgorithm::Sha3_256 => 9,
+        HashAlgorithm::Sha3_384 => 10,
+        HashAlgorithm::Sha3_512 => 11,
+        HashAlgorithm::Keccak256 => 12,
+        HashAlgorithm::Shake128 => 13,
+        HashAlgorithm::Shake256 => 14,
+        HashAlgorithm::Blake2b | HashAlgorithm::Blake2s | HashAlgorithm::Blake3 => return None,
+    })
+}
+
+fn algorithm_from_id(id: u8) -> Option<HashAlgorithm> {
+    HashAlgorithm::ALL.into_iter().find(|algorithm| algorithm_id(*algorithm) == Some(id))
+}
+
+fn header(mode: u8, algorithm: HashAlgorithm) -> Vec<u8> {
+    let id = algorithm_id(algorithm).expect("exportable algorithm");
+    let mut out = Vec::with_capacity(256);
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[VERSION, mode, id]);
+    out
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn open(blob: &'a [u8], mode: u8, algorithm: HashAlgorithm) -> Result<Self, CipherError> {
+        let expected = header(mode, algorithm);
+        if blob.len() < expected.len() || blob[..expected.len()] != expected[..] {
+            return Err(CipherError::InvalidFormat);
+        }
+        Ok(Self { data: &blob[expected.len()..] })
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CipherError> {
+        if self.data.len() < len { return Err(CipherError::InvalidFormat); }
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], CipherError> {
+        Ok(self.take(N)?.try_into().expect("exact length"))
+    }
+
+    fn byte(&mut self) -> Result<u8, CipherError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn finish(self) -> Result<(), CipherError> {
+        if self.data.is_empty() { Ok(()) } else { Err(CipherError::InvalidFormat) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_algorithm::{hash_internal, hmac_internal};
+
+    fn exportable() -> impl Iterator<Item = HashAlgorithm> {
+        HashAlgorithm::ALL.iter().copied().filter(|algorithm| Engine::new(*algorithm).is_some())
+    }
+
+    fn data() -> Vec<u8> {
+        (0..300u32).map(|i| (i * 7 + 3) as u8).collect()
+    }
+
+    #[test]
+    fn engines_match_rustcrypto_across_export_at_every_split() {
+        let data = data();
+        for algorithm in exportable() {
+            let expected = hash_internal(algorithm, &data);
+            for split in 0..=data.len() {
+                let mut engine = Engine::of(algorithm);
+                engine.update(&data[..split]);
+                let mut restored = Engine::import(algorithm, &engine.export()).unwrap();
+                restored.update(&data[split..]);
+                assert_eq!(restored.finalize(expected.len()), expected, "{} split {split}", algorithm.name());
+            }
+        }
+    }
+
+    #[test]
+    fn shake_engines_match_rustcrypto_for_long_output() {
+        let data = data();
+        let mut engine = Engine::of(HashAlgorithm::Shake128);
+        engine.update(&data);
+        assert_eq!(engine.finalize(500), crate::shake128_internal(&data, 500));
+
+        let mut engine = Engine::of(HashAlgorithm::Shake256);
+        engine.update(&data);
+        assert_eq!(engine.finalize(500), crate::shake256_internal(&data, 500));
+    }
+
+    #[test]
+    fn hmac_engines_match_rustcrypto_across_export_at_every_split() {
+        let data = data();
+        for algorithm in exportable().filter(|algorithm| algorithm.info().supports_hmac) {
+            // A short key is padded, a key longer than any block is hashed first
+            for key in [b"key".to_vec(), vec![0x5a; 200]] {
+                let expected = hmac_internal(algorithm, &key, &data).unwrap();
+                for split in 0..=data.len() {
+                    let mut mac = HmacEngine::new(algorithm, &key);
+                    mac.update(&data[..split]);
+                    let mut restored = HmacEngine::import(algorithm, &mac.export()).unwrap();
+                    restored.update(&data[split..]);
+                    assert_eq!(restored.finalize(), expected, "hmac-{} split {split}", algorithm.name());
+                }
+
+                let mut mac = HmacEngine::new(algorithm, &key);
+                mac.update(b"discarded");
+                mac.reset();
+                mac.update(&data);
+                assert_eq!(mac.finalize(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_blobs_are_rejected() {
+        let reject = |result: Result<Engine, CipherError>| assert!(matches!(result, Err(CipherError::InvalidFormat)));
+        let mut engine = Engine::of(HashAlgorithm::Sha256);
+        engine.update(b"abc");
+        let blob = engine.export();
+        assert!(Engine::import(HashAlgorithm::Sha256, &blob).is_ok());
+
+        // Every truncation and a trailing byte
+        for len in 0..blob.len() {
+            reject(Engine::import(HashAlgorithm::Sha256, &blob[..len]));
+        }
+        reject(Engine::import(HashAlgorithm::Sha256, &[blob.as_slice(), &[0]].concat()));
+
+        let patched = |offset: usize, value: u8| {
+            let mut copy = blob.clone();
+            copy[offset] = value;
+            copy
+        };
+        reject(Engine::import(HashAlgorithm::Sha256, &patched(0, b'X')));
+        reject(Engine::import(HashAlgorithm::Sha256, &patched(4, VERSION + 1)));
+        reject(Engine::import(HashAlgorithm::Sha256, &patched(5, MODE_HMAC)));
+        reject(Engine::import(HashAlgorithm::Sha224, &blob));
+        reject(Engine::import_any(&patched(6, 15)));
+        reject(Engine::import_any(&patched(6, u8::MAX)));
+
+        // Engine fields: a 32-bit word with its high half set, a total length
+        // that disagrees with the buffer, and a full buffer
+        reject(Engine::import(HashAlgorithm::Sha256, &patched(7, 1)));
+        reject(Engine::import(HashAlgorithm::Sha256, &patched(7 + 64 + 15, 4)));
+        let mut full = blob[..7 + 64].to_vec();
+        full.extend_from_slice(&64u128.to_be_bytes());
+        full.push(64);
+        full.extend_from_slice(&[0u8; 64]);
+        reject(Engine::import(HashAlgorithm::Sha256, &full));
+
+        let mut sponge = Engine::of(HashAlgorithm::Sha3_256).export();
+        sponge[7 + 200] = 136;
+        sponge.extend_from_slice(&[0u8; 136]);
+        reject(Engine::import(HashAlgorithm::Sha3_256, &sponge));
+
+        // HMAC: a plain hash blob, and keyed states that never absorbed a key block
+        assert!(HmacEngine::import(HashAlgorithm::Sha256, &blob).is_err());
+        let mut unkeyed = header(MODE_HMAC, HashAlgorithm::Sha256);
+        for _ in 0..3 {
+            Engine::of(HashAlgorithm::Sha256).write(&mut unkeyed);
+        }
+        assert!(matches!(HmacEngine::import(HashAlgorithm::Sha256, &unkeyed), Err(CipherError::InvalidFormat)));
+        let mut unkeyed = header(MODE_HMAC, HashAlgorithm::Sha3_256);
+        for _ in 0..3 {
+            Engine::of(HashAlgorithm::Sha3_256).write(&mut unkeyed);
+        }
+        assert!(matches!(HmacEngine::import(HashAlgorithm::Sha3_256, &unkeyed), Err(CipherError::InvalidFormat)));
+    }
+
+    #[test]
+    fn algorithm_ids_are_pinned() {
+        // Changing any of these breaks every state blob already stored
+        let expected = [
+            (HashAlgorithm::Sha1, 0),
+            (HashAlgorithm::Sha256, 2),
+            (HashAlgorithm::Md5, 7),
+            (HashAlgorithm::Sha3_256, 9),
+            (HashAlgorithm::Keccak256, 12),
+            (HashAlgorithm::Shake256, 14),
+        ];
+        for (algorithm, id) in expected {
+            assert_eq!(Engine::of(algorithm).export()[6], id, "{}", algorithm.name());
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for algorithm in exportable() {
+            let id = algorithm_id(algorithm).unwrap();
+            assert!(seen.insert(id), "{} reuses ID {id}", algorithm.name());
+            assert_eq!(Engine::import_any(&Engine::of(algorithm).export()).unwrap().algorithm(), algorithm);
+        }
+    }
+}
@@ -0,0 +1,372 @@
+// ============================================================================
+// MISUSE-RESISTANT ENCRYPTION: AES-256-GCM-SIV (RFC 8452) & AES-SIV (RFC 5297)
+// A repeated nonce only reveals that two messages were equal, so both modes
+// are safe for keys shared across devices. Each comes in two flavours:
+//   random-nonce   a fresh nonce is generated and prepended (like `aes256_encrypt`)
+//   deterministic  no nonce: equal (plaintext, aad) give equal ciphertexts,
+//                  e.g. for a searchable local index
+// ============================================================================
+
+use aes::{Aes128, Aes192, Aes256};
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm_siv::Aes256GcmSiv;
+use cmac::{Cmac, Mac};
+use ctr::cipher::consts::U16;
+use ctr::cipher::{BlockCipher, BlockEncrypt, BlockSizeUser, KeyIvInit, StreamCipher};
+use rand::Rng;
+
+use crate::{aead_decrypt_internal, aead_encrypt_internal, check_key_len, ct_eq_internal, CipherError};
+
+const GCM_SIV_TAG_LEN: usize = 16;
+const SIV_LEN: usize = 16;
+const SIV_NONCE_LEN: usize = 16;
+
+// ============================================================================
+// INTERNAL: AES-256-GCM-SIV
+// ============================================================================
+
+/// Deterministic GCM-SIV pins the nonce to zero; the output is ciphertext || tag
+fn gcm_siv_deterministic_encrypt_internal(key: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_key_len(key, 32)?;
+    let cipher = Aes256GcmSiv::new_from_slice(key).map_err(|_| CipherError::InvalidKey)?;
+    cipher
+        .encrypt(&Default::default(), Payload { msg: plaintext, aad })
+        .map_err(|_| CipherError::EncryptionFailed)
+}
+
+fn gcm_siv_deterministic_decrypt_internal(key: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_key_len(key, 32)?;
+    if ciphertext.len() < GCM_SIV_TAG_LEN {
+        return Err(CipherError::CiphertextTooShort { minimum: GCM_SIV_TAG_LEN, actual: ciphertext.len() });
+    }
+    let cipher = Aes256GcmSiv::new_from_slice(key).map_err(|_| CipherError::InvalidKey)?;
+    cipher
+        .decrypt(&Default::default(), Payload { msg: ciphertext, aad })
+        .map_err(|_| CipherError::AuthenticationFailed)
+}
+
+// ============================================================================
+// INTERNAL: AES-SIV (S2V + CTR)
+// ============================================================================
+
+/// Doubling in GF(2^128) as defined for S2V
+#[inline(always)]
+fn dbl(block: [u8; 16]) -> [u8; 16] {
+    let value = u128::from_be_bytes(block);
+    let reduced = (value << 1) ^ if value >> 127 == 1 { 0x87 } else { 0 };
+    reduced.to_be_bytes()
+}
+
+#[inline(always)]
+fn xor_block(a: [u8; 16], b: [u8; 16]) -> [u8; 16] {
+    core::array::from_fn(|i| a[i] ^ b[i])
+}
+
+fn cmac<C>(key: &[u8], data: &[u8]) -> [u8; 16]
+where
+    C: BlockCipher + BlockEncrypt + BlockSizeUser<BlockSize = U16> + KeyInit + Clone,
+{
+    let mut mac = <Cmac<C> as Mac>::new_from_slice(key).expect("CMAC key length checked by caller");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// S2V over the associated data components followed by the plaintext
+fn s2v<C>(key: &[u8], components: &[&[u8]], plaintext: &[u8]) -> [u8; 16]
+where
+    C: BlockCipher + BlockEncrypt + BlockSizeUser<BlockSize = U16> + KeyInit + Clone,
+{
+    let mut d = cmac::<C>(key, &[0u8; 16]);
+    for component in components {
+        d = xor_block(dbl(d), cmac::<C>(key, component));
+    }
+
+    if plaintext.len() >= 16 {
+        let mut t = plaintext.to_vec();
+        let split = t.len() - 16;
+        for (byte, mask) in t[split..].iter_mut().zip(d) {
+            *byte ^= mask;
+        }
+        cmac::<C>(key, &t)
+    } else {
+        let mut padded = [0u8; 16];
+        padded[..plaintext.len()].copy_from_slice(plaintext);
+        padded[plaintext.len()] = 0x80;
+        cmac::<C>(key, &xor_block(dbl(d), padded))
+    }
+}
+
+/// Apply AES-CTR keyed by the second half of the key, with the SIV as
+/// counter block after clearing the two bits RFC 5297 reserves
+fn siv_ctr<C>(key: &[u8], siv: [u8; 16], data: &mut [u8])
+where
+    C: BlockCipher + BlockEncrypt + BlockSizeUser<BlockSize = U16> + KeyInit + Clone,
+{
+    let mut counter = siv;
+    counter[8] &= 0x7f;
+    counter[12] &= 0x7f;
+    let mut cipher = ctr::Ctr128BE::<C>::new_from_slices(key, &counter).expect("CTR key length checked by caller");
+    cipher.apply_keystream(data);
+}
+
+fn siv_seal<C>(key: &[u8], components: &[&[u8]], plaintext: &[u8]) -> Vec<u8>
+where
+    C: BlockCipher + BlockEncrypt + BlockSizeUser<BlockSize = U16> + KeyInit + Clone,
+{
+    let (mac_key, enc_key) = key.split_at(key.len() / 2);
+    let siv = s2v::<C>(mac_key, components, plaintext);
+
+    let mut result = Vec::with_capacity(SIV_LEN + plaintext.len());
+    result.extend_from_slice(&siv);
+    result.extend_from_slice(plaintext);
+    siv_ctr::<C>(enc_key, siv, &mut result[SIV_LEN..]);
+    result
+}
+
+fn siv_open<C>(key: &[u8], components: &[&[u8]], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError>
+where
+    C: BlockCipher + BlockEncrypt + BlockSizeUser<BlockSize = U16> + KeyInit + Clone,
+{
+    let (mac_key, enc_key) = key.split_at(key.len() / 2);
+    let (siv, body) = ciphertext.split_at(SIV_LEN);
+    let siv: [u8; 16] = siv.try_into().expect("SIV length checked by caller");
+
+    let mut plaintext = body.to_vec();
+    siv_ctr::<C>(enc_key, siv, &mut plaintext);
+    if !ct_eq_internal(&s2v::<C>(mac_key, components, &plaintext), &siv) {
+        return Err(CipherError::AuthenticationFailed);
+    }
+    Ok(plaintext)
+}
+
+/// AES-SIV keys are two AES keys back to back: 32, 48 or 64 bytes
+fn check_siv_key(key: &[u8]) -> Result<(), CipherError> {
+    match key.len() {
+        32 | 48 | 64 => Ok(()),
+        _ => Err(CipherError::InvalidParameter { message: "AES-SIV key must be 32, 48 or 64 bytes".to_string() }),
+    }
+}
+
+fn siv_encrypt_internal(key: &[u8], components: &[&[u8]], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_siv_key(key)?;
+    Ok(match key.len() {
+        32 => siv_seal::<Aes128>(key, components, plaintext),
+        48 => siv_seal::<Aes192>(key, components, plaintext),
+        _ => siv_seal::<Aes256>(key, components, plaintext),
+    })
+}
+
+fn siv_decrypt_internal(key: &[u8], components: &[&[u8]], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_siv_key(key)?;
+    if ciphertext.len() < SIV_LEN {
+        return Err(CipherError::CiphertextTooShort { minimum: SIV_LEN, actual: ciphertext.len() });
+    }
+    match key.len() {
+        32 => siv_open::<Aes128>(key, components, ciphertext),
+        48 => siv_open::<Aes192>(key, components, ciphertext),
+        _ => siv_open::<Aes256>(key, components, ciphertext),
+    }
+}
+
+/// Random-nonce AES-SIV: nonce (16) || SIV (16) || ciphertext, with the
+/// nonce as the last associated data component (RFC 5297 section 3)
+fn siv_nonce_encrypt_internal(key: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let mut nonce = [0u8; SIV_NONCE_LEN];
+    rand::rng().fill_bytes(&mut nonce);
+
+    let sealed = siv_encrypt_internal(key, &[aad, &nonce], plaintext)?;
+    let mut result = nonce.to_vec();
+    result.extend_from_slice(&sealed);
+    Ok(result)
+}
+
+fn siv_nonce_decrypt_internal(key: &[u8], ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>, CipherError> {
+    check_siv_key(key)?;
+    let minimum = SIV_NONCE_LEN + SIV_LEN;
+    if ciphertext.len() < minimum {
+        return Err(CipherError::CiphertextTooShort { minimum, actual: ciphertext.len() });
+    }
+    let (nonce, sealed) = ciphertext.split_at(SIV_NONCE_LEN);
+    siv_decrypt_internal(key, &[aad, nonce], sealed)
+}
+
+// ============================================================================
+// AES-256-GCM-SIV (SYNC & ASYNC)
+// 12-byte nonce is automatically generated and prepended to ciphertext
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes256_gcm_siv_encrypt(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<Aes256GcmSiv>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_gcm_siv_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_encrypt_internal::<Aes256GcmSiv>(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes256_gcm_siv_decrypt(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<Aes256GcmSiv>(&key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_gcm_siv_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    aead_decrypt_internal::<Aes256GcmSiv>(&key, &ciphertext, &aad)
+}
+
+// ============================================================================
+// AES-256-GCM-SIV DETERMINISTIC (SYNC & ASYNC)
+// Fixed all-zero nonce, output is ciphertext || tag
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes256_gcm_siv_encrypt_deterministic(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    gcm_siv_deterministic_encrypt_internal(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_gcm_siv_encrypt_deterministic_async(
+    plaintext: Vec<u8>,
+    key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    gcm_siv_deterministic_encrypt_internal(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes256_gcm_siv_decrypt_deterministic(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    gcm_siv_deterministic_decrypt_internal(&key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes256_gcm_siv_decrypt_deterministic_async(
+    ciphertext: Vec<u8>,
+    key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    gcm_siv_deterministic_decrypt_internal(&key, &ciphertext, &aad)
+}
+
+// ============================================================================
+// AES-SIV (SYNC & ASYNC)
+// Key is 32, 48 or 64 bytes (AES-128/192/256-SIV); a 16-byte nonce is
+// generated and prepended: nonce || SIV || ciphertext
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_siv_encrypt(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    siv_nonce_encrypt_internal(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_siv_encrypt_async(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    siv_nonce_encrypt_internal(&key, &plaintext, &aad)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_siv_decrypt(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    siv_nonce_decrypt_internal(&key, &ciphertext, &aad)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_siv_decrypt_async(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    siv_nonce_decrypt_internal(&key, &ciphertext, &aad)
+}
+
+// ============================================================================
+// AES-SIV DETERMINISTIC (SYNC & ASYNC)
+// RFC 5297 deterministic mode: SIV || ciphertext, `aad` is the only header
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_siv_encrypt_deterministic(plaintext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    siv_encrypt_internal(&key, &[&aad], &plaintext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_siv_encrypt_deterministic_async(
+    plaintext: Vec<u8>,
+    key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    siv_encrypt_internal(&key, &[&aad], &plaintext)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn aes_siv_decrypt_deterministic(ciphertext: Vec<u8>, key: Vec<u8>, aad: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    siv_decrypt_internal(&key, &[&aad], &ciphertext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn aes_siv_decrypt_deterministic_async(
+    ciphertext: Vec<u8>,
+    key: Vec<u8>,
+    aad: Vec<u8>,
+) -> Result<Vec<u8>, CipherError> {
+    siv_decrypt_internal(&key, &[&aad], &ciphertext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(data: &str) -> Vec<u8> {
+        hex::decode(data.replace(' ', "")).unwrap()
+    }
+
+    #[test]
+    fn rfc5297_a1_deterministic() {
+        let key = unhex("fffefdfc fbfaf9f8 f7f6f5f4 f3f2f1f0 f0f1f2f3 f4f5f6f7 f8f9fafb fcfdfeff");
+        let aad = unhex("10111213 14151617 18191a1b 1c1d1e1f 20212223 24252627");
+        let plaintext = unhex("11223344 55667788 99aabbcc ddee");
+        let expected = unhex("85632d07 c6e8f37f 950acd32 0a2ecc93 40c02b96 90c4dc04 daef7f6a fe5c");
+
+        let sealed = aes_siv_encrypt_deterministic(plaintext.clone(), key.clone(), aad.clone()).unwrap();
+        assert_eq!(sealed, expected);
+        assert_eq!(aes_siv_decrypt_deterministic(sealed, key, aad).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn rfc5297_a2_nonce_based() {
+        let key = unhex("7f7e7d7c 7b7a7978 77767574 73727170 40414243 44454647 48494a4b 4c4d4e4f");
+        let ad1 = unhex(
+            "00112233 44556677 8899aabb ccddeeff deaddada deaddada ffeeddcc bbaa9988 77665544 33221100",
+        );
+        let ad2 = unhex("10203040 50607080 90a0");
+        let nonce = unhex("09f91102 9d74e35b d84156c5 635688c0");
+        let plaintext = unhex(
+            "74686973 20697320 736f6d65 20706c61 696e7465 78742074 6f20656e 63727970 74207573 696e6720 5349562d 414553",
+        );
+        let expected = unhex(
+            "7bdb6e3b 432667eb 06f4d14b ff2fbd0f cb900f2f ddbe4043 26601965 c889bf17 \
+             dba77ceb 094fa663 b7a3f748 ba8af829 ea64ad54 4a272e9c 485b62a3 fd5c0d",
+        );
+
+        let components: [&[u8]; 3] = [&ad1, &ad2, &nonce];
+        let sealed = siv_encrypt_internal(&key, &components, &plaintext).unwrap();
+        assert_eq!(sealed, expected);
+        assert_eq!(siv_decrypt_internal(&key, &components, &sealed).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn tampering_is_rejected() {
+        let key = [3u8; 64];
+        let sealed = aes_siv_encrypt(b"secret".to_vec(), key.to_vec(), b"aad".to_vec()).unwrap();
+        assert_eq!(aes_siv_decrypt(sealed.clone(), key.to_vec(), b"aad".to_vec()).unwrap(), b"secret");
+
+        // Nonce, SIV and ciphertext bytes, then the associated data
+        for index in [0, SIV_NONCE_LEN, SIV_NONCE_LEN + SIV_LEN] {
+            let mut tampered = sealed.clone();
+            tampered[index] ^= 1;
+            assert!(matches!(
+                aes_siv_decrypt(tampered, key.to_vec(), b"aad".to_vec()),
+                Err(CipherError::AuthenticationFailed)
+            ));
+        }
+        assert!(matches!(
+            aes_siv_decrypt(sealed, key.to_vec(), b"aad!".to_vec()),
+            Err(CipherError::AuthenticationFailed)
+        ));
+    }
+}
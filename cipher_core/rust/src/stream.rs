@@ -0,0 +1,186 @@
+// ============================================================================
+// STREAMING AES-256-GCM (SYNC ONLY - stateful objects)
+//
+// Segmented STREAM construction (Hoang, Reyhanitabar, Rogaway, Vizár):
+// every chunk is sealed with nonce = prefix(7) || counter(4, BE) || last(1).
+// Reordering a chunk changes its counter, dropping the tail leaves the
+// decryptor without a chunk carrying the last flag, so both fail to verify.
+//
+// Layout produced by the encryptor:
+//   header (7-byte nonce prefix) || chunk_0 || chunk_1 || ... || last_chunk
+// Each sealed chunk is `plaintext_len + 16` bytes. The decryptor must be fed
+// the same segment boundaries the encryptor produced.
+// ============================================================================
+
+use aes_gcm::aead::stream::{DecryptorBE32, EncryptorBE32};
+use aes_gcm::aead::{KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Key};
+use rand::Rng;
+
+use crate::{check_key_len, CipherError};
+
+/// Length of the random nonce prefix written at the start of a stream.
+pub const STREAM_HEADER_LEN: usize = 7;
+
+/// Authentication tag appended to every sealed chunk.
+pub const STREAM_TAG_LEN: usize = 16;
+
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Aes256GcmStreamEncryptor {
+    inner: EncryptorBE32<Aes256Gcm>,
+    header: [u8; STREAM_HEADER_LEN],
+}
+
+impl Aes256GcmStreamEncryptor {
+    /// Create an encryptor with a fresh random nonce prefix.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>) -> Result<Self, CipherError> {
+        check_key_len(&key, 32)?;
+
+        let mut header = [0u8; STREAM_HEADER_LEN];
+        rand::rng().fill_bytes(&mut header);
+
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        let inner = EncryptorBE32::from_aead(cipher, header.as_slice().into());
+        Ok(Self { inner, header })
+    }
+
+    /// Stream header that must be stored before the first chunk.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn header(&self) -> Vec<u8> {
+        self.header.to_vec()
+    }
+
+    /// Seal an intermediate chunk. Fails once the chunk counter is exhausted.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn encrypt_chunk(&mut self, chunk: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+        self.inner
+            .encrypt_next(Payload::from(&chunk[..]))
+            .map_err(|_| CipherError::StreamExhausted)
+    }
+
+    /// Seal the final chunk (may be empty) and consume the encryptor.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self, chunk: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+        self.inner
+            .encrypt_last(Payload::from(&chunk[..]))
+            .map_err(|_| CipherError::EncryptionFailed)
+    }
+}
+
+#[flutter_rust_bridge::frb(opaque)]
+pub struct Aes256GcmStreamDecryptor {
+    inner: DecryptorBE32<Aes256Gcm>,
+}
+
+impl Aes256GcmStreamDecryptor {
+    /// Create a decryptor from the key and the header written by the encryptor.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(key: Vec<u8>, header: Vec<u8>) -> Result<Self, CipherError> {
+        check_key_len(&key, 32)?;
+        if header.len() != STREAM_HEADER_LEN { return Err(CipherError::InvalidFormat); }
+
+        let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key));
+        let inner = DecryptorBE32::from_aead(cipher, header.as_slice().into());
+        Ok(Self { inner })
+    }
+
+    /// Open an intermediate chunk. Fails on tampering, reordering, or if the
+    /// chunk was actually sealed as the last one.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn decrypt_chunk(&mut self, chunk: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+        self.inner
+            .decrypt_next(Payload::from(&chunk[..]))
+            .map_err(|_| CipherError::AuthenticationFailed)
+    }
+
+    /// Open the final chunk and consume the decryptor. Fails if the stream
+    /// was truncated, since the chunk given here was not sealed as the last.
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn finalize(self, chunk: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+        self.inner
+            .decrypt_last(Payload::from(&chunk[..]))
+            .map_err(|_| CipherError::AuthenticationFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 32] = [7u8; 32];
+
+    /// Header and sealed chunks; the last one carries the last flag
+    fn sealed() -> (Vec<u8>, Vec<Vec<u8>>) {
+        let mut encryptor = Aes256GcmStreamEncryptor::new(KEY.to_vec()).unwrap();
+        let mut chunks: Vec<Vec<u8>> = [b"first".as_slice(), b"second", b"third"]
+            .iter()
+            .map(|chunk| encryptor.encrypt_chunk(chunk.to_vec()).unwrap())
+            .collect();
+        let header = encryptor.header();
+        chunks.push(encryptor.finalize(b"last".to_vec()).unwrap());
+        (header, chunks)
+    }
+
+    fn decryptor(header: &[u8]) -> Aes256GcmStreamDecryptor {
+        Aes256GcmStreamDecryptor::new(KEY.to_vec(), header.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let (header, chunks) = sealed();
+        assert_eq!(header.len(), STREAM_HEADER_LEN);
+        assert_eq!(chunks[0].len(), b"first".len() + STREAM_TAG_LEN);
+
+        let mut decryptor = decryptor(&header);
+        let (last, rest) = chunks.split_last().unwrap();
+        let opened: Vec<Vec<u8>> = rest.iter().map(|chunk| decryptor.decrypt_chunk(chunk.clone()).unwrap()).collect();
+        assert_eq!(opened, [b"first".to_vec(), b"second".to_vec(), b"third".to_vec()]);
+        assert_eq!(decryptor.finalize(last.clone()).unwrap(), b"last");
+    }
+
+    #[test]
+    fn swapped_chunks_fail() {
+        let (header, mut chunks) = sealed();
+        chunks.swap(1, 2);
+        let mut decryptor = decryptor(&header);
+        assert!(decryptor.decrypt_chunk(chunks[0].clone()).is_ok());
+        assert_eq!(decryptor.decrypt_chunk(chunks[1].clone()), Err(CipherError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn dropped_final_chunk_fails() {
+        let (header, mut chunks) = sealed();
+        chunks.pop();
+        let mut decryptor = decryptor(&header);
+        for chunk in &chunks[..2] {
+            assert!(decryptor.decrypt_chunk(chunk.clone()).is_ok());
+        }
+        // What is now the tail was sealed as an intermediate chunk
+        assert_eq!(decryptor.finalize(chunks[2].clone()), Err(CipherError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn last_chunk_opened_as_intermediate_fails() {
+        let (header, chunks) = sealed();
+        let mut decryptor = decryptor(&header);
+        for chunk in &chunks[..3] {
+            assert!(decryptor.decrypt_chunk(chunk.clone()).is_ok());
+        }
+        assert_eq!(decryptor.decrypt_chunk(chunks[3].clone()), Err(CipherError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn wrong_header_or_key_fails() {
+        let (mut header, chunks) = sealed();
+        assert!(matches!(
+            Aes256GcmStreamDecryptor::new(KEY.to_vec(), header[..6].to_vec()),
+            Err(CipherError::InvalidFormat)
+        ));
+        let mut other_key = Aes256GcmStreamDecryptor::new([8u8; 32].to_vec(), header.clone()).unwrap();
+        assert_eq!(other_key.decrypt_chunk(chunks[0].clone()), Err(CipherError::AuthenticationFailed));
+
+        header[0] ^= 1;
+        assert_eq!(decryptor(&header).decrypt_chunk(chunks[0].clone()), Err(CipherError::AuthenticationFailed));
+    }
+}
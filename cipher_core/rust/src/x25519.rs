@@ -0,0 +1,147 @@
+// ============================================================================
+// X25519 KEY AGREEMENT & SEALED BOXES (SYNC & ASYNC)
+//
+// Sealed box layout (anonymous sender, like libsodium's crypto_box_seal):
+//   ephemeral public key (32) || nonce (12) || AES-256-GCM ciphertext || tag (16)
+// The AES key is HKDF-SHA256(ikm = X25519(ephemeral, recipient),
+// salt = ephemeral_pk || recipient_pk, info = SEALED_BOX_INFO).
+// ============================================================================
+
+use aes_gcm::Aes256Gcm;
+use rand::Rng;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use crate::kdf::hkdf_sha256_internal;
+use crate::{aead_decrypt_internal, aead_encrypt_internal, check_key_len, CipherError};
+
+/// Length of X25519 secret and public keys
+pub const X25519_KEY_LEN: usize = 32;
+
+const SEALED_BOX_INFO: &[u8] = b"cipher_core sealed box v1";
+
+pub struct X25519KeyPair {
+    pub secret_key: [u8; 32],
+    pub public_key: [u8; 32],
+}
+
+#[inline(always)]
+pub(crate) fn secret_internal(secret_key: &[u8]) -> Result<StaticSecret, CipherError> {
+    check_key_len(secret_key, X25519_KEY_LEN)?;
+    let mut bytes = [0u8; X25519_KEY_LEN];
+    bytes.copy_from_slice(secret_key);
+    Ok(StaticSecret::from(bytes))
+}
+
+#[inline(always)]
+pub(crate) fn public_internal(public_key: &[u8]) -> Result<PublicKey, CipherError> {
+    check_key_len(public_key, X25519_KEY_LEN)?;
+    let mut bytes = [0u8; X25519_KEY_LEN];
+    bytes.copy_from_slice(public_key);
+    Ok(PublicKey::from(bytes))
+}
+
+/// Diffie-Hellman that rejects low-order public keys (all-zero shared secret)
+#[inline(always)]
+pub(crate) fn shared_secret_internal(secret: &StaticSecret, public: &PublicKey) -> Result<[u8; 32], CipherError> {
+    let shared = secret.diffie_hellman(public);
+    if !shared.was_contributory() { return Err(CipherError::InvalidKey); }
+    Ok(shared.to_bytes())
+}
+
+fn sealed_box_key_internal(shared: &[u8], ephemeral_pk: &[u8], recipient_pk: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let mut salt = [0u8; 2 * X25519_KEY_LEN];
+    salt[..X25519_KEY_LEN].copy_from_slice(ephemeral_pk);
+    salt[X25519_KEY_LEN..].copy_from_slice(recipient_pk);
+    hkdf_sha256_internal(shared, &salt, SEALED_BOX_INFO, 32)
+}
+
+fn seal_internal(recipient_pk: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let recipient = public_internal(recipient_pk)?;
+
+    let mut ephemeral_bytes = [0u8; X25519_KEY_LEN];
+    rand::rng().fill_bytes(&mut ephemeral_bytes);
+    let ephemeral = StaticSecret::from(ephemeral_bytes);
+    let ephemeral_pk = PublicKey::from(&ephemeral);
+
+    let shared = shared_secret_internal(&ephemeral, &recipient)?;
+    let key = sealed_box_key_internal(&shared, ephemeral_pk.as_bytes(), recipient.as_bytes())?;
+    let ciphertext = aead_encrypt_internal::<Aes256Gcm>(&key, plaintext, &[])?;
+
+    let mut result = ephemeral_pk.as_bytes().to_vec();
+    result.extend_from_slice(&ciphertext);
+    Ok(result)
+}
+
+fn open_internal(secret_key: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, CipherError> {
+    let secret = secret_internal(secret_key)?;
+    let recipient_pk = PublicKey::from(&secret);
+
+    let minimum = X25519_KEY_LEN + 12 + 16;
+    if ciphertext.len() < minimum {
+        return Err(CipherError::CiphertextTooShort { minimum, actual: ciphertext.len() });
+    }
+    let (ephemeral_pk, body) = ciphertext.split_at(X25519_KEY_LEN);
+
+    let shared = shared_secret_internal(&secret, &public_internal(ephemeral_pk)?)?;
+    let key = sealed_box_key_internal(&shared, ephemeral_pk, recipient_pk.as_bytes())?;
+    aead_decrypt_internal::<Aes256Gcm>(&key, body, &[])
+}
+
+// ============================================================================
+// KEY GENERATION & DIFFIE-HELLMAN
+// ============================================================================
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn x25519_generate_keypair() -> X25519KeyPair {
+    let mut secret_key = [0u8; X25519_KEY_LEN];
+    rand::rng().fill_bytes(&mut secret_key);
+    let public_key = PublicKey::from(&StaticSecret::from(secret_key)).to_bytes();
+    X25519KeyPair { secret_key, public_key }
+}
+
+/// Rebuild a keypair from a previously exported 32-byte secret key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn x25519_keypair_from_secret(secret_key: Vec<u8>) -> Result<X25519KeyPair, CipherError> {
+    let secret = secret_internal(&secret_key)?;
+    Ok(X25519KeyPair {
+        secret_key: secret.to_bytes(),
+        public_key: PublicKey::from(&secret).to_bytes(),
+    })
+}
+
+/// Raw X25519 shared secret. Run it through HKDF before using it as a key.
+#[flutter_rust_bridge::frb(sync)]
+pub fn x25519_diffie_hellman(secret_key: Vec<u8>, public_key: Vec<u8>) -> Result<[u8; 32], CipherError> {
+    shared_secret_internal(&secret_internal(&secret_key)?, &public_internal(&public_key)?)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn x25519_diffie_hellman_async(secret_key: Vec<u8>, public_key: Vec<u8>) -> Result<[u8; 32], CipherError> {
+    shared_secret_internal(&secret_internal(&secret_key)?, &public_internal(&public_key)?)
+}
+
+// ============================================================================
+// SEALED BOXES
+// ============================================================================
+
+/// Encrypt to `recipient_pk` with a fresh ephemeral key. Only the holder of
+/// the matching secret key can open it; the sender stays anonymous.
+#[flutter_rust_bridge::frb(sync)]
+pub fn seal(recipient_pk: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    seal_internal(&recipient_pk, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn seal_async(recipient_pk: Vec<u8>, plaintext: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    seal_internal(&recipient_pk, &plaintext)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn open(secret_key: Vec<u8>, ciphertext: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    open_internal(&secret_key, &ciphertext)
+}
+
+#[flutter_rust_bridge::frb(dart_async)]
+pub async fn open_async(secret_key: Vec<u8>, ciphertext: Vec<u8>) -> Result<Vec<u8>, CipherError> {
+    open_internal(&secret_key, &ciphertext)
+}